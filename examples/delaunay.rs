@@ -4,25 +4,20 @@ use std::error::Error;
 fn main() -> Result<(), Box<dyn Error>> {
     let qh = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])?;
 
-    for simplex in qh.simplices().filter(|f| !f.upper_delaunay()) {
-        println!(
-            "{:?}",
-            simplex
-                .vertices()
-                .unwrap()
-                .iter()
-                .map(|v| v.index(&qh).unwrap())
-                .collect::<Vec<_>>()
-        );
+    for simplex in qh.lower_facets() {
+        let mut indices = Vec::new();
+        for v in simplex.vertices().unwrap() {
+            indices.push(v.index(&qh).unwrap());
+        }
+        println!("{:?}", indices);
     }
 
     let mut simplices = qh
-        .simplices()
-        .filter(|f| !f.is_sentinel() && !f.upper_delaunay())
+        .lower_facets()
         .map(|f| {
             f.vertices()
                 .unwrap()
-                .iter()
+                .into_iter()
                 .map(|v| v.index(&qh).unwrap())
                 .collect::<Vec<_>>()
         })
@@ -0,0 +1,41 @@
+//! Compares the visitor methods with iterators that collect the vertices of each facet
+//!
+//! ```sh
+//! cargo run --release --example facet_visit
+//! cargo run --release --example facet_visit -- 100000 # number of points
+//! ```
+
+use qhull::{rbox, Qh};
+use std::{error::Error, hint::black_box, ops::ControlFlow, time::Instant};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let count: usize = match std::env::args().nth(1) {
+        Some(count) => count.parse()?,
+        None => 1_000_000,
+    };
+
+    // points on a sphere, every point is a vertex
+    let (dim, coords) = rbox::generate(&format!("{} s D3 t1", count))?;
+    let start = Instant::now();
+    let qh = Qh::builder().triangulate(true).build_managed(dim, coords)?;
+    println!("hull of {} points: {} facets in {:?}", count, qh.num_facets(), start.elapsed());
+
+    let start = Instant::now();
+    let mut collected = 0;
+    for facet in qh.facets() {
+        let vertices: Vec<_> = facet.vertices().unwrap().iter().collect();
+        collected += vertices.iter().map(|v| v.id() as u64).sum::<u64>();
+    }
+    println!("collect: {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let mut visited = 0;
+    let _ = qh.for_each_facet(|facet| {
+        facet.for_each_vertex(|v| visited += v.id() as u64);
+        ControlFlow::Continue(())
+    });
+    println!("visit:   {:?}", start.elapsed());
+
+    assert_eq!(black_box(collected), black_box(visited));
+    Ok(())
+}
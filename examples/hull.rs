@@ -15,7 +15,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         let vertices = simplex
             .vertices()
             .unwrap()
-            .iter()
+            .into_iter()
             .map(|v| v.index(&qh).unwrap())
             .collect::<Vec<_>>();
 
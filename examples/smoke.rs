@@ -0,0 +1,38 @@
+//! Exercises the captured streams of qhull, without temporary files on WASI
+//!
+//! ```sh
+//! cargo run --example smoke
+//! cargo run --example smoke --target wasm32-wasip1 # with a wasm runner, e.g. CARGO_TARGET_WASM32_WASIP1_RUNNER=wasmtime
+//! ```
+
+use qhull::{PrintFormat, Qh};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // stderr is captured by default
+    let mut qh = Qh::builder().build_from_iter([
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+        [0.1, 0.1, 0.1],
+    ])?;
+    assert_eq!(qh.num_facets(), 4);
+
+    // output written to a stream
+    let off = qh.produce_output(PrintFormat::Off).map_err(|e| e.into_static())?;
+    assert!(off.starts_with("3\n"));
+
+    // the error message is read back from the captured stderr
+    let error = Qh::builder()
+        .build_from_iter([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]])
+        .unwrap_err();
+    assert!(error.error_message.unwrap().contains("QH6154"));
+
+    // input given to a program through a stream
+    let output = qhull::programs::qconvex("i", &[0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0], 2)?;
+    assert_eq!(output.stdout.lines().next(), Some("4"));
+
+    println!("ok");
+    Ok(())
+}
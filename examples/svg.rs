@@ -7,12 +7,11 @@ fn main() {
         .build_from_iter(points())
         .unwrap();
 
-    let simplices = convex_hull.simplices().count();
-    eprintln!("Convex hull has {simplices} segments and {} vertices", convex_hull.num_vertices());
+    eprintln!("Convex hull has {} segments and {} vertices", convex_hull.num_facets(), convex_hull.num_vertices());
 
     let triangulation = Qh::new_delaunay(points()).unwrap();
 
-    let triangles = triangulation.simplices().filter(|s| !s.upper_delaunay()).count();
+    let triangles = triangulation.lower_facets().count();
     eprintln!("Triangulation has {triangles} triangles and {} vertices", triangulation.num_vertices());
 
     let mut doc = svg::Document::new()
@@ -41,17 +40,17 @@ fn main() {
     }
 
     eprintln!("drawing triangles:");
-    for s in triangulation.simplices().filter(|s| !s.upper_delaunay()) {
+    for s in triangulation.lower_facets() {
         eprintln!(
             "- {} -",
             s
-                .vertices().unwrap().iter()
+                .vertices().unwrap().into_iter()
                 .map(|v| v.index(&triangulation).unwrap().to_string())
                 .collect::<Vec<_>>()
                 .join(" - "),
         );
         let vertices = s
-            .vertices().unwrap().iter()
+            .vertices().unwrap().into_iter()
             .map(|v| v.point().unwrap())
             .collect::<Vec<_>>();
         assert_eq!(vertices.len(), 3);
@@ -67,30 +66,17 @@ fn main() {
     }
 
     eprintln!("drawing convex hull:");
-    for s in convex_hull.simplices() {
-        eprintln!(
-            "{}",
-            s
-                .vertices().unwrap().iter()
-                .map(|v| v.index(&convex_hull).unwrap().to_string())
-                .collect::<Vec<_>>()
-                .join(" - "),
-        );
-        let vertices = s
-            .vertices().unwrap().iter()
-            .map(|v| v.point().unwrap())
-            .collect::<Vec<_>>();
-        assert_eq!(vertices.len(), 2);
-        let a = vertices[0];
-        let b = vertices[1];
-        doc = doc.add(svg::node::element::Line::new()
-            .set("x1", a[0])
-            .set("y1", a[1])
-            .set("x2", b[0])
-            .set("y2", b[1])
-            .set("stroke", "red")
-            .set("stroke-width", 0.5));
-    }
+    let polygon = convex_hull.hull_polygon_2d().unwrap();
+    eprintln!(
+        "{}",
+        polygon.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(" - "),
+    );
+    let coords = convex_hull.hull_polygon_2d_coords().unwrap();
+    doc = doc.add(svg::node::element::Polygon::new()
+        .set("points", coords.iter().map(|[x, y]| format!("{x},{y}")).collect::<Vec<_>>().join(" "))
+        .set("fill", "none")
+        .set("stroke", "red")
+        .set("stroke-width", 0.5));
 
     svg::write(std::io::stdout(), &doc).unwrap();
     println!("\n<!-- redirect stdout to a file to save the SVG -->");
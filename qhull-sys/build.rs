@@ -2,9 +2,53 @@ use std::{env, fs::read_dir, path::PathBuf};
 
 const QHULL_SRC_DIR: &str = "qhull/src/libqhull_r";
 
+/// Qhull sources replaced by files in `src`
+///
+/// These are the documented customization points of qhull:
+/// * `userprintf_r.c` by `user_printf.c`, which forwards the output to Rust writers
+/// * `usermem_r.c` by `user_mem.c`, where `qh_exit` returns to the current try instead of exiting
+const REPLACED_SOURCES: &[&str] = &["userprintf_r.c", "usermem_r.c"];
+
+/// Inserted before the `main` function of the programs
+///
+/// Redirects the standard streams to the files set with `qhull_sys__set_program_files`
+/// and turns the `exit` calls of `main` into returns, so that the programs can be run in-process.
+const PROGRAM_PRELUDE: &str = r#"
+FILE* qhull_sys__program_stdin(void);
+FILE* qhull_sys__program_stdout(void);
+FILE* qhull_sys__program_stderr(void);
+int qhull_sys__program_files_redirected(void);
+#undef stdin
+#undef stdout
+#undef stderr
+#undef printf
+#undef exit
+#define stdin qhull_sys__program_stdin()
+#define stdout qhull_sys__program_stdout()
+#define stderr qhull_sys__program_stderr()
+#define printf(...) fprintf(stdout, __VA_ARGS__)
+#define isatty(fd) (qhull_sys__program_files_redirected() ? 0 : isatty(fd))
+#define exit(code) return (code)
+
+"#;
+
 fn main() {
     println!("cargo:rerun-if-changed=src/error_handling.h");
     println!("cargo:rerun-if-changed=src/error_handling.c");
+    println!("cargo:rerun-if-changed=src/user_printf.h");
+    println!("cargo:rerun-if-changed=src/user_printf.c");
+    println!("cargo:rerun-if-changed=src/user_mem.c");
+
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_arch.starts_with("wasm") && target_os == "unknown" {
+        // qhull needs malloc, the stdio streams and setjmp/longjmp, none of which exist
+        // without a C standard library: fail here rather than at link time or run time
+        panic!(
+            "qhull cannot be built for wasm32-unknown-unknown, which has no C standard library: \
+             use wasm32-wasip1 with wasi-sdk instead"
+        );
+    }
 
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     let target_triple = env::var("TARGET").unwrap();
@@ -20,7 +64,9 @@ fn main() {
         let path = entry.path();
         if path.is_file() {
             let file = path.file_name().unwrap().to_str().unwrap().to_string();
-            if file.ends_with(".c") {
+            if REPLACED_SOURCES.contains(&file.as_str()) {
+                continue;
+            } else if file.ends_with(".c") {
                 sources.push(file);
             } else if file.ends_with(".h") {
                 headers.push(file);
@@ -31,9 +77,18 @@ fn main() {
     let mut builder = cc::Build::new();
     builder.files(sources.iter().map(|s| format!("{}/{}", QHULL_SRC_DIR, s)));
     builder.file("src/error_handling.c");
+    builder.file("src/user_printf.c");
+    builder.file("src/user_mem.c");
     builder.include(QHULL_SRC_DIR);
     builder.include("qhull/src");
 
+    if target_os == "wasi" {
+        // the error handling uses setjmp/longjmp, which wasi-libc implements
+        // with the exception handling proposal (needs wasi-sdk 22 or later)
+        builder.flag("-mllvm").flag("-wasm-enable-sjlj");
+        println!("cargo:rustc-link-lib=setjmp");
+    }
+
     let wrapper = if all_headers {
         // create a wrapper file
         let mut wrapper = String::new();
@@ -51,6 +106,7 @@ fn main() {
     let mut bindings_builder = bindgen::Builder::default()
         .header(wrapper.to_str().unwrap())
         .header("src/error_handling.h")
+        .header("src/user_printf.h")
         .use_core() // no_std
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .clang_args([
@@ -78,7 +134,7 @@ fn main() {
             let main_function_name = format!("qhull_sys__{}_main", program);
             let program_source = std::fs::read_to_string(&program_path)
                 .unwrap()
-                .replace("int main(", &format!("int {}(", main_function_name))
+                .replace("int main(", &format!("{}int {}(", PROGRAM_PRELUDE, main_function_name))
                 .replace("char hidden_options", "static char hidden_options")
                 .replace("char qh_prompt", "static char qh_prompt")
                 .replace("char prompt", "static char prompt");
@@ -1,6 +1,19 @@
 
 #include "./error_handling.h"
 
+#if defined(_MSC_VER)
+#define QHULL_SYS_THREAD_LOCAL __declspec(thread)
+#else
+#define QHULL_SYS_THREAD_LOCAL _Thread_local
+#endif
+
+// where `qh_exit` jumps to, set during `qhull_sys__try_on_qh`
+static QHULL_SYS_THREAD_LOCAL jmp_buf* exit_target = NULL;
+
+jmp_buf* qhull_sys__exit_target(void) {
+    return exit_target;
+}
+
 int qhull_sys__try_on_qh(
     qhT* qh,
     void (*fn)(qhT* qh, void* data),
@@ -13,24 +26,41 @@ int qhull_sys__try_on_qh(
         return QH_TRY_ERROR;
     }
 
-    // this is the error status, 0 means no error
-    int try_status = 0;
+    if (qh->ERREXITcalled) {
+        // a previous error could not be handled, the instance is poisoned
+        return QH_TRY_ERROR_DURING_ERROR_HANDLING;
+    }
 
-    if (qh->NOerrexit) {
-        qh->NOerrexit = False;
-        try_status = setjmp(qh->errexit);
-    } else {
-        // try_on_qh was nested
+    if (!qh->NOerrexit) {
+        // try_on_qh was nested, the outer try owns the error handling state
         printf("try_on_qh was nested\n");
-        try_status = QH_TRY_ERROR;
+        return QH_TRY_ERROR;
     }
 
+    // the try of another instance may be running on this thread
+    jmp_buf* previous_exit_target = exit_target;
+    jmp_buf exit_buffer;
+
+    // this is the error status, 0 means no error
+    int try_status = 0;
+
+    qh->NOerrexit = False;
+    try_status = setjmp(qh->errexit);
+
     // do not execute the function if an error occurred and we
     // jumped back to the setjmp point
     if (try_status == 0) {
-        fn(qh, data);
+        if (setjmp(exit_buffer) == 0) {
+            exit_target = &exit_buffer;
+            fn(qh, data);
+        } else {
+            // qh_exit was called, mark the instance as poisoned
+            qh->ERREXITcalled = True;
+            try_status = QH_TRY_ERROR_DURING_ERROR_HANDLING;
+        }
     }
 
+    exit_target = previous_exit_target;
     qh->NOerrexit = True;
 
     return try_status;
@@ -46,4 +76,31 @@ FILE* qhull_sys__stdout() {
 
 FILE* qhull_sys__stderr() {
     return stderr;
+}
+
+// streams used by the programs, NULL means the standard stream
+static QHULL_SYS_THREAD_LOCAL FILE* program_stdin = NULL;
+static QHULL_SYS_THREAD_LOCAL FILE* program_stdout = NULL;
+static QHULL_SYS_THREAD_LOCAL FILE* program_stderr = NULL;
+
+void qhull_sys__set_program_files(FILE* in, FILE* out, FILE* err) {
+    program_stdin = in;
+    program_stdout = out;
+    program_stderr = err;
+}
+
+FILE* qhull_sys__program_stdin() {
+    return program_stdin ? program_stdin : stdin;
+}
+
+FILE* qhull_sys__program_stdout() {
+    return program_stdout ? program_stdout : stdout;
+}
+
+FILE* qhull_sys__program_stderr() {
+    return program_stderr ? program_stderr : stderr;
+}
+
+int qhull_sys__program_files_redirected() {
+    return program_stdin || program_stdout || program_stderr;
 }
\ No newline at end of file
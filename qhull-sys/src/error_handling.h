@@ -4,7 +4,16 @@
 #include "../qhull/src/libqhull_r/libqhull_r.h"
 
 #define QH_TRY_ERROR 10071
+// qhull failed while handling an error, the instance is left with `ERREXITcalled` set
+#define QH_TRY_ERROR_DURING_ERROR_HANDLING 10072
 
+// Call `fn(qh, data)`, returning the qhull error code if qhull calls `qh_errexit`, 0 otherwise
+//
+// The `setjmp` is done here: on error, qhull jumps out of `fn` back to this function,
+// so `fn` must not rely on running to completion.
+// If qhull would exit the process (`qh_exit`), for example because of an error while
+// handling a previous error, QH_TRY_ERROR_DURING_ERROR_HANDLING is returned instead.
+// Such an instance is poisoned: `fn` is not called anymore and the same code is returned.
 int qhull_sys__try_on_qh(
     qhT* qh,
     void (*fn)(qhT* qh, void* data),
@@ -13,4 +22,10 @@ int qhull_sys__try_on_qh(
 
 FILE* qhull_sys__stdin();
 FILE* qhull_sys__stdout();
-FILE* qhull_sys__stderr();
\ No newline at end of file
+FILE* qhull_sys__stderr();
+
+void qhull_sys__set_program_files(FILE* in, FILE* out, FILE* err);
+FILE* qhull_sys__program_stdin();
+FILE* qhull_sys__program_stdout();
+FILE* qhull_sys__program_stderr();
+int qhull_sys__program_files_redirected();
\ No newline at end of file
@@ -15,6 +15,24 @@ include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
 pub const QHULL_LICENSE_TEXT: &str = include_str!("../qhull/COPYING.txt");
 
+/// [`qh_version`] as a string, e.g. `"2020.2.r 2023/01/02"`
+pub fn qh_version_str() -> &'static str {
+    // SAFETY: `qh_version` is a nul-terminated constant of the C library
+    unsafe { static_c_str(core::ptr::addr_of!(qh_version) as *const _) }
+}
+
+/// [`qh_version2`] as a string, e.g. `"qhull_r 8.1-alpha3 (2020.2.r 2023/01/02)"`
+pub fn qh_version2_str() -> &'static str {
+    // SAFETY: `qh_version2` is a nul-terminated constant of the C library
+    unsafe { static_c_str(core::ptr::addr_of!(qh_version2) as *const _) }
+}
+
+/// # Safety
+/// `ptr` must point to a nul-terminated string that is never modified
+unsafe fn static_c_str(ptr: *const core::ffi::c_char) -> &'static str {
+    CStr::from_ptr(ptr).to_str().unwrap_or("")
+}
+
 fn str_from_i32_array<'a>(data: &'a [i8]) -> Result<&'a CStr, FromBytesUntilNulError> {
     unsafe {
         CStr::from_bytes_until_nul(core::slice::from_raw_parts(
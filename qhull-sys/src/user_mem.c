@@ -0,0 +1,39 @@
+// Replacement for qhull's usermem_r.c
+//
+// Same as the original, except for `qh_exit`: inside `qhull_sys__try_on_qh`
+// it jumps back to the try instead of exiting the process.
+
+#include "./error_handling.h"
+
+#include <stdarg.h>
+#include <stdlib.h>
+
+// defined in error_handling.c, NULL outside of `qhull_sys__try_on_qh`
+jmp_buf* qhull_sys__exit_target(void);
+
+void qh_exit(int exitcode) {
+    jmp_buf* target = qhull_sys__exit_target();
+    if (target) {
+        longjmp(*target, 1);
+    }
+    exit(exitcode);
+}
+
+void qh_fprintf_stderr(int msgcode, const char* fmt, ...) {
+    va_list args;
+
+    va_start(args, fmt);
+    if (msgcode) {
+        fprintf(stderr, "QH%.4d ", msgcode);
+    }
+    vfprintf(stderr, fmt, args);
+    va_end(args);
+}
+
+void qh_free(void* mem) {
+    free(mem);
+}
+
+void* qh_malloc(size_t size) {
+    return malloc(size);
+}
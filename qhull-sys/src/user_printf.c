@@ -0,0 +1,113 @@
+// Replacement for qhull's userprintf_r.c
+//
+// Behaves as the original `qh_fprintf`, except for the streams returned by
+// `qhull_sys__writer_file`: the text printed on these is forwarded
+// to the writers attached to the instance with `qhull_sys__set_writers`.
+
+#include "./user_printf.h"
+
+#include <stdarg.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+// only the addresses are used, to recognize the writer streams
+static char stdout_writer_marker;
+static char stderr_writer_marker;
+
+FILE* qhull_sys__writer_file(int stream) {
+    switch (stream) {
+    case QHULL_SYS_STDOUT_WRITER:
+        return (FILE*)&stdout_writer_marker;
+    case QHULL_SYS_STDERR_WRITER:
+        return (FILE*)&stderr_writer_marker;
+    default:
+        return NULL;
+    }
+}
+
+void qhull_sys__set_writers(qhT* qh, qhull_sys__writers* writers) {
+    qh->cpp_user = writers;
+}
+
+static int writer_stream(FILE* fp) {
+    if (fp == (FILE*)&stdout_writer_marker) {
+        return QHULL_SYS_STDOUT_WRITER;
+    }
+    if (fp == (FILE*)&stderr_writer_marker) {
+        return QHULL_SYS_STDERR_WRITER;
+    }
+    return 0;
+}
+
+// Print `prefix` followed by the formatted message, with a single call to the writer
+static void write_to_stream(qhT* qh, FILE* fp, int msgcode, const char* prefix, const char* fmt, va_list args) {
+    int stream = writer_stream(fp);
+    qhull_sys__writers* writers = stream && qh ? (qhull_sys__writers*)qh->cpp_user : NULL;
+    if (!writers || !writers->write) {
+        if (stream) {
+            // the writers are not attached yet, fall back to the standard streams
+            fp = stream == QHULL_SYS_STDOUT_WRITER ? stdout : stderr;
+        }
+        fputs(prefix, fp);
+        vfprintf(fp, fmt, args);
+        return;
+    }
+
+    char buffer[1024];
+    char* text = buffer;
+    int prefix_len = snprintf(buffer, sizeof(buffer), "%s", prefix);
+    va_list copy;
+    va_copy(copy, args);
+    int len = vsnprintf(buffer + prefix_len, sizeof(buffer) - prefix_len, fmt, args);
+    if (len >= 0 && prefix_len + len >= (int)sizeof(buffer)) {
+        text = (char*)malloc((size_t)(prefix_len + len) + 1);
+        if (text) {
+            memcpy(text, prefix, (size_t)prefix_len);
+            vsnprintf(text + prefix_len, (size_t)len + 1, fmt, copy);
+        } else {
+            text = buffer;
+            len = (int)sizeof(buffer) - 1 - prefix_len;
+        }
+    }
+    va_end(copy);
+
+    if (len > 0 || prefix_len > 0) {
+        writers->write(writers->data, stream, msgcode, text, (size_t)(prefix_len + (len > 0 ? len : 0)));
+    }
+    if (text != buffer) {
+        free(text);
+    }
+}
+
+void qh_fprintf(qhT* qh, FILE* fp, int msgcode, const char* fmt, ...) {
+    va_list args;
+
+    if (!fp) {
+        if (!qh) {
+            qh_fprintf_stderr(6241, "qhull internal error (user_printf.c): fp and qh not defined for qh_fprintf '%s'\n", fmt);
+            qh_exit(qh_ERRqhull); /* can not use qh_errexit() */
+        }
+        qh_fprintf_stderr(6028, "qhull internal error (user_printf.c): fp is 0.  Wrong qh_fprintf was called.\n");
+        qh->last_errcode = 6028;
+        qh_errexit(qh, qh_ERRqhull, NULL, NULL);
+    }
+    char prefix[16] = "";
+    if ((qh && qh->ANNOTATEoutput) || msgcode < MSG_TRACE4) {
+        snprintf(prefix, sizeof(prefix), "[QH%.4d]", msgcode);
+    } else if (msgcode >= MSG_ERROR && msgcode < MSG_STDERR) {
+        snprintf(prefix, sizeof(prefix), "QH%.4d ", msgcode);
+    }
+    va_start(args, fmt);
+    write_to_stream(qh, fp, msgcode, prefix, fmt, args);
+    va_end(args);
+
+    if (qh) {
+        if (msgcode >= MSG_ERROR && msgcode < MSG_WARNING) {
+            qh->last_errcode = msgcode;
+        }
+        if (qh->FLUSHprint && !writer_stream(fp)) {
+            fflush(fp);
+        }
+    }
+}
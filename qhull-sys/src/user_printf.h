@@ -0,0 +1,25 @@
+#pragma once
+
+#include <stddef.h>
+#include "../qhull/src/libqhull_r/libqhull_r.h"
+
+#define QHULL_SYS_STDOUT_WRITER 1
+#define QHULL_SYS_STDERR_WRITER 2
+
+// Receives the text printed by qhull on a writer stream
+typedef void (*qhull_sys__write_fn)(
+    void* data,
+    int stream,
+    int msgcode,
+    const char* text,
+    size_t len
+);
+
+// Writers attached to a qhull instance through `qh->cpp_user`
+typedef struct qhull_sys__writers {
+    qhull_sys__write_fn write;
+    void* data;
+} qhull_sys__writers;
+
+FILE* qhull_sys__writer_file(int stream);
+void qhull_sys__set_writers(qhT* qh, qhull_sys__writers* writers);
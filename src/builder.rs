@@ -1,9 +1,15 @@
-use std::{cell::{RefCell, UnsafeCell}, marker::PhantomData, ptr, rc::Rc};
+use std::{cell::{RefCell, UnsafeCell}, ffi::CString, io::Write, marker::PhantomData, path::{Path, PathBuf}, ptr, rc::Rc};
 
 use crate::{
-    helpers::{collect_coords, CollectedCoords},
-    io_buffers::IOBuffers,
-    sys, Qh, QhError,
+    dedup,
+    helpers::{collect_point_source, try_collect_coords, try_collect_coords_with_capacity, CollectedCoords, PointSource},
+    io_buffers::{IOBuffers, MessageHandler, OutputSink},
+    rebuild::BuildSettings,
+    rotation::{check_rotation, rotate_input, rotation_dim},
+    scaling::{bound_options, input_scaling, set_bounds, ScaleBound},
+    sys,
+    tmp_file::TmpFile,
+    PrintFormat, Qh, QhError, QhErrorKind, QhMessage,
 };
 
 type QhConfigurator = Box<dyn for<'b> Fn(&'b mut Qh) -> Result<(), QhError<'b>> + 'static>;
@@ -31,9 +37,18 @@ pub struct QhBuilder {
     dim: Option<usize>,
     capture_stdout: bool,
     capture_stderr: bool,
+    stdout_sink: Option<OutputSink>,
+    stderr_sink: Option<OutputSink>,
+    stdout_file: Option<PathBuf>,
+    stderr_file: Option<PathBuf>,
     compute: bool,
     check_output: bool,
     check_points: bool,
+    check_convexity: bool,
+    retry_on_precision_error: usize,
+    skip_validation: bool,
+    allow_degenerate: bool,
+    dedup_points: bool,
     configs: Vec<QhConfigurator>,
 }
 
@@ -42,15 +57,28 @@ pub struct QhBuilder {
 /// * [stdout](QhBuilder::capture_stdout) is not captured
 /// * [stderr](QhBuilder::capture_stderr) is captured
 /// * [compute](QhBuilder::compute) is `true`
+/// * no [retries](QhBuilder::retry_on_precision_error) on precision errors
+/// * the input is [validated](QhBuilder::skip_validation) before calling qhull
+/// * [degenerate input](QhBuilder::allow_degenerate) is an error
+/// * [duplicate points](QhBuilder::dedup_points) are given to qhull
 impl Default for QhBuilder {
     fn default() -> Self {
         Self {
             dim: None,
             capture_stdout: false,
             capture_stderr: true,
+            stdout_sink: None,
+            stderr_sink: None,
+            stdout_file: None,
+            stderr_file: None,
             compute: true,
             check_output: false,
             check_points: false,
+            check_convexity: false,
+            retry_on_precision_error: 0,
+            skip_validation: false,
+            allow_degenerate: false,
+            dedup_points: false,
             configs: Vec::new(),
         }
     }
@@ -62,9 +90,21 @@ impl QhBuilder {
     /// This is useful when you want to **assert** that the data
     /// has the correct dimensionality before building the Qhull instance.
     ///
-    /// This build will panic if the dimensionality of the data does not match the hint.
+    /// The build returns an [`InvalidInput`](QhErrorKind::InvalidInput) error
+    /// if the dimensionality of the data does not match the hint, so a hint of 0 always fails.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+    /// assert!(Qh::builder().dim(2).build_from_iter(points).is_ok());
+    ///
+    /// for dim in [0, 3] {
+    ///     let error = Qh::builder().dim(dim).build_from_iter(points).unwrap_err();
+    ///     assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// }
+    /// ```
     pub fn dim(mut self, dim: usize) -> Self {
-        assert!(dim > 0, "dim must be > 0");
         self.dim = Some(dim);
         self
     }
@@ -85,10 +125,278 @@ impl QhBuilder {
         self
     }
 
+    /// Capture stdout in a file
+    ///
+    /// The file is created if it does not exist, otherwise qhull appends to it (see [`TmpFile::from_path`](crate::tmp_file::TmpFile::from_path)).
+    /// It takes precedence over [`capture_stdout`](QhBuilder::capture_stdout),
+    /// while a [writer](QhBuilder::stdout_writer) takes precedence over the file.
+    /// The file is opened for each instance, and closed when the instance is dropped.
+    /// It is not opened (nor created) if a writer or a [message handler](QhBuilder::message_handler) is set.
+    ///
+    /// Unlike a temporary file, the file is not replaced when its content is taken:
+    /// [`Qh::take_stdout`] returns the text written since the instance was built or since the previous call,
+    /// and the file keeps all of it.
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if the file cannot be opened.
+    pub fn stdout_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.stdout_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Capture stderr in a file
+    ///
+    /// As [`stdout_file`](QhBuilder::stdout_file), for the error output:
+    /// the file takes precedence over [`capture_stderr`](QhBuilder::capture_stderr)
+    /// and a [writer](QhBuilder::stderr_writer) or a [message handler](QhBuilder::message_handler)
+    /// takes precedence over the file.
+    ///
+    /// The file is kept after an error: the [message](QhError::error_message) of the error is the text
+    /// written to the file since the previous error (or since the instance was built),
+    /// while the temporary file used by [`capture_stderr`](QhBuilder::capture_stderr) is replaced with a new one.
+    /// This makes it suitable as a log file, that can be followed while qhull runs.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let path = std::env::temp_dir().join(format!("qhull-rs-doctest-stderr-{}.log", std::process::id()));
+    /// let builder = || Qh::builder().stderr_file(&path);
+    ///
+    /// for _ in 0..2 {
+    ///     let Err(error) = builder().build_from_iter([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]) else {
+    ///         panic!("flat input should fail");
+    ///     };
+    ///     let message = error.error_message.unwrap();
+    ///     assert_eq!(message.matches("QH6154").count(), 1);
+    /// }
+    /// // the log has the messages of both builds
+    /// let log = std::fs::read_to_string(&path).unwrap();
+    /// assert_eq!(log.matches("QH6154").count(), 2);
+    ///
+    /// // warnings are taken as from a temporary file, and stay in the log
+    /// let mut qh = builder()
+    ///     .scale_last(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    /// assert!(qh.take_stderr().unwrap().unwrap().contains("QH7040"));
+    /// assert_eq!(qh.take_stderr().unwrap().unwrap(), "");
+    /// drop(qh);
+    /// assert!(std::fs::read_to_string(&path).unwrap().contains("QH7040"));
+    /// std::fs::remove_file(&path).unwrap();
+    ///
+    /// let error = Qh::builder()
+    ///     .stderr_file(std::env::temp_dir().join("qhull-rs-missing-dir/qhull.log"))
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    ///
+    /// // the files are not used with a message handler, they are not opened
+    /// Qh::builder()
+    ///     .stdout_file(std::env::temp_dir().join("qhull-rs-missing-dir/out.log"))
+    ///     .stderr_file(&path)
+    ///     .message_handler(|_| {})
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert!(!path.exists());
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if the file cannot be opened.
+    pub fn stderr_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.stderr_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Send the output of the qhull library to a writer
+    ///
+    /// The writer takes precedence over [`capture_stdout`](QhBuilder::capture_stdout),
+    /// no temporary file is created for stdout.
+    /// Write errors are ignored.
+    ///
+    /// Functions returning the output, such as [`Qh::produce_output`], do not use the writer.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::{cell::RefCell, io::{self, Write}, rc::Rc};
+    /// #[derive(Clone, Default)]
+    /// struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+    ///
+    /// impl Write for SharedBuffer {
+    ///     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    ///         self.0.borrow_mut().write(buf)
+    ///     }
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let out = SharedBuffer::default();
+    /// let mut qh = Qh::builder()
+    ///     .stdout_writer(Box::new(out.clone()))
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// // print the summary to qhull's stdout
+    /// unsafe {
+    ///     Qh::try_on_qh_mut(&mut qh, |qh| sys::qh_printsummary(qh, (*qh).fout)).unwrap();
+    /// }
+    /// let output = String::from_utf8(out.0.borrow().clone()).unwrap();
+    /// assert!(output.contains("Number of vertices: 3"));
+    ///
+    /// // the output went straight to the writer, there is no capture file
+    /// assert_eq!(qh.take_stdout().unwrap(), None);
+    /// ```
+    pub fn stdout_writer(mut self, writer: Box<dyn Write + 'static>) -> Self {
+        self.stdout_sink = Some(OutputSink::Writer(Rc::new(RefCell::new(writer))));
+        self
+    }
+
+    /// Send the error output of the qhull library to a writer
+    ///
+    /// The writer takes precedence over [`capture_stderr`](QhBuilder::capture_stderr),
+    /// no temporary file is created for stderr.
+    /// The messages of a failed call are still reported in the [`QhError`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::{cell::RefCell, io::{self, Write}, rc::Rc};
+    /// # #[derive(Clone, Default)]
+    /// # struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+    /// # impl Write for SharedBuffer {
+    /// #     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    /// #         self.0.borrow_mut().write(buf)
+    /// #     }
+    /// #     fn flush(&mut self) -> io::Result<()> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let err = SharedBuffer::default();
+    /// let Err(error) = Qh::builder()
+    ///     .stderr_writer(Box::new(err.clone()))
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 1.0],
+    ///         [2.0, 2.0],
+    ///     ])
+    /// else {
+    ///     panic!("flat input should fail");
+    /// };
+    ///
+    /// let written = String::from_utf8(err.0.borrow().clone()).unwrap();
+    /// assert!(written.contains("QH6154"));
+    /// assert!(error.error_message.unwrap().contains("QH6154"));
+    ///
+    /// // the message of an error only contains the text printed during the failed call
+    /// let Err(error) = Qh::builder()
+    ///     .stderr_writer(Box::new(err.clone()))
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]])
+    /// else {
+    ///     panic!("flat input should fail");
+    /// };
+    /// let written = String::from_utf8(err.0.borrow().clone()).unwrap();
+    /// assert_eq!(written.matches("QH6154").count(), 2);
+    /// assert_eq!(error.error_message.unwrap().matches("QH6154").count(), 1);
+    ///
+    /// // no capture file is used for stderr
+    /// let mut qh = Qh::builder()
+    ///     .stderr_writer(Box::new(err.clone()))
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.take_stderr().unwrap(), None);
+    /// ```
+    pub fn stderr_writer(mut self, writer: Box<dyn Write + 'static>) -> Self {
+        self.stderr_sink = Some(OutputSink::Writer(Rc::new(RefCell::new(writer))));
+        self
+    }
+
+    /// Send every message of the qhull library to a callback
+    ///
+    /// The handler receives each call of `qh_fprintf` as a [`QhMessage`], with its code and [`Severity`](crate::Severity),
+    /// both for the output (stdout) and for the error output (stderr).
+    /// It takes precedence over the captures and the writers of both streams: no temporary file is created.
+    /// The messages of a failed call are still reported in the [`QhError`].
+    ///
+    /// The few messages that qhull prints directly to the standard error (`qh_fprintf_stderr`) are not received.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// let messages = Rc::new(RefCell::new(Vec::new()));
+    /// let Err(error) = Qh::builder()
+    ///     .message_handler({
+    ///         let messages = messages.clone();
+    ///         move |message| messages.borrow_mut().push(message)
+    ///     })
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 1.0],
+    ///         [2.0, 2.0],
+    ///     ])
+    /// else {
+    ///     panic!("flat input should fail");
+    /// };
+    ///
+    /// let messages = messages.borrow();
+    /// let message = messages.iter().find(|m| m.code == 6154).unwrap();
+    /// assert_eq!(message.severity, Severity::Error);
+    /// assert!(message.text.starts_with("QH6154"));
+    /// assert!(error.error_message.unwrap().contains("QH6154"));
+    /// ```
+    pub fn message_handler(mut self, handler: impl FnMut(QhMessage) + 'static) -> Self {
+        let handler: MessageHandler = Rc::new(RefCell::new(Box::new(handler)));
+        self.stdout_sink = Some(OutputSink::Handler(handler.clone()));
+        self.stderr_sink = Some(OutputSink::Handler(handler));
+        self
+    }
+
+    /// Trace the execution of qhull with the [`tracing`] crate
+    ///
+    /// Sets the trace level (`Tn` option, see [`is_tracing`](QhBuilder::is_tracing))
+    /// and sends the error output of qhull to [`tracing`] events instead of a file or a writer.
+    /// Each qhull message is an event with target `qhull` and the message code as the `code` field,
+    /// the level depends on the code: `6xxx` are errors, `7xxx` warnings and the others debug events.
+    ///
+    /// The messages of a failed call are still reported in the [`QhError`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use tracing_test::traced_test;
+    /// #[traced_test]
+    /// fn traced_compute() {
+    ///     let qh = Qh::builder()
+    ///         .trace_to_tracing(1)
+    ///         .build_from_iter([
+    ///             [0.0, 0.0],
+    ///             [1.0, 0.0],
+    ///             [0.0, 1.0],
+    ///             [0.25, 0.25],
+    ///         ])
+    ///         .unwrap();
+    ///     assert_eq!(qh.num_facets(), 3);
+    ///     assert!(logs_contain("code=1"));
+    /// }
+    /// traced_compute();
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn trace_to_tracing(mut self, level: i32) -> Self {
+        self.stderr_sink = Some(OutputSink::Tracing);
+        self.is_tracing(level)
+    }
+
     /// Set whether to compute the hull when building the Qhull instance
     ///
     /// When enabled, [`Qh::compute`] will be called.
-    /// When disabled, you will have to call this method manually.
+    /// When disabled, you will have to call this method manually,
+    /// until then the instance has no facets and no vertices (see [`Qh::is_computed`]).
     ///
     /// # Example
     /// ```
@@ -109,6 +417,7 @@ impl QhBuilder {
     ///     .compute(false)
     ///     .build_from_iter(points)
     ///     .unwrap();
+    /// assert!(!qh.is_computed());
     /// assert_eq!(qh.num_facets(), 0);
     /// qh.compute().unwrap();
     /// assert_eq!(qh.num_facets(), 3);
@@ -127,13 +436,984 @@ impl QhBuilder {
         self
     }
 
-    /// Set whether to check the points when building the Qhull instance
+    /// Set whether to check the points when building the Qhull instance
+    ///
+    /// When enabled, [`Qh::check_points`] will be called after computing the hull.
+    /// If [`compute`](QhBuilder::compute) is disabled, this setting will have no effect.
+    pub fn check_points(mut self, check: bool) -> Self {
+        self.check_points = check;
+        self
+    }
+
+    /// Set whether to check the convexity of the hull when building the Qhull instance
+    ///
+    /// When enabled, [`Qh::check_convexity`] will be called after computing the hull.
+    /// If [`compute`](QhBuilder::compute) is disabled, this setting will have no effect.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = QhBuilder::default()
+    ///     .check_convexity(true)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// ```
+    pub fn check_convexity(mut self, check: bool) -> Self {
+        self.check_convexity = check;
+        self
+    }
+
+    /// Joggle the input (`QJ` option)
+    ///
+    /// Qhull will randomly perturb each coordinate of the input by at most `max`,
+    /// instead of merging facets to handle precision problems.
+    /// If `max` is `None`, qhull chooses the joggle from the input (`QJ` without arguments),
+    /// see [`Qh::joggle_applied`] for the value that was actually used.
+    ///
+    /// Joggled hulls are simplicial, but the output is not deterministic with respect to the input coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .joggle(None)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    /// assert!(qh.joggle_applied().unwrap() > 0.0);
+    ///
+    /// let qh = Qh::builder()
+    ///     .joggle(Some(1e-6))
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(qh.joggle_applied(), Some(1e-6));
+    /// ```
+    pub fn joggle(self, max: Option<f64>) -> Self {
+        self.joggle_max(max.unwrap_or(0.0))
+    }
+
+    /// Compute an approximate hull (`Wn` option)
+    ///
+    /// Points less than `eps` above every facet are considered inside,
+    /// the hull may therefore miss some of the extreme points.
+    /// This also enables [`approx_hull`](QhBuilder::approx_hull) (`APPROXhull`),
+    /// without which qhull ignores the distance.
+    /// See also [`approximate`](QhBuilder::approximate), which also keeps pre-merging enabled.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("1000 s D2").unwrap();
+    /// let exact = Qh::builder().build_managed(dim, coords.clone()).unwrap();
+    /// let approximate = Qh::builder()
+    ///     .approximate_hull(0.1)
+    ///     .build_managed(dim, coords)
+    ///     .unwrap();
+    /// assert!(approximate.num_facets() < exact.num_facets());
+    ///
+    /// let error = Qh::builder()
+    ///     .approximate_hull(-0.1)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `eps` is not positive and finite.
+    pub fn approximate_hull(self, eps: f64) -> Self {
+        self.tolerance("Wn", eps, eps > 0.0, "a positive distance", move |qh| {
+            qh.MINoutside = eps;
+            qh.APPROXhull = true as _;
+        })
+    }
+
+    /// Compute an approximate hull, omitting the points less than `epsilon` above the hull (`Wn` option)
+    ///
+    /// Unlike [`approximate_hull`](QhBuilder::approximate_hull), which only sets `Wn`,
+    /// this keeps pre-merging enabled ([`pre_merge`](QhBuilder::pre_merge)) even if qhull
+    /// would not merge by default (with [`no_premerge`](QhBuilder::no_premerge) or in 5D and higher).
+    /// Without merging, the facets of an approximate hull may be narrow or flipped.  
+    /// A point is added to the hull only if it is at least `epsilon` above a facet,
+    /// so every omitted point is usually within `epsilon` of the hull.
+    /// As noted in the qhull documentation, in pathological cases merged facets may end up further
+    /// than `epsilon` from an omitted point: the actual bound is [`Qh::max_outside`].
+    /// The value used by qhull is available after the build with [`Qh::approximation_epsilon`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // points on a circle
+    /// let (dim, coords) = rbox::generate("10000 s D2").unwrap();
+    /// let exact = Qh::builder().build_managed(dim, coords.clone()).unwrap();
+    /// let qh = Qh::builder()
+    ///     .approximate(0.05)
+    ///     .build_managed(dim, coords)
+    ///     .unwrap();
+    /// assert_eq!(qh.approximation_epsilon(), Some(0.05));
+    /// assert!(qh.num_facets() * 100 < exact.num_facets());
+    ///
+    /// // every point is within epsilon of the plane of a facet
+    /// for point in qh.points() {
+    ///     let dist = qh
+    ///         .facets()
+    ///         .map(|f| f.offset() + f.normal().unwrap().iter().zip(point).map(|(n, x)| n * x).sum::<f64>())
+    ///         .fold(f64::NEG_INFINITY, f64::max);
+    ///     assert!(dist.abs() <= 0.05, "{dist}");
+    /// }
+    ///
+    /// let error = Qh::builder()
+    ///     .approximate(0.0)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `epsilon` is not positive and finite.
+    pub fn approximate(self, epsilon: f64) -> Self {
+        self.tolerance("Wn", epsilon, epsilon > 0.0, "a positive distance", move |qh| {
+            qh.MINoutside = epsilon;
+            qh.APPROXhull = true as _;
+            qh.NOpremerge = false as _;
+            qh.PREmerge = true as _;
+            qh.MERGING = true as _;
+        })
+    }
+
+    /// Merge facets while building the hull if a centrum is less than `radius` from a neighbor (`C-n` option)
+    ///
+    /// The round-off error is added to `radius`.
+    /// This enables pre-merging ([`pre_merge`](QhBuilder::pre_merge)), like the other `C-n` and `A-n` options.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("1000 s D3").unwrap();
+    /// let exact = Qh::builder().build_managed(dim, coords.clone()).unwrap();
+    /// let merged = Qh::builder()
+    ///     .premerge_centrum_radius(0.01)
+    ///     .build_managed(dim, coords)
+    ///     .unwrap();
+    /// assert!(merged.num_facets() < exact.num_facets());
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `radius` is negative or not finite.
+    pub fn premerge_centrum_radius(self, radius: f64) -> Self {
+        self.tolerance("C-n", radius, radius >= 0.0, "a non-negative distance", move |qh| {
+            qh.premerge_centrum = radius;
+            qh.PREmerge = true as _;
+            qh.MERGING = true as _;
+        })
+    }
+
+    /// Merge facets after building the hull if a centrum is less than `radius` from a neighbor (`Cn` option)
+    ///
+    /// The round-off error is added to `radius`.
+    /// This enables post-merging ([`post_merge`](QhBuilder::post_merge)), like the other `Cn` and `An` options.
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `radius` is negative or not finite.
+    pub fn postmerge_centrum_radius(self, radius: f64) -> Self {
+        self.tolerance("Cn", radius, radius >= 0.0, "a non-negative distance", move |qh| {
+            qh.postmerge_centrum = radius;
+            qh.POSTmerge = true as _;
+            qh.MERGING = true as _;
+        })
+    }
+
+    /// Merge facets while building the hull if the cosine of the angle between their normals is greater than `cos` (`A-n` option)
+    ///
+    /// This enables pre-merging ([`pre_merge`](QhBuilder::pre_merge)), like the other `C-n` and `A-n` options.
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `cos` is not in `[-1, 1]`.
+    pub fn premerge_angle_cos(self, cos: f64) -> Self {
+        self.tolerance("A-n", cos, (-1.0..=1.0).contains(&cos), "a cosine in [-1, 1]", move |qh| {
+            qh.premerge_cos = cos;
+            qh.PREmerge = true as _;
+            qh.MERGING = true as _;
+        })
+    }
+
+    /// Merge facets after building the hull if the cosine of the angle between their normals is greater than `cos` (`An` option)
+    ///
+    /// This enables post-merging ([`post_merge`](QhBuilder::post_merge)), like the other `Cn` and `An` options.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("1000 s D3").unwrap();
+    /// let exact = Qh::builder().build_managed(dim, coords.clone()).unwrap();
+    /// let merged = Qh::builder()
+    ///     .postmerge_angle_cos(0.99)
+    ///     .build_managed(dim, coords)
+    ///     .unwrap();
+    /// assert!(merged.num_facets() < exact.num_facets());
+    ///
+    /// let error = Qh::builder()
+    ///     .postmerge_angle_cos(1.5)
+    ///     .build_from_iter([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `cos` is not in `[-1, 1]`.
+    pub fn postmerge_angle_cos(self, cos: f64) -> Self {
+        self.tolerance("An", cos, (-1.0..=1.0).contains(&cos), "a cosine in [-1, 1]", move |qh| {
+            qh.postmerge_cos = cos;
+            qh.POSTmerge = true as _;
+            qh.MERGING = true as _;
+        })
+    }
+
+    /// Use `dist` as the maximum round-off error for distances (`En` option)
+    ///
+    /// Qhull computes it from the input otherwise.
+    /// This also enables [`set_roundoff`](QhBuilder::set_roundoff) (`SETroundoff`),
+    /// without which qhull overwrites the value.
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `dist` is negative or not finite.
+    pub fn distance_roundoff(self, dist: f64) -> Self {
+        self.tolerance("En", dist, dist >= 0.0, "a non-negative distance", move |qh| {
+            qh.DISTround = dist;
+            qh.SETroundoff = true as _;
+        })
+    }
+
+    /// Maximum distance below a facet for a point to be coplanar to it (`Un` option)
+    ///
+    /// Qhull uses the minimum distance of a visible facet otherwise.
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `dist` is negative or not finite.
+    pub fn max_coplanar_distance(self, dist: f64) -> Self {
+        self.tolerance("Un", dist, dist >= 0.0, "a non-negative distance", move |qh| {
+            qh.MAXcoplanar = dist;
+        })
+    }
+
+    /// Feasible point of a halfspace intersection (`Hn,n,n` option)
+    ///
+    /// A point clearly inside all the halfspaces, with one coordinate for each dimension of the hull.
+    /// Qhull uses it to compute the intersection points (`Fp`, [`PrintFormat::PointIntersect`]),
+    /// see [`Qh::feasible_point`].
+    /// This setting overrides [`feasible_string`](QhBuilder::feasible_string).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // duals of the halfspaces `x <= 1`, `x >= -1`, `y <= 1` and `y >= -1` about the origin
+    /// let mut qh = Qh::builder()
+    ///     .half_space(true)
+    ///     .feasible_point([0.0, 0.0])
+    ///     .build_from_points(&[[1.0, 0.0], [-1.0, 0.0], [0.0, 1.0], [0.0, -1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.feasible_point(), Some(&[0.0, 0.0][..]));
+    ///
+    /// // the corners of the square
+    /// let corners = qh.produce_output(PrintFormat::PointIntersect).unwrap();
+    /// assert_eq!(corners.lines().nth(1), Some("4"));
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if the number of coordinates
+    /// is not the dimension of the hull.
+    /// ```
+    /// # use qhull::*;
+    /// for point in [vec![], vec![0.0], vec![0.0, 0.0, 0.0]] {
+    ///     let error = Qh::builder()
+    ///         .half_space(true)
+    ///         .feasible_point(point)
+    ///         .build_from_points(&[[1.0, 0.0], [-1.0, 0.0], [0.0, 1.0], [0.0, -1.0]])
+    ///         .unwrap_err();
+    ///     assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// }
+    /// ```
+    pub fn feasible_point(self, point: impl IntoIterator<Item = f64>) -> Self {
+        let point: Vec<f64> = point.into_iter().collect();
+        unsafe {
+            self.with_configure(move |qh| {
+                qh.owned_values.feasible_point_len = Some(point.len());
+                let point = point.clone();
+                Qh::try_on_qh_mut(qh, move |qh| {
+                    // qhull frees the feasible point with `qh_free`
+                    let copy = sys::qh_malloc(std::mem::size_of_val(&point[..])) as *mut f64;
+                    copy.copy_from_nonoverlapping(point.as_ptr(), point.len());
+                    sys::qh_free((*qh).feasible_point as *mut _);
+                    (*qh).feasible_point = copy;
+                })
+            })
+        }
+    }
+
+    /// Feasible point of a halfspace intersection, as in the `Hn,n,n` option of qhull
+    ///
+    /// The coordinates are separated by commas, e.g. `"0.5,0.5,0.5"`,
+    /// the missing coordinates are `0` (`"0"` is the origin).
+    /// The string is parsed by qhull when the instance is built, see [`QhBuilder::feasible_point`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .half_space(true)
+    ///     .feasible_string("0.25,-0.5")
+    ///     .build_from_points(&[[1.0, 0.0], [-1.0, 0.0], [0.0, 1.0], [0.0, -1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.feasible_point(), Some(&[0.25, -0.5][..]));
+    ///
+    /// let qh = Qh::builder()
+    ///     .half_space(true)
+    ///     .feasible_string("0")
+    ///     .build_from_points(&[[1.0, 0.0], [-1.0, 0.0], [0.0, 1.0], [0.0, -1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.feasible_point(), Some(&[0.0, 0.0][..]));
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if the string contains a nul character.
+    /// ```
+    /// # use qhull::*;
+    /// let error = Qh::builder()
+    ///     .half_space(true)
+    ///     .feasible_string("0\0")
+    ///     .build_from_points(&[[1.0, 0.0], [-1.0, 0.0], [0.0, 1.0], [0.0, -1.0]])
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    pub fn feasible_string(self, feasible: &str) -> Self {
+        let feasible = CString::new(feasible);
+        unsafe {
+            self.with_configure(move |qh| {
+                let feasible = feasible
+                    .clone()
+                    .map_err(|_| QhError::invalid_input("the feasible point contains a nul character"))?;
+                Qh::try_on_qh_mut(qh, move |qh| {
+                    // qhull frees the string with `qh_free`
+                    let bytes = feasible.as_bytes_with_nul();
+                    let copy = sys::qh_malloc(bytes.len()) as *mut u8;
+                    copy.copy_from_nonoverlapping(bytes.as_ptr(), bytes.len());
+                    sys::qh_free((*qh).feasible_string as *mut _);
+                    (*qh).feasible_string = copy as *mut _;
+                })
+            })
+        }
+    }
+
+    /// Rotate the input points by an orthonormal matrix
+    ///
+    /// Each input point `p` is replaced by `matrix * p` before computing the hull,
+    /// as with the random rotation of [`rotate_random`](QhBuilder::rotate_random) (`QRn`).
+    /// The input points are not modified, qhull rotates a copy.
+    /// The rows of the matrix are given in order, see [`Qh::input_rotation`] to undo the rotation.
+    ///
+    /// The matrix must be square, with the dimension of the input points:
+    /// for a [Delaunay triangulation](QhBuilder::delaunay), the lifted coordinate is not rotated.
+    /// If [`rotate_random`](QhBuilder::rotate_random) is also set, this rotation is applied after the random one.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let volume = |qh: &mut Qh| unsafe {
+    ///     Qh::try_on_qh_mut(qh, |qh| sys::qh_getarea(qh, (*qh).facet_list)).unwrap();
+    ///     (*Qh::raw_ptr(qh)).totvol
+    /// };
+    /// let cube: Vec<_> = (0..8)
+    ///     .map(|i| [(i & 1) as f64, (i >> 1 & 1) as f64, 2.0 * (i >> 2 & 1) as f64])
+    ///     .collect();
+    ///
+    /// let (sin, cos) = 0.5f64.sin_cos();
+    /// let rotation = vec![
+    ///     vec![cos, -sin, 0.0],
+    ///     vec![sin, cos, 0.0],
+    ///     vec![0.0, 0.0, 1.0],
+    /// ];
+    /// let mut qh = Qh::builder()
+    ///     .rotate_input(rotation.clone())
+    ///     .build_from_iter(cube.clone())
+    ///     .unwrap();
+    /// assert_eq!(qh.input_rotation(), Some(&rotation[..]));
+    /// assert!((volume(&mut qh) - 2.0).abs() < 1e-12);
+    ///
+    /// // the point 1 (1, 0, 0) is rotated
+    /// assert!((qh.point(1).unwrap()[1] - sin).abs() < 1e-15);
+    ///
+    /// let error = Qh::builder()
+    ///     .rotate_input(vec![vec![1.0, 0.0, 0.0], vec![1.0, 1.0, 0.0], vec![0.0, 0.0, 1.0]])
+    ///     .build_from_iter(cube.clone())
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    ///
+    /// let error = Qh::builder()
+    ///     .rotate_input(vec![vec![1.0, 0.0], vec![0.0, 1.0]])
+    ///     .build_from_iter(cube)
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if the matrix does not have the right size
+    /// or if its rows are not orthonormal.
+    pub fn rotate_input(self, matrix: Vec<Vec<f64>>) -> Self {
+        let matrix = Rc::new(matrix);
+        unsafe {
+            self.with_configure(move |qh| {
+                qh.owned_values.input_rotation = Some(matrix.clone());
+                Ok(())
+            })
+        }
+    }
+
+    /// Rotate the input points randomly (`QRn` option)
+    ///
+    /// The rotation is a random orthonormal matrix generated from `seed`,
+    /// use [`Qh::input_rotation`] to know the matrix that was applied.
+    /// With `0`, the seed is the current time (`QR0`).
+    /// A negative seed only sets the seed of the random numbers of qhull, the input is not rotated (`QR-n`).
+    ///
+    /// Rotating the input is a simple way to test the sensitivity of a computation to precision problems.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let volume = |qh: &mut Qh| unsafe {
+    ///     Qh::try_on_qh_mut(qh, |qh| sys::qh_getarea(qh, (*qh).facet_list)).unwrap();
+    ///     (*Qh::raw_ptr(qh)).totvol
+    /// };
+    /// let (dim, coords) = rbox::generate("100 D3 s").unwrap();
+    ///
+    /// let mut qh = Qh::builder().build_managed(dim, coords.clone()).unwrap();
+    /// assert_eq!(qh.input_rotation(), None);
+    /// let expected = volume(&mut qh);
+    ///
+    /// let mut qh = Qh::builder()
+    ///     .rotate_random(42)
+    ///     .build_managed(dim, coords)
+    ///     .unwrap();
+    /// assert!((volume(&mut qh) - expected).abs() < 1e-12);
+    ///
+    /// // the rotation is orthonormal
+    /// let rotation = qh.input_rotation().unwrap();
+    /// for (i, a) in rotation.iter().enumerate() {
+    ///     for (j, b) in rotation.iter().enumerate() {
+    ///         let dot: f64 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+    ///         assert!((dot - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn rotate_random(self, seed: i32) -> Self {
+        unsafe {
+            self.with_configure(move |qh| {
+                Qh::try_on_qh_mut(qh, |qh| {
+                    (*qh).ROTATErandom = seed;
+                })
+            })
+        }
+    }
+
+    /// Set the seed of the random numbers of qhull
+    ///
+    /// Qhull uses random numbers for [`random_outside`](QhBuilder::random_outside) (`Qr`),
+    /// [`random_dist`](QhBuilder::random_dist) (`Rn`) and [joggle](QhBuilder::joggle) (`QJ`).
+    /// The generator is seeded after the input is initialized, the random rotation of
+    /// [`rotate_random`](QhBuilder::rotate_random) uses its own seed.
+    ///
+    /// Without this option, the seed comes from [`rotate_random`](QhBuilder::rotate_random),
+    /// and is `1` if it is not set.
+    /// Qhull has its own generator: the same seed gives the same results on every platform.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("500 D3").unwrap();
+    /// let facet_ids = |seed| {
+    ///     let qh = Qh::builder()
+    ///         .random_seed(seed)
+    ///         .random_outside(true)
+    ///         .rotate_random(7)
+    ///         .build_managed(dim, coords.clone())
+    ///         .unwrap();
+    ///     qh.facets().map(|f| f.id()).collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(facet_ids(42), facet_ids(42));
+    /// assert_ne!(facet_ids(1), facet_ids(2));
+    /// ```
+    pub fn random_seed(self, seed: u32) -> Self {
+        unsafe {
+            self.with_configure(move |qh| {
+                qh.random_seed = Some(seed);
+                Ok(())
+            })
+        }
+    }
+
+    /// Scale a coordinate of the input to a new range (`Qbk:low QBk:high` options)
+    ///
+    /// The coordinate `k` of the input points is mapped linearly from its range to `[low, high]`
+    /// before computing the hull, see [`Qh::scaling`] to map the results back.
+    /// The input points are not modified, qhull scales a copy.
+    /// `low` may be greater than `high` to reverse the coordinate.
+    /// Setting the same coordinate twice replaces its range.
+    ///
+    /// For a [Delaunay triangulation](QhBuilder::delaunay), `k` is a coordinate of the input,
+    /// the lifted coordinate is scaled with [`scale_last_coordinate`](QhBuilder::scale_last_coordinate).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("200 D3").unwrap();
+    /// let vertices = |qh: &Qh| {
+    ///     let mut vertices: Vec<_> = qh.vertices().map(|v| v.index(qh).unwrap()).collect();
+    ///     vertices.sort();
+    ///     vertices
+    /// };
+    ///
+    /// // scale x by 1000
+    /// let (min, max) = coords
+    ///     .iter()
+    ///     .step_by(3)
+    ///     .fold((f64::MAX, f64::MIN), |(min, max), &x| (min.min(x), max.max(x)));
+    /// let scaled = Qh::builder()
+    ///     .scale_dimension(0, 1000.0 * min, 1000.0 * max)
+    ///     .build_managed(dim, coords.clone())
+    ///     .unwrap();
+    /// let x = scaled.scaling()[0];
+    /// assert!((x.scale - 1000.0).abs() < 1e-9 && x.offset.abs() < 1e-9);
+    /// assert!((x.invert(scaled.point(7).unwrap()[0]) - coords[7 * 3]).abs() < 1e-12);
+    /// assert_eq!(scaled.scaling()[1], AxisScaling::IDENTITY);
+    ///
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// assert_eq!(vertices(&scaled), vertices(&qh));
+    ///
+    /// let error = Qh::builder()
+    ///     .scale_dimension(3, -1.0, 1.0)
+    ///     .build_managed(dim, vec![0.0; 12])
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if `k` is not a coordinate of the input,
+    /// if the bounds are not finite or if they are equal.
+    pub fn scale_dimension(self, k: usize, low: f64, high: f64) -> Self {
+        unsafe {
+            self.with_configure(move |qh| {
+                if k >= qh.dim {
+                    return Err(QhError::invalid_input(format!(
+                        "cannot scale coordinate {} of points of dimension {}",
+                        k, qh.dim,
+                    )));
+                }
+                if !low.is_finite() || !high.is_finite() || low == high {
+                    return Err(QhError::invalid_input(format!(
+                        "invalid range [{}, {}] for coordinate {}",
+                        low, high, k,
+                    )));
+                }
+                set_bounds(qh, [ScaleBound { dim: k, low, high }])
+            })
+        }
+    }
+
+    /// Scale the last coordinate to the range of the others (`Qbb` option)
+    ///
+    /// When computing the hull, the last coordinate is mapped to `[0, m]`,
+    /// where `m` is the maximum absolute value of the other coordinates.
+    /// This is meant for [Delaunay triangulations](QhBuilder::delaunay), where it reduces
+    /// the precision problems of the lifted coordinate ([`Qh::new_delaunay`] enables it).
+    /// See [`Qh::scaling`] for the applied scaling.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [10.0, 0.0], [0.0, 10.0], [2.0, 3.0]];
+    /// let qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .scale_last_coordinate(true)
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    ///
+    /// // the lifted coordinates (0 to 100) are mapped to [0, 10]
+    /// let lifted = qh.scaling()[2];
+    /// assert_eq!(lifted.apply(0.0), 0.0);
+    /// assert!((lifted.apply(100.0) - 10.0).abs() < 1e-12);
+    /// ```
+    pub fn scale_last_coordinate(self, scale: bool) -> Self {
+        self.scale_last(scale)
+    }
+
+    /// Ignore some coordinates of the input (`Qbk:0Bk:0` options)
+    ///
+    /// Qhull computes the hull of the input points projected to the other coordinates:
+    /// the projected points are a new array allocated by qhull (`qh_projectinput`), the input is not modified.
+    /// The [hull dimension](Qh::hull_dim) and the [points](Qh::points) of the instance
+    /// have the remaining coordinates, in the same order, see [`Qh::dropped_dimensions`].
+    /// A dimension set with [`scale_dimension`](QhBuilder::scale_dimension) is not scaled anymore, and vice versa.
+    ///
+    /// For a [Delaunay triangulation](QhBuilder::delaunay), the dimensions are dropped before lifting the points:
+    /// the triangulation is the one of the projected points.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (_, coords) = rbox::generate("100 D3").unwrap();
+    /// let points: Vec<[f64; 3]> = coords.chunks(3).map(|p| [p[0], p[1], p[2]]).collect();
+    /// let vertices = |qh: &Qh| {
+    ///     let mut vertices: Vec<_> = qh.vertices().map(|v| v.index(qh).unwrap()).collect();
+    ///     vertices.sort();
+    ///     vertices
+    /// };
+    ///
+    /// // the hull of the points projected to the xz plane
+    /// let qh = Qh::builder()
+    ///     .drop_dimensions([1])
+    ///     .build_from_iter(points.clone())
+    ///     .unwrap();
+    /// assert_eq!(qh.hull_dim(), 2);
+    /// assert_eq!(qh.dropped_dimensions(), [1]);
+    /// assert_eq!(qh.point(5).unwrap(), [points[5][0], points[5][2]]);
+    ///
+    /// let sliced = Qh::builder()
+    ///     .build_from_iter(points.iter().map(|p| [p[0], p[2]]))
+    ///     .unwrap();
+    /// assert_eq!(vertices(&qh), vertices(&sliced));
+    ///
+    /// // the Delaunay triangulation of the projected points
+    /// let qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .drop_dimensions([1])
+    ///     .build_from_iter(points.clone())
+    ///     .unwrap();
+    /// let sliced = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .build_from_iter(points.iter().map(|p| [p[0], p[2]]))
+    ///     .unwrap();
+    /// assert_eq!(qh.hull_dim(), 3);
+    /// assert_eq!(qh.num_facets(), sliced.num_facets());
+    ///
+    /// let error = Qh::builder()
+    ///     .drop_dimensions([0, 1])
+    ///     .build_from_iter(points)
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::UnsupportedDimension);
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`] if a dimension is not a coordinate of the input,
+    /// and with [`QhErrorKind::UnsupportedDimension`] if less than 2 dimensions are left.
+    pub fn drop_dimensions(self, dims: impl IntoIterator<Item = usize>) -> Self {
+        let mut dims: Vec<usize> = dims.into_iter().collect();
+        dims.sort();
+        dims.dedup();
+        unsafe {
+            self.with_configure(move |qh| {
+                if let Some(&k) = dims.iter().find(|&&k| k >= qh.dim) {
+                    return Err(QhError::invalid_input(format!(
+                        "cannot drop coordinate {} of points of dimension {}",
+                        k, qh.dim,
+                    )));
+                }
+                set_bounds(qh, dims.iter().map(|&k| ScaleBound::drop(k)))
+            })
+        }
+    }
+
+    /// Retry with a joggled input when the computation fails with a precision error
+    ///
+    /// If [`Qh::compute`] fails because of a precision problem, the instance is rebuilt
+    /// with [joggle](QhBuilder::joggle) enabled, up to `attempts` times.
+    /// The first retry uses the joggle chosen by qhull (or the one given with [`QhBuilder::joggle`]),
+    /// each following retry increases it by a factor of 10.
+    ///
+    /// Use [`Qh::joggle_applied`] to know the joggle of the returned instance.
+    /// If [`compute`](QhBuilder::compute) is disabled, this setting will have no effect.
+    ///
+    /// Each attempt starts from the points given to the builder:
+    /// with [`QhBuilder::build`] and options that modify the points in place (e.g. `Qbb`),
+    /// the points are saved before the first attempt and restored before each retry.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // nearly flat points without pre-merging fail with a topology error
+    /// let (dim, coords) = rbox::generate("1000 W1e-13 D4 t1").unwrap();
+    /// assert!(Qh::builder().no_premerge(true).build_managed(dim, coords.clone()).is_err());
+    ///
+    /// let qh = Qh::builder()
+    ///     .no_premerge(true)
+    ///     .retry_on_precision_error(3)
+    ///     .build_managed(dim, coords.clone())
+    ///     .unwrap();
+    /// assert!(qh.joggle_applied().is_some());
+    ///
+    /// // the failed attempt scaled the points in place, the retry starts from the original ones
+    /// // (the joggled retry works on a copy)
+    /// let mut points = coords.clone();
+    /// let qh = Qh::builder()
+    ///     .no_premerge(true)
+    ///     .scale_last_coordinate(true)
+    ///     .retry_on_precision_error(3)
+    ///     .build(dim, &mut points)
+    ///     .unwrap();
+    /// assert!(qh.joggle_applied().is_some());
+    /// drop(qh);
+    /// assert_eq!(points, coords);
+    /// ```
+    pub fn retry_on_precision_error(mut self, attempts: usize) -> Self {
+        self.retry_on_precision_error = attempts;
+        self
+    }
+
+    /// Joggle the input instead of failing when it is degenerate
+    ///
+    /// By default, points that do not span the whole space (e.g. collinear points in 2D)
+    /// make the build fail with [`QhErrorKind::DegenerateInput`],
+    /// and [`QhError::rank`] tells the dimension they actually span.
+    /// With this option, the instance is rebuilt with [joggle](QhBuilder::joggle) enabled:
+    /// the resulting hull is full-dimensional but very thin,
+    /// use [`Qh::joggle_applied`] to know the joggle that was applied.
+    ///
+    /// If [`compute`](QhBuilder::compute) is disabled, this setting will have no effect.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let collinear: Vec<_> = (0..10).map(|i| [i as f64, 2.0 * i as f64]).collect();
+    /// let coplanar: Vec<_> = (0..10).map(|i| [i as f64, (i * i % 7) as f64, 1.0]).collect();
+    ///
+    /// let Err(error) = Qh::builder().build_from_iter(collinear.clone()) else {
+    ///     panic!("collinear points were accepted");
+    /// };
+    /// assert_eq!(error.kind, QhErrorKind::DegenerateInput);
+    /// assert_eq!(error.rank, Some(1));
+    ///
+    /// let Err(error) = Qh::builder().build_from_iter(coplanar.clone()) else {
+    ///     panic!("coplanar points were accepted");
+    /// };
+    /// assert_eq!(error.kind, QhErrorKind::DegenerateInput);
+    /// assert_eq!(error.rank, Some(2));
+    ///
+    /// let qh = Qh::builder()
+    ///     .allow_degenerate(true)
+    ///     .build_from_iter(collinear)
+    ///     .unwrap();
+    /// assert!(qh.joggle_applied().is_some());
+    /// assert!(qh.num_facets() >= 3);
+    ///
+    /// let qh = Qh::builder()
+    ///     .allow_degenerate(true)
+    ///     .build_from_iter(coplanar)
+    ///     .unwrap();
+    /// assert!(qh.joggle_applied().is_some());
+    /// ```
+    pub fn allow_degenerate(mut self, allow: bool) -> Self {
+        self.allow_degenerate = allow;
+        self
+    }
+
+    /// Remove the exact duplicates from the input before giving it to qhull
+    ///
+    /// Duplicate points make qhull do more work and print warnings,
+    /// and produce degenerate simplices in Delaunay triangulations.
+    /// With this option, only the first occurrence of each point is given to qhull
+    /// and the instance owns the deduplicated copy of the input.
+    ///
+    /// The indices reported by the instance ([`Vertex::index`](crate::Vertex::index), [`Qh::simplex_indices`], [`Qh::point`], ...)
+    /// refer to the deduplicated points: use [`Qh::original_index`] to get the index in the input
+    /// and [`Qh::deduped_index`] for the opposite.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::collections::BTreeSet;
+    /// let unique: Vec<[f64; 2]> = (0..70)
+    ///     .map(|i| {
+    ///         let (r, t) = (1.0 + i as f64 / 70.0, i as f64 * 0.37);
+    ///         [r * t.cos(), r * t.sin()]
+    ///     })
+    ///     .collect();
+    /// // 30 duplicates, interleaved with the unique points
+    /// let mut points = Vec::new();
+    /// for i in 0..70 {
+    ///     points.push(unique[i]);
+    ///     if i % 7 < 3 {
+    ///         points.push(unique[i / 2]);
+    ///     }
+    /// }
+    /// assert_eq!(points.len(), 100);
+    ///
+    /// let qh = Qh::builder().dedup_points(true).build_from_points(&points).unwrap();
+    /// assert_eq!(qh.num_points(), 70);
+    ///
+    /// // same hull as the unique points
+    /// let expected = Qh::builder().build_from_points(&unique).unwrap();
+    /// let hull = |qh: &Qh, points: &[[f64; 2]], original: bool| {
+    ///     qh.vertices()
+    ///         .map(|v| v.index(qh).unwrap())
+    ///         .map(|i| if original { qh.original_index(i).unwrap() } else { i })
+    ///         .map(|i| points[i].map(f64::to_bits))
+    ///         .collect::<BTreeSet<_>>()
+    /// };
+    /// assert_eq!(hull(&qh, &points, true), hull(&expected, &unique, false));
+    ///
+    /// // indices in both directions
+    /// for v in qh.vertices() {
+    ///     let index = v.index(&qh).unwrap();
+    ///     let original = qh.original_index(index).unwrap();
+    ///     assert_eq!(qh.point(index).unwrap(), &points[original]);
+    ///     assert_eq!(qh.deduped_index(original), Some(index));
+    /// }
+    /// // the duplicate of `unique[0]` is the second input point
+    /// assert_eq!(qh.deduped_index(1), Some(0));
+    ///
+    /// // qhull scales the deduplicated copy in place ('Qbb'), a retry starts again from the input
+    /// let (dim, coords) = rbox::generate("1000 W1e-13 D4 t1").unwrap();
+    /// let duplicated = [&coords[..], &coords[..400]].concat();
+    /// let qh = Qh::builder()
+    ///     .dedup_points(true)
+    ///     .no_premerge(true)
+    ///     .scale_last_coordinate(true)
+    ///     .retry_on_precision_error(3)
+    ///     .build_from_flat(dim, &duplicated)
+    ///     .unwrap();
+    /// assert!(qh.joggle_applied().is_some());
+    /// assert_eq!(qh.num_points(), 1000);
+    /// assert_eq!(qh.original_index(99), Some(99));
+    /// assert_eq!(qh.deduped_index(1099), Some(99));
+    /// ```
+    pub fn dedup_points(mut self, dedup: bool) -> Self {
+        self.dedup_points = dedup;
+        self
+    }
+
+    /// Skip the validation of the input
+    ///
+    /// By default, the points are checked before they are given to qhull, in a single pass.
+    /// The build fails with [`QhErrorKind::InvalidInput`] and a description of the problem if:
+    /// * there are fewer than `d + 1` points, where `d` is the dimension of the hull
+    ///   (unless [`allow_short`](QhBuilder::allow_short) is set)
+    /// * a coordinate is not finite
+    /// * all the points are identical
+    ///
+    /// Qhull would fail on these inputs anyway, with less readable errors.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let invalid_inputs = [
+    ///     (vec![[0.0, 0.0], [1.0, 0.0]], "not enough"),
+    ///     (vec![[0.0, 0.0], [1.0, 0.0], [0.0, f64::NAN]], "not finite"),
+    ///     (vec![[1.0, 1.0]; 4], "identical"),
+    /// ];
+    /// for (points, description) in invalid_inputs {
+    ///     let Err(error) = Qh::builder().build_from_iter(points) else {
+    ///         panic!("invalid input was accepted");
+    ///     };
+    ///     assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    ///     assert!(error.error_message.unwrap().contains(description));
+    /// }
+    ///
+    /// // without validation, qhull reports the error
+    /// let Err(error) = Qh::builder()
+    ///     .skip_validation(true)
+    ///     .build_from_iter([[1.0, 1.0]; 4])
+    /// else {
+    ///     panic!("identical points were accepted");
+    /// };
+    /// assert!(error.error_message.unwrap().contains("QH6"));
+    /// ```
+    pub fn skip_validation(mut self, skip: bool) -> Self {
+        self.skip_validation = skip;
+        self
+    }
+
+    /// Set the output format of qhull
     ///
-    /// When enabled, [`Qh::check_points`] will be called after computing the hull.
-    /// If [`compute`](QhBuilder::compute) is disabled, this setting will have no effect.
-    pub fn check_points(mut self, check: bool) -> Self {
-        self.check_points = check;
-        self
+    /// Same as [`output_formats`](QhBuilder::output_formats) with a single format.
+    ///
+    /// To get the output of a computed hull as a string, see [`Qh::produce_output`].
+    pub fn output_format(self, format: PrintFormat) -> Self {
+        self.output_formats([format])
+    }
+
+    /// Set the output formats of qhull
+    ///
+    /// This fills [`PRINTout`](crate::sys::qhT::PRINTout) with the formats and clears the other entries,
+    /// see [`print_out`](QhBuilder::print_out) for the raw setter.
+    /// The formats are used by qhull's own printing functions, such as `qh_produce_output`.
+    ///
+    /// The options required by the formats are enabled as the qhull programs do,
+    /// e.g. [`PrintFormat::Area`] and [`PrintFormat::Size`] enable the computation of the facet areas
+    /// ([`get_area`](QhBuilder::get_area)).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .output_formats([PrintFormat::Area, PrintFormat::Off])
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    /// let qh = unsafe { &*Qh::raw_ptr(&qh) };
+    /// assert_ne!(qh.GETarea, 0);
+    /// assert_eq!(&qh.PRINTout[..3], [sys::qh_PRINT_qh_PRINTarea, sys::qh_PRINT_qh_PRINToff, sys::qh_PRINT_qh_PRINTnone]);
+    ///
+    /// // too many formats
+    /// let error = Qh::builder()
+    ///     .output_formats([PrintFormat::Off; 100])
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    ///
+    /// # Errors
+    /// Building fails with [`QhErrorKind::InvalidInput`] if more than `qh_PRINTEND` formats are given.
+    pub fn output_formats(self, formats: impl IntoIterator<Item = PrintFormat>) -> Self {
+        let formats: Vec<PrintFormat> = formats.into_iter().collect();
+        unsafe {
+            self.with_configure(move |qh| {
+                let raw = qh.qh.get_mut();
+                if formats.len() > raw.PRINTout.len() {
+                    return Err(QhError::invalid_input(format!(
+                        "at most {} output formats can be set, {} were given",
+                        raw.PRINTout.len(),
+                        formats.len(),
+                    )));
+                }
+                raw.PRINTout = [sys::qh_PRINT_qh_PRINTnone; sys::qh_PRINT_qh_PRINTEND as usize];
+                for (target, format) in raw.PRINTout.iter_mut().zip(&formats) {
+                    *target = format.to_raw();
+                    if format.needs_area() {
+                        raw.GETarea = true as _;
+                    }
+                }
+                Ok(())
+            })
+        }
     }
 
     /// Build a Qhull instance
@@ -154,24 +1434,234 @@ impl QhBuilder {
     /// assert_eq!(qh.num_facets(), 3);
     /// ```
     ///
+    /// The returned instance borrows the points, so it cannot outlive them:
+    /// ```compile_fail
+    /// # use qhull::*;
+    /// let qh = {
+    ///     let mut points = vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+    ///     QhBuilder::default().build(2, &mut points).unwrap()
+    /// }; // `points` dropped here while still borrowed
+    /// assert_eq!(qh.num_facets(), 3);
+    /// ```
+    ///
+    /// Use [`QhBuilder::build_managed`] to let the instance own the points.
+    ///
+    /// Qhull only supports hulls of dimension 2 or more, other dimensions fail with
+    /// [`QhErrorKind::UnsupportedDimension`]. The hull of 1-dimensional points is the interval
+    /// between the smallest and the largest point, their Delaunay triangulation can be computed
+    /// by qhull since it is lifted to dimension 2 (see [`Qh::new_delaunay`]):
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0], [2.0], [1.0]];
+    ///
+    /// let Err(error) = Qh::builder().build_from_iter(points) else {
+    ///     panic!("1-d hull was accepted");
+    /// };
+    /// assert_eq!(error.kind, QhErrorKind::UnsupportedDimension);
+    ///
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    /// let mut simplices = qh.simplex_indices();
+    /// simplices.iter_mut().for_each(|s| s.sort());
+    /// simplices.sort();
+    /// assert_eq!(simplices, [[0, 2], [1, 2]]);
+    /// ```
+    ///
+    /// # Errors
+    /// Besides the errors of qhull, an [`InvalidInput`](QhErrorKind::InvalidInput) error if:
+    /// * the dimension is 0
+    /// * the number of coordinates is not divisible by the dimension
+    /// * the dimension does not match the [hint](QhBuilder::dim)
+    /// * the points fail the [validation](QhBuilder::skip_validation)
+    ///
+    /// ```
+    /// # use qhull::*;
+    /// let mut points = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.25];
+    /// let error = Qh::builder().build(2, &mut points).unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    ///
+    /// let error = Qh::builder().build(0, &mut points).unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    ///
+    /// let mut points = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+    /// let error = Qh::builder().dim(3).build(2, &mut points).unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    ///
     /// # Panics
-    /// * If the number of points is not divisible by the dimension
-    /// * If the dimensionality of the points does not match the hint
-    /// * Cannot create a temporary file for capturing stdout or stderr
-    pub fn build(self, dim: usize, points: &mut [f64]) -> Result<Qh, QhError> {
+    /// If a temporary file for capturing stdout or stderr cannot be created.
+    pub fn build<'a>(self, dim: usize, points: &'a mut [f64]) -> Result<Qh<'a>, QhError<'static>> {
+        self.build_impl(dim, Points::Mutable(points))
+    }
+
+    /// Build a Qhull instance from shared points
+    ///
+    /// Unlike [`QhBuilder::build`], the points are never modified:
+    /// if the options would make qhull modify the input in place,
+    /// the points are copied into a buffer owned by the instance, otherwise they are used directly.
+    ///
+    /// The only option that forces a copy is [`scale_last_coordinate`](QhBuilder::scale_last_coordinate) (`Qbb`),
+    /// unless qhull already works on its own copy of the points, that is
+    /// with [`project_input`](QhBuilder::project_input) or with
+    /// [`delaunay`](QhBuilder::delaunay) and [`project_delaunay`](QhBuilder::project_delaunay)
+    /// (as in [`Qh::new_delaunay`]).
+    /// The other options that transform the points do not force a copy, as qhull copies the input itself:
+    /// [scaling](QhBuilder::scale_input) (`Qbk`, `QBk`), [rotation](QhBuilder::rotate_random) (`QRn`)
+    /// and [joggle](QhBuilder::joggle) (`QJ`).
+    /// The points of the instance are then that copy.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = vec![
+    ///     0.0, 0.0,
+    ///     2.0, 0.0,
+    ///     0.0, 2.0,
+    ///     0.5, 0.5,
+    /// ];
+    ///
+    /// // the points are used directly
+    /// let qh = QhBuilder::default().build_from_slice(2, &points).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// assert_eq!(qh.point(0).unwrap().as_ptr(), points.as_ptr());
+    ///
+    /// // scaling would modify the points, a copy is used instead
+    /// let qh = QhBuilder::default()
+    ///     .scale_last(true)
+    ///     .build_from_slice(2, &points)
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// assert_ne!(qh.point(0).unwrap().as_ptr(), points.as_ptr());
+    /// assert_eq!(points, [0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 0.5, 0.5]);
+    ///
+    /// // qhull rotates and scales its own copy, even of mutable points
+    /// let mut mutable = points.clone();
+    /// let mutable_ptr = mutable.as_ptr();
+    /// let qh = QhBuilder::default()
+    ///     .rotate_random(1)
+    ///     .scale_dimension(0, -1.0, 1.0)
+    ///     .build(2, &mut mutable)
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// assert_ne!(qh.point(0).unwrap().as_ptr(), mutable_ptr);
+    /// drop(qh);
+    /// assert_eq!(mutable, points);
+    /// ```
+    ///
+    /// # Errors
+    /// See [`QhBuilder::build`].
+    pub fn build_from_slice<'a>(self, dim: usize, points: &'a [f64]) -> Result<Qh<'a>, QhError<'static>> {
+        self.build_impl(dim, Points::Shared(points))
+    }
+
+    /// Shared implementation of [`QhBuilder::build`] and [`QhBuilder::build_from_slice`]
+    fn build_impl<'a>(self, dim: usize, mut points: Points<'a>) -> Result<Qh<'a>, QhError<'static>> {
+        check_layout(dim, points.len())?;
         if let Some(dim_hint) = self.dim {
-            assert_eq!(
-                dim, dim_hint,
-                "data dimensionality does not match hint that was given with QhBuilder::dim"
-            );
+            if dim != dim_hint {
+                return Err(QhError::invalid_input(format!(
+                    "the points have dimension {}, but the dimension hint given with QhBuilder::dim is {}",
+                    dim, dim_hint,
+                )));
+            }
+        }
+
+        // the instance owns the deduplicated points, which qhull is free to modify
+        let (mut deduped, mut point_map) = match self.dedup_points.then(|| dedup::dedup(dim, points.as_slice())).flatten() {
+            Some((coords, map)) => (Some(coords), Some(map)),
+            None => (None, None),
+        };
+        if let Some(coords) = &mut deduped {
+            // qhull is free to modify the copy, it is restored before each retry as the points of the caller.
+            // SAFETY: the heap buffer does not move when the vector is moved into the returned instance,
+            // the instances of failed attempts are dropped before it
+            points = Points::Mutable(unsafe { std::slice::from_raw_parts_mut(coords.as_mut_ptr(), coords.len()) });
+        }
+
+        // the points before qhull modifies them in place, restored before each retry
+        let retry = self.compute && (self.retry_on_precision_error > 0 || self.allow_degenerate);
+        let mut original = None;
+
+        let mut joggle = None;
+        let mut retries = 0;
+        loop {
+            let mut qh = self.init(dim, &mut points, joggle, retry.then_some(&mut original))?;
+
+            if self.compute {
+                if let Err(e) = qh.compute() {
+                    let e = e.into_static();
+                    if retries < self.retry_on_precision_error && is_precision_error(e.kind) {
+                        retries += 1;
+                        joggle = Some(qh.joggle_applied().map_or(0.0, |j| j * sys::qh_JOGGLEincrease));
+                        continue;
+                    }
+                    if self.allow_degenerate
+                        && e.kind == QhErrorKind::DegenerateInput
+                        && qh.joggle_applied().is_none()
+                    {
+                        joggle = Some(0.0);
+                        continue;
+                    }
+                    return Err(e);
+                }
+                if self.check_output {
+                    qh.check_output().map_err(|e| e.into_static())?;
+                }
+                if self.check_points {
+                    qh.check_points().map_err(|e| e.into_static())?;
+                }
+                if self.check_convexity {
+                    qh.check_convexity().map_err(|e| e.into_static())?;
+                }
+            }
+
+            if deduped.is_some() {
+                qh.coords_holder = deduped.take();
+                qh.point_map = point_map.take();
+            }
+            return Ok(qh);
         }
+    }
 
-        assert_eq!(points.len() % dim, 0, "points.len() % dim != 0");
+    /// Create the qhull instance and initialize it with the points, without computing the hull
+    ///
+    /// `joggle` overrides the joggle set by the configurations.
+    /// If the configured options would modify the points, [shared](Points::Shared) points are copied
+    /// into the instance, while [mutable](Points::Mutable) points are modified in place:
+    /// with `original`, they are saved there on the first call and restored on the following ones,
+    /// so that each attempt starts from the input of the caller.
+    ///
+    /// The instance borrows the points, the caller must tie `'a` to their lifetime.
+    fn init<'a>(
+        &self,
+        dim: usize,
+        points: &mut Points<'_>,
+        joggle: Option<f64>,
+        original: Option<&mut Option<Vec<f64>>>,
+    ) -> Result<Qh<'a>, QhError<'static>> {
         let num_points = points.len() / dim;
 
         unsafe {
             let mut qh: sys::qhT = std::mem::zeroed();
-            let buffers = IOBuffers::new(self.capture_stdout, self.capture_stderr);
+            let mut buffers = IOBuffers::with_writers(
+                self.capture_stdout && self.stdout_file.is_none(),
+                self.capture_stderr && self.stderr_file.is_none(),
+                self.stdout_sink.clone(),
+                self.stderr_sink.clone(),
+            );
+            let file_error = |path: &Path, what: &str, e: std::io::Error| {
+                QhError::invalid_input(format!("cannot open {} for {}: {}", path.display(), what, e))
+            };
+            // a writer or a message handler takes precedence, the file is not even opened
+            if let Some(path) = self.stdout_file.as_ref().filter(|_| self.stdout_sink.is_none()) {
+                TmpFile::from_path(path)
+                    .and_then(|file| buffers.use_out_file(file))
+                    .map_err(|e| file_error(path, "stdout", e))?;
+            }
+            if let Some(path) = self.stderr_file.as_ref().filter(|_| self.stderr_sink.is_none()) {
+                TmpFile::from_path(path)
+                    .and_then(|file| buffers.use_err_file(file))
+                    .map_err(|e| file_error(path, "stderr", e))?;
+            }
 
             // Note: this function cannot be called
             // inside of a try
@@ -190,33 +1680,118 @@ impl QhBuilder {
                 dim,
                 buffers: RefCell::new(buffers),
                 owned_values: Default::default(),
+                build_settings: None,
+                point_map: None,
+                input_rotation: None,
+                input_scaling: Vec::new(),
+                random_seed: None,
+                computed: false,
+                point_vertices: None,
                 phantom: PhantomData,
             };
+            qh.buffers.get_mut().attach(qh.qh.get_mut());
 
-            for config in self.configs {
+            for config in &self.configs {
                 config(&mut qh).map_err(|e| e.into_static())?;
             }
 
+            if let Some(joggle) = joggle {
+                qh.qh.get_mut().JOGGLEmax = joggle;
+            }
+
+            if qh.qh.get_mut().qhull_command[0] == 0 {
+                let command = command_line(qh.qh.get_mut());
+                let target = &mut qh.qh.get_mut().qhull_command;
+                let len = command.len().min(target.len() - 1);
+                for (c, &b) in target.iter_mut().zip(&command.as_bytes()[..len]) {
+                    *c = b as _;
+                }
+                target[len] = 0;
+            }
+            if !qh.owned_values.scale_bounds.is_empty() {
+                // `qh_init_B` reads the bounds from the command line, after allocating them
+                let bounds = bound_options(&qh.owned_values.scale_bounds);
+                let target = &mut qh.qh.get_mut().qhull_command;
+                let start = target.iter().position(|&c| c == 0).unwrap_or(target.len());
+                if start + bounds.len() >= target.len() {
+                    return Err(QhError::invalid_input(format!(
+                        "the bounds of the {} scaled coordinates do not fit in the qhull command line",
+                        qh.owned_values.scale_bounds.len(),
+                    )));
+                }
+                for (c, &b) in target[start..].iter_mut().zip(bounds.as_bytes()) {
+                    *c = b as _;
+                }
+                target[start + bounds.len()] = 0;
+                qh.input_scaling = input_scaling(&qh.owned_values.scale_bounds, dim, points.as_slice());
+            }
+
+            let hull_dim = hull_dim(qh.qh.get_mut(), dim);
+            if hull_dim < 2 {
+                return Err(QhError::unsupported_dimension(format!(
+                    "qhull does not support hulls of dimension {}, the dimension must be at least 2",
+                    hull_dim,
+                )));
+            }
+
+            if let Some(matrix) = &qh.owned_values.input_rotation {
+                check_rotation(matrix, rotation_dim(qh.qh.get_mut(), hull_dim))?;
+            }
+
+            if !self.skip_validation {
+                validate_input(qh.qh.get_mut(), dim, points.as_slice())?;
+            }
+
+            if let Some(len) = qh.owned_values.feasible_point_len.filter(|&len| len != hull_dim) {
+                return Err(QhError::invalid_input(format!(
+                    "the feasible point has {} coordinates, but the hull has dimension {}",
+                    len, hull_dim,
+                )));
+            }
+
+            // as `qh_readpoints`, parse the `Hn,n,n` option unless the point is given
+            let raw = qh.qh.get_mut();
+            if raw.HALFspace != 0 && !raw.feasible_string.is_null() && raw.feasible_point.is_null() {
+                Qh::try_on_qh_mut(&mut qh, |qh| sys::qh_setfeasible(qh, hull_dim as _)).map_err(|e| e.into_static())?;
+            }
+
+            let modifies = modifies_input(qh.qh.get_mut());
+            let points_ptr = match points {
+                Points::Shared(points) if modifies => {
+                    // the heap buffer does not move when the vector is moved into the instance
+                    let mut copy = points.to_vec();
+                    let points_ptr = copy.as_mut_ptr();
+                    qh.coords_holder = Some(copy);
+                    points_ptr
+                }
+                // qhull only reads the points
+                Points::Shared(points) => points.as_ptr() as *mut f64,
+                Points::Mutable(points) => {
+                    if let Some(original) = original.filter(|_| modifies) {
+                        match original {
+                            Some(original) => points.copy_from_slice(original),
+                            None => *original = Some(points.to_vec()),
+                        }
+                    }
+                    points.as_mut_ptr()
+                }
+            };
+
             Qh::try_on_qh_mut(&mut qh, |qh| {
                 sys::qh_init_B(
                     qh,
-                    points.as_ptr() as *mut f64,
+                    points_ptr,
                     num_points as _,
                     dim as _,
                     false as _,
                 );
             })
             .map_err(|e| e.into_static())?;
+            finish_init(&mut qh)?;
 
-            if self.compute {
-                qh.compute().map_err(|e| e.into_static())?;
-                if self.check_output {
-                    qh.check_output().map_err(|e| e.into_static())?;
-                }
-                if self.check_points {
-                    qh.check_points().map_err(|e| e.into_static())?;
-                }
-            }
+            // qhull might have projected the points to a different dimension (e.g. Delaunay lifting)
+            qh.dim = (*qh.qh.get()).hull_dim as usize;
+            qh.build_settings = Some(BuildSettings::capture(qh.qh.get_mut()));
 
             Ok(qh)
         }
@@ -244,18 +1819,142 @@ impl QhBuilder {
     pub fn build_managed(
         self,
         dim: usize,
-        points: impl ToOwned<Owned = Vec<f64>>,
+        points: impl Into<Vec<f64>>,
     ) -> Result<Qh<'static>, QhError<'static>> {
-        let mut points = points.to_owned();
-        let points_ptr = points.as_mut_ptr();
-        let mut qh: Qh<'static> = self.build(dim, unsafe {
-            std::slice::from_raw_parts_mut(points_ptr, points.len())
-        })?;
-        assert!(qh.coords_holder.is_none());
-        qh.coords_holder = Some(points);
+        // `Into` moves a `Vec` without copying it
+        let mut points = points.into();
+        // SAFETY: the buffer is moved into the instance (`coords_holder`) and dropped with it,
+        // moving a `Vec` does not move its heap allocation, so the slice is valid
+        // for the whole lifetime of the instance even though it is not really `'static`.
+        let points_slice: &'static mut [f64] =
+            unsafe { std::slice::from_raw_parts_mut(points.as_mut_ptr(), points.len()) };
+        let mut qh = self.build(dim, points_slice)?;
+        // with `dedup_points`, the instance already owns a deduplicated copy
+        if qh.coords_holder.is_none() {
+            qh.coords_holder = Some(points);
+        }
         Ok(qh)
     }
 
+    /// Build a Qhull instance from a flat slice of coordinates
+    ///
+    /// This is the simplest way to build an instance from a slice:
+    /// the coordinates are copied once into a buffer owned by the instance,
+    /// so the slice is not borrowed by the result.
+    /// Use [`QhBuilder::build_from_slice`] to avoid the copy when the instance can borrow the points.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// fn hull(points: &[f64]) -> Qh<'static> {
+    ///     Qh::builder().build_from_flat(2, points).unwrap()
+    /// }
+    ///
+    /// let qh = hull(&[
+    ///     0.0, 0.0,
+    ///     1.0, 0.0,
+    ///     0.0, 1.0,
+    ///     0.25, 0.25,
+    /// ]);
+    /// assert_eq!(qh.num_facets(), 3);
+    /// ```
+    ///
+    /// # Errors
+    /// See [`QhBuilder::build`].
+    pub fn build_from_flat(self, dim: usize, points: &[f64]) -> Result<Qh<'static>, QhError<'static>> {
+        check_layout(dim, points.len())?;
+        self.build_managed(dim, Vec::from(points))
+    }
+
+    /// Build a Qhull instance from a slice of fixed-size points
+    ///
+    /// The dimension is `N`, the coordinates are copied once as in [`QhBuilder::build_from_flat`]:
+    /// unlike [`QhBuilder::build_from_iter`], no intermediate buffer is allocated for each point.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_points(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// let qh = Qh::builder()
+    ///     .build_from_points(&[
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///         [0.1, 0.1, 0.1],
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    ///
+    /// // the 8-d simplex and a point inside
+    /// let mut points = vec![[0.0; 8]];
+    /// for i in 0..8 {
+    ///     let mut vertex = [0.0; 8];
+    ///     vertex[i] = 1.0;
+    ///     points.push(vertex);
+    /// }
+    /// points.push([0.1; 8]);
+    /// let qh = Qh::builder().build_from_points(&points).unwrap();
+    /// assert_eq!(qh.dim(), 8);
+    /// assert_eq!(qh.num_facets(), 9);
+    /// assert_eq!(qh.num_vertices(), 9);
+    /// ```
+    ///
+    /// Points without coordinates are rejected at compile time:
+    /// ```compile_fail
+    /// # use qhull::*;
+    /// let points: [[f64; 0]; 3] = [[], [], []];
+    /// let qh = Qh::builder().build_from_points(&points);
+    /// ```
+    pub fn build_from_points<const N: usize>(self, points: &[[f64; N]]) -> Result<Qh<'static>, QhError<'static>> {
+        const { assert!(N > 0, "points must have at least one coordinate") };
+        self.build_from_flat(N, points.as_flattened())
+    }
+
+    /// Build a Qhull instance from points given as slices of coordinates
+    ///
+    /// Accepts any [`PointSource`](crate::helpers::PointSource):
+    /// `&[&[f64]]`, `Vec<Vec<f64>>`, `&[[f64; N]]`, iterators of slices...
+    /// The dimension is the length of the first point,
+    /// the coordinates are copied once in a buffer sized from the number of points when it is known.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let slices: &[&[f64]] = &[&[0.0, 0.0], &[1.0, 0.0], &[0.0, 1.0], &[0.25, 0.25]];
+    /// let qh = Qh::builder().build_from_source(slices).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// let vecs = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0], vec![0.25, 0.25]];
+    /// let qh = Qh::builder().build_from_source(&vecs).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// let qh = Qh::builder().build_from_source(vecs).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// let arrays = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]];
+    /// let qh = Qh::builder().build_from_source(&arrays).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    ///
+    /// let flat = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.25, 0.25];
+    /// let qh = Qh::builder().build_from_source(flat.chunks(2)).unwrap();
+    /// assert_eq!(qh.num_facets(), 3);
+    /// ```
+    ///
+    /// # Errors
+    /// See [`collect_point_source`](crate::helpers::collect_point_source) and [`QhBuilder::build`].
+    pub fn build_from_source(self, points: impl PointSource) -> Result<Qh<'static>, QhError<'static>> {
+        let CollectedCoords {
+            coords,
+            count: _,
+            dim,
+        } = collect_point_source(points)?;
+        self.build_managed(dim, coords)
+    }
+
     /// Build a Qhull instance from an iterator of points
     ///
     /// # Example
@@ -271,6 +1970,19 @@ impl QhBuilder {
     ///
     /// assert_eq!(qh.num_facets(), 3);
     /// ```
+    ///
+    /// # Errors
+    /// See [`try_collect_coords`](crate::helpers::try_collect_coords) and [`QhBuilder::build`].
+    /// ```
+    /// # use qhull::*;
+    /// let error = Qh::builder().build_from_iter(Vec::<[f64; 2]>::new()).unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    ///
+    /// let error = Qh::builder()
+    ///     .build_from_iter([vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0, 0.0]])
+    ///     .unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
     pub fn build_from_iter<I>(
         self,
         points: impl IntoIterator<Item = I>,
@@ -282,7 +1994,52 @@ impl QhBuilder {
             coords,
             count: _,
             dim,
-        } = collect_coords(points);
+        } = try_collect_coords(points)?;
+        self.build_managed(dim, coords)
+    }
+
+    /// Build a Qhull instance from an iterator of points, with a known number of points
+    ///
+    /// Like [`QhBuilder::build_from_iter`], but the coordinates are collected in a buffer
+    /// allocated upfront for `expected_points` points of dimension `dim`,
+    /// which avoids reallocations when the iterator does not know its length.
+    /// `expected_points` is only a hint, the build works with any number of points.
+    ///
+    /// This build fails with an [`InvalidInput`](QhErrorKind::InvalidInput) error
+    /// if the points are not of dimension `dim`.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = (0..100)
+    ///     .map(|i| [(i % 10) as f64, (i / 10) as f64])
+    ///     .filter(|p| p[0] != 5.0);
+    /// let qh = QhBuilder::default()
+    ///     .build_from_iter_with_capacity(points, 90, 2)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(qh.num_facets(), 4);
+    /// ```
+    pub fn build_from_iter_with_capacity<I>(
+        self,
+        points: impl IntoIterator<Item = I>,
+        expected_points: usize,
+        dim: usize,
+    ) -> Result<Qh<'static>, QhError<'static>>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let CollectedCoords {
+            coords,
+            count: _,
+            dim: collected_dim,
+        } = try_collect_coords_with_capacity(points, expected_points.saturating_mul(dim))?;
+        if collected_dim != dim {
+            return Err(QhError::invalid_input(format!(
+                "the points have dimension {}, but the expected dimension is {}",
+                collected_dim, dim,
+            )));
+        }
         self.build_managed(dim, coords)
     }
 
@@ -315,9 +2072,197 @@ impl QhBuilder {
         self
     }
 
+    /// Set a tolerance of qhull if `valid`, for the safe setters like [`QhBuilder::approximate_hull`]
+    fn tolerance(
+        self,
+        option: &'static str,
+        value: f64,
+        valid: bool,
+        expected: &'static str,
+        set: impl Fn(&mut sys::qhT) + 'static,
+    ) -> Self {
+        unsafe {
+            self.with_configure(move |qh| {
+                if !valid || !value.is_finite() {
+                    return Err(QhError::invalid_input(format!(
+                        "invalid value {} for option '{}', expected {}",
+                        value, option, expected,
+                    )));
+                }
+                Qh::try_on_qh_mut(qh, |qh| set(&mut *qh))
+            })
+        }
+    }
+
     // TODO args and checkflags
 }
 
+/// Finish the initialization of the input after `qh_init_B`
+///
+/// Rotates the input with [`QhBuilder::rotate_input`] and sets the seed of [`QhBuilder::random_seed`],
+/// `qh_init_B` seeds the random numbers with the seed of `QRn`.
+pub(crate) unsafe fn finish_init(qh: &mut Qh) -> Result<(), QhError<'static>> {
+    rotate_input(qh)?;
+    if let Some(seed) = qh.random_seed {
+        // the states of the generator are 1 to 2^31 - 2
+        sys::qh_srand(qh.qh.get_mut(), (seed % (i32::MAX as u32 - 1) + 1) as _);
+    }
+    Ok(())
+}
+
+/// The points given to [`QhBuilder::init`]
+enum Points<'a> {
+    /// Points that qhull can modify in place
+    Mutable(&'a mut [f64]),
+    /// Points that must not be modified, they are copied if the options would modify them
+    Shared(&'a [f64]),
+}
+
+impl Points<'_> {
+    fn as_slice(&self) -> &[f64] {
+        match self {
+            Points::Mutable(points) => points,
+            Points::Shared(points) => points,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+}
+
+/// Whether qhull will modify the input points in place
+///
+/// `Qbb` scales `qh.first_point` in place (`qh_initbuild`), which is the input array
+/// unless it has already been replaced by a projected copy.
+/// Scaling (`qh_scaleinput`), rotation (`qh_rotateinput`) and joggle copy the input before modifying it.
+fn modifies_input(qh: &sys::qhT) -> bool {
+    let projected = qh.PROJECTinput != 0 || (qh.DELAUNAY != 0 && qh.PROJECTdelaunay != 0);
+    qh.SCALElast != 0 && !projected
+}
+
+/// Check that `len` coordinates are points of dimension `dim`
+pub(crate) fn check_layout(dim: usize, len: usize) -> Result<(), QhError<'static>> {
+    if dim == 0 {
+        return Err(QhError::invalid_input("the dimension must be at least 1"));
+    }
+    if !len.is_multiple_of(dim) {
+        return Err(QhError::invalid_input(format!(
+            "{} coordinates are not a whole number of points of dimension {}",
+            len, dim,
+        )));
+    }
+    Ok(())
+}
+
+/// Dimension of the hull built from points of dimension `dim`
+///
+/// Same as `qh_initqhull_globals`: the input may be projected (`Qbk:0Bk:0`) or lifted (Delaunay).
+pub(crate) fn hull_dim(qh: &sys::qhT, dim: usize) -> usize {
+    if qh.PROJECTinput != 0 || (qh.DELAUNAY != 0 && qh.PROJECTdelaunay != 0) {
+        (dim + (qh.DELAUNAY != 0) as usize).saturating_sub(qh.PROJECTinput as usize)
+    } else {
+        dim
+    }
+}
+
+/// The qhull command line equivalent to the options of an instance, e.g. `qhull d Qbb Qt`
+///
+/// Only the options that change the result of the computation are listed.
+/// The builder sets the options directly, this line is written to `qh.qhull_command`
+/// so that the messages of qhull and [`QhError::options`] show how to reproduce the computation.
+pub(crate) fn command_line(qh: &sys::qhT) -> String {
+    let mut line = String::from("qhull");
+    let mut option = |enabled: bool, name: &str| {
+        if enabled {
+            line.push(' ');
+            line.push_str(name);
+        }
+    };
+    option(qh.VORONOI != 0, "v");
+    option(qh.DELAUNAY != 0 && qh.VORONOI == 0, "d");
+    option(qh.HALFspace != 0, "H");
+    option(qh.UPPERdelaunay != 0, "Qu");
+    option(qh.SCALElast != 0, "Qbb");
+    option(qh.ATinfinity != 0, "Qz");
+    option(qh.TRIangulate != 0 && qh.TRInormals == 0, "Qt");
+    option(qh.TRInormals != 0, "Q11");
+    option(qh.KEEPcoplanar != 0, "Qc");
+    option(qh.KEEPinside != 0, "Qi");
+    option(qh.ALLpoints != 0, "Qs");
+    option(qh.BESToutside != 0, "Qf");
+    option(qh.ONLYgood != 0, "Qg");
+    option(qh.ONLYmax != 0, "Qm");
+    option(qh.RANDOMoutside != 0, "Qr");
+    option(qh.MERGEexact != 0, "Qx");
+    option(qh.NOpremerge != 0, "Q0");
+    option(qh.ANGLEmerge != 0, "Q1");
+    option(qh.MERGEindependent == 0, "Q2");
+    option(qh.MERGEvertices == 0, "Q3");
+    option(qh.AVOIDold != 0, "Q4");
+    option(qh.SKIPcheckmax != 0, "Q5");
+    option(qh.SKIPconvex != 0, "Q6");
+    option(qh.VIRTUALmemory != 0, "Q7");
+    option(qh.NOnearinside != 0, "Q8");
+    option(qh.PICKfurthest != 0, "Q9");
+    option(qh.NOnarrow != 0, "Q10");
+    option(qh.ALLOWwide != 0, "Q12");
+    option(qh.MERGEpinched != 0, "Q14");
+    option(qh.CHECKduplicates != 0, "Q15");
+    option(qh.ALLOWshort != 0, "Qa");
+    option(qh.ALLOWwarning != 0, "Qw");
+    option(qh.PRINTprecision == 0, "Pp");
+    if qh.JOGGLEmax < f64::MAX / 2.0 {
+        line.push_str(&format!(" QJ{}", qh.JOGGLEmax));
+    }
+    if qh.ROTATErandom >= 0 {
+        line.push_str(&format!(" QR{}", qh.ROTATErandom));
+    }
+    if qh.IStracing > 0 {
+        line.push_str(&format!(" T{}", qh.IStracing));
+    }
+    line
+}
+
+/// Check the points before giving them to qhull, see [`QhBuilder::skip_validation`]
+pub(crate) fn validate_input(qh: &sys::qhT, dim: usize, points: &[f64]) -> Result<(), QhError<'static>> {
+    let num_points = points.len() / dim;
+    let hull_dim = hull_dim(qh, dim);
+    if qh.ALLOWshort == 0 && num_points < hull_dim + 1 {
+        return Err(QhError::invalid_input(format!(
+            "{} points are not enough for a hull of dimension {}, at least {} are needed",
+            num_points,
+            hull_dim,
+            hull_dim + 1,
+        )));
+    }
+
+    let first = &points[..dim.min(points.len())];
+    let mut identical = true;
+    for (index, point) in points.chunks_exact(dim).enumerate() {
+        if let Some(coord) = point.iter().position(|c| !c.is_finite()) {
+            return Err(QhError::invalid_input(format!(
+                "coordinate {} of point {} is not finite: {}",
+                coord, index, point[coord],
+            )));
+        }
+        identical &= point == first;
+    }
+    if identical && num_points > 1 {
+        return Err(QhError::invalid_input(format!("all the {} points are identical", num_points)));
+    }
+
+    Ok(())
+}
+
+/// Errors that can be avoided by joggling the input
+fn is_precision_error(kind: QhErrorKind) -> bool {
+    matches!(
+        kind.error_code() as u32,
+        sys::qh_ERRprec | sys::qh_ERRtopology | sys::qh_ERRwide
+    )
+}
+
 // https://doc.rust-lang.org/book/ch03-02-data-types.html
 
 macro_rules! add_setting {
@@ -520,11 +2465,11 @@ add_setting!(
     scalar(boolT) random_dist => RANDOMdist "true 'Rn' if randomly change distplane and setfacetplane",
     scalar(realT) random_factor => RANDOMfactor "maximum random perturbation",
     scalar(realT) random_a => RANDOMa "qh_randomfactor is randr * RANDOMa + RANDOMb",
-    scalar(realT) random_b => RANDOMb "boolT) RANDOMoutside \"true 'Qr' if select a random outside point",
+    scalar(realT) random_b => RANDOMb "qh_randomfactor is randr * RANDOMa + RANDOMb",
+    scalar(boolT) random_outside => RANDOMoutside "true 'Qr' if select a random outside point",
     scalar(int)   report_freq => REPORTfreq "TFn' buildtracing reports every n facets",
     scalar(int)   report_freq_2 => REPORTfreq2 "tracemerging reports every REPORTfreq/2 facets",
     scalar(int)   rerun => RERUN "TRn' rerun qhull n times (qh.build_cnt)",
-    scalar(int)   rotate_random => ROTATErandom "QRn' n<-1 random seed, n==-1 time is seed, n==0 random rotation by time, n>0 rotate input",
     scalar(boolT) scale_input => SCALEinput "true 'Qbk' if scaling input",
     scalar(boolT) scale_last => SCALElast "true 'Qbb' if scale last coord to max prev coord",
     scalar(boolT) set_roundoff => SETroundoff "true 'En' if qh.DISTround is predefined",
@@ -552,8 +2497,7 @@ add_setting!(
   /*--------input constants ---------*/
     scalar(realT) area_factor => AREAfactor "1/(hull_dim-1)! for converting det's to area",
     scalar(boolT) do_check_max => DOcheckmax "true if calling qh_check_maxout (!qh.SKIPcheckmax && qh.MERGING)",
-    dyn_array(char*) feasible_string => feasible_string "feasible point 'Hn,n,n' for halfspace intersection",
-    point(coordT*) feasible_point => feasible_point "   as coordinates, both malloc'd",
+    scalar(realT) joggle_max => JOGGLEmax "set 'QJn' if randomly joggle input. 'QJ'/'QJ0.0' sets default (qh_detjoggle)",
     scalar(boolT) get_area => GETarea "true 'Fa', 'FA', 'FS', 'PAn', 'PFn' if compute facet area/Voronoi volume in io_r.c",
     scalar(boolT) keep_near_inside => KEEPnearinside "true if near-inside points in coplanarset",
     //scalar(int)   hull_dim => hull_dim "dimension of hull, set by initbuffers",
@@ -608,7 +2552,6 @@ mod type_mapping {
     pub type realT = f64;
     pub type int = i32;
     pub type pointT = f64;
-    pub type coordT = f64;
     pub type char = i8;
     pub type qh_PRINT = sys::qh_PRINT;
 }
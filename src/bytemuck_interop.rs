@@ -0,0 +1,91 @@
+//! Input from [`bytemuck::Pod`] structs
+
+use crate::{Qh, QhBuilder, QhError};
+
+impl QhBuilder {
+    /// Build a Qhull instance from the coordinates stored in plain old data structs
+    ///
+    /// Each element of `points` has `dim` contiguous `f64` coordinates starting `offset` bytes
+    /// from the beginning of the struct, e.g. the position of an interleaved vertex buffer.
+    ///
+    /// If the structs only contain the coordinates (`offset` is `0` and `T` is `dim` `f64`s)
+    /// and the slice is aligned for `f64`, the points are used without copying them,
+    /// as in [`QhBuilder::build_from_slice`].
+    /// Otherwise the coordinates are gathered into a buffer owned by the instance with a single strided copy.
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    /// #[repr(C)]
+    /// struct MyVertex {
+    ///     pos: [f64; 3],
+    ///     id: u64,
+    /// }
+    ///
+    /// let vertices: Vec<MyVertex> = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.1, 0.1, 0.1]]
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(i, pos)| MyVertex { pos, id: 100 + i as u64 })
+    ///     .collect();
+    /// let qh = Qh::builder().build_from_pod(&vertices, 0, 3).unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    /// for vertex in qh.vertices() {
+    ///     let index = vertex.index(&qh).unwrap();
+    ///     assert_eq!(vertex.point().unwrap(), vertices[index].pos);
+    ///     assert_ne!(vertices[index].id, 104);
+    /// }
+    ///
+    /// // only the first two coordinates
+    /// let qh = Qh::builder().build_from_pod(&vertices, 0, 2).unwrap();
+    /// assert_eq!(qh.num_vertices(), 3);
+    /// // the coordinates must be inside the struct and aligned
+    /// assert!(Qh::builder().build_from_pod(&vertices, 16, 3).is_err());
+    /// assert!(Qh::builder().build_from_pod(&vertices, 4, 2).is_err());
+    /// assert!(Qh::builder().build_from_pod(&vertices, 0, 0).is_err());
+    ///
+    /// // points without other fields are used directly
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+    /// let qh = Qh::builder().build_from_pod(&points, 0, 2).unwrap();
+    /// assert_eq!(qh.point(0).unwrap().as_ptr(), points.as_ptr() as *const f64);
+    /// ```
+    ///
+    /// # Errors
+    /// The build fails with [`QhErrorKind::InvalidInput`](crate::QhErrorKind::InvalidInput) if `dim` is `0`,
+    /// if the coordinates do not fit in `T` or if `offset` is not a multiple of the size of a `f64`,
+    /// see also [`QhBuilder::build`].
+    pub fn build_from_pod<T: bytemuck::Pod>(
+        self,
+        points: &[T],
+        offset: usize,
+        dim: usize,
+    ) -> Result<Qh<'_>, QhError<'static>> {
+        const COORD: usize = std::mem::size_of::<f64>();
+        let size = std::mem::size_of::<T>();
+        if dim == 0 {
+            return Err(QhError::invalid_input("the dimension must be at least 1"));
+        }
+        if !offset.is_multiple_of(COORD) || dim.checked_mul(COORD).and_then(|len| len.checked_add(offset)).is_none_or(|end| end > size) {
+            return Err(QhError::invalid_input(format!(
+                "{} coordinates at offset {} do not fit in a point of {} bytes, or are not aligned to {} bytes",
+                dim, offset, size, COORD,
+            )));
+        }
+
+        if offset == 0 && size == dim * COORD {
+            // fails if the slice is not aligned for f64
+            if let Ok(coords) = bytemuck::try_cast_slice::<T, f64>(points) {
+                return self.build_from_slice(dim, coords);
+            }
+        }
+        let bytes: &[u8] = bytemuck::cast_slice(points);
+        let coords: Vec<f64> = bytes
+            .chunks_exact(size)
+            .flat_map(|point| point[offset..offset + dim * COORD].chunks_exact(COORD))
+            .map(|coord| f64::from_ne_bytes(coord.try_into().unwrap()))
+            .collect();
+        self.build_managed(dim, coords)
+    }
+}
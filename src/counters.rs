@@ -0,0 +1,38 @@
+use crate::sys;
+
+/// Counters maintained by qhull while building the hull
+///
+/// See [`Qh::counters`](crate::Qh::counters).
+/// These are the raw fields of the qhull instance: while the hull is being built,
+/// the facets and the vertices that are about to be deleted are still counted.
+/// Once the hull is [computed](crate::Qh::is_computed), [`num_facets`](QhCounters::num_facets)
+/// and [`num_vertices`](QhCounters::num_vertices) are the same as
+/// [`Qh::num_facets`](crate::Qh::num_facets) and [`Qh::num_vertices`](crate::Qh::num_vertices),
+/// which do not count the sentinels and are `0` before the hull is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QhCounters {
+    /// Number of points in the outside sets, not yet added to the hull (`qh.num_outside`)
+    pub num_outside: usize,
+    /// Number of visible facets, about to be deleted (`qh.num_visible`)
+    pub num_visible: usize,
+    /// Number of facets in the facet list, including the visible ones (`qh.num_facets`)
+    pub num_facets: usize,
+    /// Number of vertices in the vertex list, including the new ones (`qh.num_vertices`)
+    pub num_vertices: usize,
+}
+
+impl QhCounters {
+    /// Read the counters of a qhull instance
+    ///
+    /// # Safety
+    /// `qh` must point to a valid instance
+    pub(crate) unsafe fn from_raw(qh: *const sys::qhT) -> Self {
+        let qh = &*qh;
+        Self {
+            num_outside: qh.num_outside.max(0) as usize,
+            num_visible: qh.num_visible.max(0) as usize,
+            num_facets: qh.num_facets.max(0) as usize,
+            num_vertices: qh.num_vertices.max(0) as usize,
+        }
+    }
+}
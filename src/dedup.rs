@@ -0,0 +1,65 @@
+use std::cmp::Ordering;
+
+/// Correspondence between the input points and the points given to qhull after removing duplicates
+///
+/// See [`QhBuilder::dedup_points`](crate::QhBuilder::dedup_points).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PointMap {
+    /// For each point given to qhull, the index of its first occurrence in the input
+    originals: Vec<usize>,
+    /// For each input point, the index of the point given to qhull
+    deduped: Vec<usize>,
+}
+
+impl PointMap {
+    pub(crate) fn original_index(&self, index: usize) -> Option<usize> {
+        self.originals.get(index).copied()
+    }
+
+    pub(crate) fn deduped_index(&self, original: usize) -> Option<usize> {
+        self.deduped.get(original).copied()
+    }
+}
+
+/// Remove the exact duplicates from `points`, keeping the first occurrence of each point
+///
+/// `0.0` and `-0.0` are the same coordinate.
+/// Returns `None` if there are no duplicates.
+pub(crate) fn dedup(dim: usize, points: &[f64]) -> Option<(Vec<f64>, PointMap)> {
+    let point = |i: usize| &points[i * dim..(i + 1) * dim];
+    let compare = |a: usize, b: usize| {
+        let key = |c: f64| if c == 0.0 { 0.0 } else { c };
+        point(a)
+            .iter()
+            .zip(point(b))
+            .map(|(a, b)| key(*a).total_cmp(&key(*b)))
+            .find(|o| o.is_ne())
+            .unwrap_or(Ordering::Equal)
+    };
+
+    let count = points.len() / dim;
+    // the sort is stable: the first occurrence of a point comes first in its group
+    let mut order: Vec<usize> = (0..count).collect();
+    order.sort_by(|&a, &b| compare(a, b));
+    let mut first = vec![0; count];
+    for group in order.chunk_by(|&a, &b| compare(a, b).is_eq()) {
+        group.iter().for_each(|&i| first[i] = group[0]);
+    }
+    if first.iter().enumerate().all(|(i, &f)| i == f) {
+        return None;
+    }
+
+    let mut coords = Vec::new();
+    let mut originals = Vec::new();
+    let mut deduped = vec![0; count];
+    for i in 0..count {
+        if first[i] == i {
+            deduped[i] = originals.len();
+            originals.push(i);
+            coords.extend_from_slice(point(i));
+        } else {
+            deduped[i] = deduped[first[i]];
+        }
+    }
+    Some((coords, PointMap { originals, deduped }))
+}
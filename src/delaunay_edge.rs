@@ -0,0 +1,19 @@
+/// Edge of a 2D Delaunay triangulation
+///
+/// See [`Qh::delaunay_edges_2d`](crate::Qh::delaunay_edges_2d).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DelaunayEdge {
+    /// Indices of the input points at the ends of the edge, the smaller first
+    pub points: (usize, usize),
+    /// Indices of the triangles sharing the edge, in [`Qh::simplex_indices`](crate::Qh::simplex_indices)
+    ///
+    /// The second triangle is `None` for the edges on the boundary of the triangulation.
+    pub simplices: (usize, Option<usize>),
+}
+
+impl DelaunayEdge {
+    /// Whether the edge is on the boundary of the triangulation (it has only one triangle)
+    pub fn is_boundary(&self) -> bool {
+        self.simplices.1.is_none()
+    }
+}
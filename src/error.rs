@@ -1,12 +1,12 @@
-use std::{error::Error, fmt::Display};
+use std::{error::Error, fmt::Display, sync::Arc};
 
-use crate::{helpers::QhTypeRef, sys, tmp_file::TmpFile, Facet, Ridge, Vertex};
+use crate::{helpers::{affine_rank, QhTypeRef}, io_buffers::IOBuffers, sys, Facet, Ridge, Vertex};
 
 macro_rules! define_error_kinds {
     (
         $(
             $(#[$attr:meta])*
-            $name:ident => $code:literal,
+            $name:ident => $code:literal
         ),*$(,)?
     ) => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -46,6 +46,66 @@ macro_rules! define_error_kinds {
 
 define_error_kinds! {
     // TODO ...
+
+    /// Invalid input, detected by qhull (`qh_ERRinput`) or by this crate before calling qhull
+    InvalidInput => 1,
+    /// The input is degenerate, e.g. collinear points in 2D (`qh_ERRsingular`)
+    ///
+    /// The initial simplex is flat, see [`QhError::rank`] for the dimension of the input.
+    /// [`QhBuilder::allow_degenerate`](crate::QhBuilder::allow_degenerate) joggles the input instead of failing.
+    DegenerateInput => 2,
+    /// Precision error, qhull could not handle the roundoff errors (`qh_ERRprec`)
+    ///
+    /// Joggling the input (`QJ`) usually avoids it.
+    Precision => 3,
+    /// Qhull could not allocate memory (`qh_ERRmem`)
+    OutOfMemory => 4,
+    /// Qhull detected an internal error (`qh_ERRqhull`)
+    Internal => 5,
+    /// Topology error, maybe due to nearly adjacent vertices (`qh_ERRtopology`)
+    Topology => 7,
+    /// A facet is wider than the roundoff errors allow, maybe due to nearly adjacent vertices (`qh_ERRwide`)
+    WideFacet => 8,
+    /// Qhull exited from its debugging code (`qh_ERRdebug`)
+    DebugExit => 9,
+
+    /// A qhull function was called on an instance while another one was running on it
+    NestedCall => 10071,
+    /// Qhull failed again while handling an error
+    ///
+    /// Qhull would have exited the process, the instance is now [poisoned](crate::Qh::is_poisoned).
+    ErrorDuringErrorHandling => 10072,
+    /// The dimension of the hull is not supported by qhull
+    ///
+    /// Qhull computes hulls of dimension 2 or more.
+    /// This is detected by this crate before calling qhull.
+    UnsupportedDimension => 10073,
+    /// The hull of the instance has already been computed
+    ///
+    /// Qhull cannot compute the hull again on the same structures,
+    /// see [`Qh::compute`](crate::Qh::compute).
+    AlreadyComputed => 10074,
+    /// The hull of the instance has not been computed yet
+    ///
+    /// The queries need the facets of the hull, see [`Qh::compute`](crate::Qh::compute).
+    NotComputed => 10075,
+}
+
+/// Class of a [`QhError`], see [`QhError::class`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QhErrorClass {
+    /// The input is invalid or degenerate
+    Input,
+    /// Roundoff errors, joggling the input (`QJ`) or merging facets usually avoids them
+    Precision,
+    /// The combinatorial structure of the hull is inconsistent, usually due to nearly adjacent vertices
+    Topology,
+    /// Qhull could not allocate memory, a smaller input might work
+    Memory,
+    /// A bug in qhull or in this crate, or a misuse of the API (e.g. computing the hull twice)
+    Internal,
+    /// Any other error
+    Other,
 }
 
 /// A Qhull error
@@ -59,6 +119,49 @@ pub struct QhError<'a> {
     pub face: Option<Facet<'a>>,
     pub ridge: Option<Ridge<'a>>,
     pub vertex: Option<Vertex<'a>>,
+    /// Dimension of the affine hull of the input, for [`QhErrorKind::DegenerateInput`]
+    ///
+    /// e.g. `1` for collinear points, `2` for coplanar points in 3D.
+    /// It is computed from the points used by qhull, with qhull's roundoff error as tolerance.
+    pub rank: Option<usize>,
+    /// The qhull command line and the options selected by qhull when the error occurred
+    ///
+    /// The first line is `qh.qhull_command` (e.g. `qhull d Qbb Qt`),
+    /// the second line, if any, is the descriptive list of options `qh.qhull_options`.
+    /// The command line can be used to reproduce the error with the qhull programs.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let error = Qh::new_delaunay([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]).unwrap_err();
+    /// let options = error.options.as_deref().unwrap();
+    /// assert!(options.starts_with("qhull d "));
+    /// assert!(error.to_string().contains("qhull d "));
+    /// ```
+    pub options: Option<String>,
+    /// The underlying error, e.g. the I/O error of a failed write, see [`Error::source`]
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use std::{error::Error, io};
+    ///
+    /// struct Full;
+    /// impl io::Write for Full {
+    ///     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+    ///         Err(io::Error::new(io::ErrorKind::StorageFull, "full"))
+    ///     }
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut qh = Qh::builder().build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]).unwrap();
+    /// let error = qh.write_geomview(Full).unwrap_err();
+    /// let source = error.source().unwrap().downcast_ref::<io::Error>().unwrap();
+    /// assert_eq!(source.kind(), io::ErrorKind::StorageFull);
+    /// ```
+    pub cause: Option<Arc<dyn Error + Send + Sync + 'static>>,
 }
 
 impl<'a> Display for QhError<'a> {
@@ -81,13 +184,207 @@ impl<'a> Display for QhError<'a> {
         if let Some(vertex) = &self.vertex {
             write!(f, "\nVertex: {:?}", vertex)?;
         }
+        if let Some(options) = &self.options {
+            write!(f, "\nOptions: {}", options)?;
+        }
         Ok(())
     }
 }
 
-impl<'a> Error for QhError<'a> {}
+impl<'a> Error for QhError<'a> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.cause.as_deref().map(|cause| cause as _)
+    }
+}
+
+/// An error of the given kind, without any other information
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// let error = QhError::from(QhErrorKind::Precision);
+/// assert_eq!(error.class(), QhErrorClass::Precision);
+/// assert!(error.error_message.is_none());
+/// ```
+impl From<QhErrorKind> for QhError<'static> {
+    fn from(kind: QhErrorKind) -> Self {
+        QhError {
+            kind,
+            error_message: None,
+            face: None,
+            ridge: None,
+            vertex: None,
+            rank: None,
+            options: None,
+            cause: None,
+        }
+    }
+}
+
+impl QhError<'static> {
+    /// An error on the input, detected by this crate before calling qhull
+    pub(crate) fn invalid_input(message: impl Into<String>) -> Self {
+        QhError {
+            kind: QhErrorKind::from_code(sys::qh_ERRinput as _),
+            error_message: Some(message.into()),
+            face: None,
+            ridge: None,
+            vertex: None,
+            rank: None,
+            options: None,
+            cause: None,
+        }
+    }
+
+    /// A hull dimension that qhull does not support
+    pub(crate) fn unsupported_dimension(message: impl Into<String>) -> Self {
+        QhError {
+            kind: QhErrorKind::UnsupportedDimension,
+            error_message: Some(message.into()),
+            face: None,
+            ridge: None,
+            vertex: None,
+            rank: None,
+            options: None,
+            cause: None,
+        }
+    }
+
+    /// A second call to [`Qh::compute`](crate::Qh::compute)
+    pub(crate) fn already_computed() -> Self {
+        QhError {
+            kind: QhErrorKind::AlreadyComputed,
+            error_message: Some("the hull has already been computed".to_string()),
+            face: None,
+            ridge: None,
+            vertex: None,
+            rank: None,
+            options: None,
+            cause: None,
+        }
+    }
+
+    /// A query on an instance whose hull has not been [computed](crate::Qh::compute)
+    pub(crate) fn not_computed() -> Self {
+        QhError {
+            kind: QhErrorKind::NotComputed,
+            error_message: Some("the hull has not been computed".to_string()),
+            face: None,
+            ridge: None,
+            vertex: None,
+            rank: None,
+            options: None,
+            cause: None,
+        }
+    }
+
+    /// An error while writing the output of qhull to a Rust writer
+    pub(crate) fn write_failed(error: std::io::Error) -> Self {
+        Self::io_failed("failed to write the output", error)
+    }
+
+    /// An I/O error on the streams used to exchange data with qhull, e.g. a temporary file
+    pub(crate) fn io_failed(context: &str, error: std::io::Error) -> Self {
+        QhError {
+            kind: QhErrorKind::from_code(sys::qh_ERRother as _),
+            error_message: Some(format!("{}: {}", context, error)),
+            face: None,
+            ridge: None,
+            vertex: None,
+            rank: None,
+            options: None,
+            cause: Some(Arc::new(error)),
+        }
+    }
+}
 
 impl<'a> QhError<'a> {
+    /// Class of the error, from its [kind](QhErrorKind)
+    ///
+    /// Qhull groups its message codes by module rather than by class,
+    /// so the class is derived from the error code given to `qh_errexit` (`qh_ERR*`).
+    /// Wide facets are precision errors, like for the `Q12` option of qhull.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let class = |kind| QhError::from(kind).class();
+    /// assert_eq!(class(QhErrorKind::InvalidInput), QhErrorClass::Input);
+    /// assert_eq!(class(QhErrorKind::DegenerateInput), QhErrorClass::Input);
+    /// assert_eq!(class(QhErrorKind::UnsupportedDimension), QhErrorClass::Input);
+    /// assert_eq!(class(QhErrorKind::Precision), QhErrorClass::Precision);
+    /// assert_eq!(class(QhErrorKind::WideFacet), QhErrorClass::Precision);
+    /// assert_eq!(class(QhErrorKind::Topology), QhErrorClass::Topology);
+    /// assert_eq!(class(QhErrorKind::OutOfMemory), QhErrorClass::Memory);
+    /// assert_eq!(class(QhErrorKind::Internal), QhErrorClass::Internal);
+    /// assert_eq!(class(QhErrorKind::NestedCall), QhErrorClass::Internal);
+    /// assert_eq!(class(QhErrorKind::AlreadyComputed), QhErrorClass::Internal);
+    /// assert_eq!(class(QhErrorKind::NotComputed), QhErrorClass::Internal);
+    /// assert_eq!(class(QhErrorKind::from_code(6)), QhErrorClass::Other);
+    ///
+    /// let error = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]])
+    ///     .unwrap_err();
+    /// assert!(error.is_input());
+    /// assert!(!error.is_precision());
+    /// ```
+    pub fn class(&self) -> QhErrorClass {
+        match self.kind {
+            QhErrorKind::InvalidInput | QhErrorKind::DegenerateInput | QhErrorKind::UnsupportedDimension => {
+                QhErrorClass::Input
+            }
+            QhErrorKind::Precision | QhErrorKind::WideFacet => QhErrorClass::Precision,
+            QhErrorKind::Topology => QhErrorClass::Topology,
+            QhErrorKind::OutOfMemory => QhErrorClass::Memory,
+            QhErrorKind::Internal
+            | QhErrorKind::DebugExit
+            | QhErrorKind::NestedCall
+            | QhErrorKind::AlreadyComputed
+            | QhErrorKind::NotComputed
+            | QhErrorKind::ErrorDuringErrorHandling => QhErrorClass::Internal,
+            QhErrorKind::Other(_) => QhErrorClass::Other,
+        }
+    }
+
+    /// Check if the error is in the [input class](QhErrorClass::Input)
+    pub fn is_input(&self) -> bool {
+        self.class() == QhErrorClass::Input
+    }
+
+    /// Check if the error is in the [precision class](QhErrorClass::Precision)
+    pub fn is_precision(&self) -> bool {
+        self.class() == QhErrorClass::Precision
+    }
+
+    /// Check if the error is in the [topology class](QhErrorClass::Topology)
+    pub fn is_topology(&self) -> bool {
+        self.class() == QhErrorClass::Topology
+    }
+
+    /// Check if the error is in the [memory class](QhErrorClass::Memory)
+    pub fn is_memory(&self) -> bool {
+        self.class() == QhErrorClass::Memory
+    }
+
+    /// Code of the first qhull message in the [error message](QhError::error_message), e.g. `6154` for `QH6154`
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let error = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]])
+    ///     .unwrap_err();
+    /// assert_eq!(error.message_code(), Some(6154));
+    /// ```
+    pub fn message_code(&self) -> Option<u32> {
+        let message = self.error_message.as_deref()?;
+        message.match_indices("QH").find_map(|(start, _)| {
+            let digits = &message[start + 2..];
+            let len = digits.bytes().take_while(u8::is_ascii_digit).count();
+            (len >= 4).then(|| digits[..len].parse().ok()).flatten()
+        })
+    }
+
     /// Convert the error to a `'static` error.
     ///
     /// This is useful when you want to return the error from a function that is not tied to a [`Qh`](crate::Qh) reference.
@@ -125,6 +422,9 @@ impl<'a> QhError<'a> {
             face,
             ridge,
             vertex,
+            rank,
+            options,
+            cause,
         } = self;
         if let Some(face) = face {
             eprintln!(
@@ -150,6 +450,9 @@ impl<'a> QhError<'a> {
             face: None,
             ridge: None,
             vertex: None,
+            rank,
+            options,
+            cause,
         }
     }
 
@@ -163,6 +466,8 @@ impl<'a> QhError<'a> {
     ///
     /// Qhull uses [`setjmp`/`longjmp`](https://en.cppreference.com/w/c/program/longjmp) for error handling, this is not currently supported in Rust.
     /// For this reason, the actual error handling is done in C and this function is just a wrapper around the C function [`qhull_sys__try_on_qh`](sys::qhull_sys__try_on_qh).
+    /// The callback given to the C function has no values to drop when qhull jumps out of it,
+    /// and the result of `f` (or its panic) is stored in a slot of this function's frame.
     ///
     /// Relevant links:
     /// - <https://github.com/rust-lang/rfcs/issues/2625>: RFC for adding support for `setjmp`/`longjmp` to Rust, describes the current problems with `setjmp`/`longjmp` in Rust.
@@ -172,61 +477,94 @@ impl<'a> QhError<'a> {
     /// - <http://groups.di.unipi.it/~nids/docs/longjump_try_trow_catch.html>
     pub unsafe fn try_on_raw<'b, R, F>(
         qh: *mut sys::qhT,
-        err_file: &mut Option<TmpFile>,
+        buffers: &mut IOBuffers,
         f: F,
     ) -> Result<R, QhError<'b>>
     where
         F: FnOnce(*mut sys::qhT) -> R,
     {
-        unsafe extern "C" fn cb<F2>(qh: *mut sys::qhT, data: *mut std::ffi::c_void)
-        where
-            F2: FnOnce(&mut sys::qhT),
-        {
-            assert!(!qh.is_null(), "qh is null");
-            assert!(!data.is_null(), "data is null");
-            let qh = &mut *qh;
-            let f: &mut Option<F2> = &mut *(data as *mut _);
-            f.take().unwrap()(qh);
+        /// State shared with the callback
+        ///
+        /// It lives in this frame, so nothing in the callback needs to be dropped
+        /// when qhull jumps out of it, and the result is read only after the C side returned.
+        struct Slot<F, R> {
+            f: Option<F>,
+            result: Option<std::thread::Result<R>>,
         }
 
-        fn get_cb<F>(
-            _: &mut Option<F>,
-        ) -> unsafe extern "C" fn(*mut sys::qhT, *mut std::ffi::c_void)
+        /// Qhull errors `longjmp` across this function: it must not hold values with destructors.
+        /// It is `C-unwind` because on MSVC `longjmp` unwinds the stack,
+        /// which aborts the process in an `extern "C"` frame.
+        /// Panics are caught and resumed after the C side returned.
+        unsafe extern "C-unwind" fn cb<F, R>(qh: *mut sys::qhT, data: *mut std::ffi::c_void)
         where
-            F: FnOnce(&mut sys::qhT),
+            F: FnOnce(*mut sys::qhT) -> R,
         {
-            cb::<F>
+            let slot = &mut *(data as *mut Slot<F, R>);
+            if let Some(f) = slot.f.take() {
+                slot.result = Some(std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || f(qh))));
+            }
         }
 
-        let mut result = None;
+        type Callback = unsafe extern "C" fn(*mut sys::qhT, *mut std::ffi::c_void);
+
+        let mut slot = Slot { f: Some(f), result: None };
 
-        let mut f = Some(|qh: &mut sys::qhT| result = Some(f(qh)));
+        buffers.begin_error_capture();
 
         let err_code = unsafe {
-            sys::qhull_sys__try_on_qh(
-                &mut *qh,
-                Some(get_cb(&mut f)),
-                &mut f as *mut _ as *mut std::ffi::c_void,
-            )
+            // the C side only calls the function, the declared ABI of the pointer does not matter to it
+            let cb: Callback = std::mem::transmute(cb::<F, R> as unsafe extern "C-unwind" fn(_, _));
+            sys::qhull_sys__try_on_qh(&mut *qh, Some(cb), &mut slot as *mut Slot<F, R> as *mut std::ffi::c_void)
         };
 
         let qh = &mut *qh;
 
         if err_code == 0 {
-            Ok(result.unwrap())
+            match slot.result.expect("the callback was not called") {
+                Ok(result) => Ok(result),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
         } else {
             let kind = QhErrorKind::from_code(err_code);
-            let file = err_file
-                .replace(TmpFile::new().expect("Failed to create a replacement temporary file"));
-            qh.ferr = err_file.as_ref().unwrap().file_handle();
-            let msg = file.map(|file| file.read_as_string_and_close().unwrap());
+            let msg = buffers.take_error_message(qh);
             Err(QhError {
                 kind,
                 error_message: msg,
                 face: Facet::from_ptr(qh.tracefacet, qh.input_dim as _), // TODO is this dim correct?
                 ridge: Ridge::from_ptr(qh.traceridge, qh.input_dim as _), // TODO is this dim correct?
                 vertex: Vertex::from_ptr(qh.tracevertex, qh.input_dim as _), // TODO is this dim correct?
+                rank: (kind == QhErrorKind::DegenerateInput).then(|| input_rank(qh)),
+                options: option_line(qh),
+                cause: None,
             })
         }
     }
 }
+
+/// Command line and selected options of a qhull instance, see [`QhError::options`]
+fn option_line(qh: &sys::qhT) -> Option<String> {
+    let read = |chars: &[std::os::raw::c_char]| {
+        let bytes: Vec<u8> = chars.iter().take_while(|&&c| c != 0).map(|&c| c as u8).collect();
+        String::from_utf8_lossy(&bytes).split_whitespace().collect::<Vec<_>>().join(" ")
+    };
+    let command = read(&qh.qhull_command);
+    let options = read(&qh.qhull_options);
+    match (command.is_empty(), options.is_empty()) {
+        (true, true) => None,
+        (_, true) => Some(command),
+        (true, _) => Some(options),
+        _ => Some(format!("{}\n{}", command, options)),
+    }
+}
+
+/// Rank of the points of a qhull instance, see [`QhError::rank`]
+fn input_rank(qh: &sys::qhT) -> usize {
+    let dim = qh.hull_dim.max(0) as usize;
+    if qh.first_point.is_null() || dim == 0 {
+        return 0;
+    }
+    let coords = unsafe { std::slice::from_raw_parts(qh.first_point, qh.num_points.max(0) as usize * dim) };
+    let scale = coords.iter().fold(0.0f64, |max, c| max.max(c.abs()));
+    affine_rank(coords, dim, qh.DISTround.max(scale * dim as f64 * f64::EPSILON))
+}
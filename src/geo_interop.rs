@@ -0,0 +1,95 @@
+//! Conversions from and to the [`geo_types`] crate
+
+use geo_types::{Coord, LineString, Polygon};
+
+use crate::{Qh, QhBuilder, QhError};
+
+impl QhBuilder {
+    /// Build a 2D Qhull instance from [`geo_types`] coordinates
+    ///
+    /// The coordinates are copied into a buffer owned by the instance,
+    /// as in [`QhBuilder::build_from_flat`].
+    ///
+    /// Requires the `geo` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use geo::{coord, Contains, Polygon};
+    ///
+    /// let points = [
+    ///     coord! { x: 0.0, y: 0.0 },
+    ///     coord! { x: 2.0, y: 0.0 },
+    ///     coord! { x: 1.0, y: 1.0 },
+    ///     coord! { x: 2.0, y: 2.0 },
+    ///     coord! { x: 0.0, y: 2.0 },
+    /// ];
+    /// let qh = Qh::builder().build_from_geo(&points).unwrap();
+    /// let polygon = Polygon::try_from(&qh).unwrap();
+    ///
+    /// // the ring is closed and counterclockwise
+    /// let ring = &polygon.exterior().0;
+    /// assert_eq!(ring.len(), 5);
+    /// assert_eq!(ring.first(), ring.last());
+    /// assert!(polygon.exterior().is_closed());
+    ///
+    /// // geo agrees with qhull on the points that are not on the boundary
+    /// for x in (-5..=25).map(|i| i as f64 * 0.1 + 0.05) {
+    ///     for y in (-5..=25).map(|i| i as f64 * 0.1 + 0.05) {
+    ///         let p = coord! { x: x, y: y };
+    ///         assert_eq!(polygon.contains(&p), qh.contains(&[x, y]).unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub fn build_from_geo(self, points: &[Coord<f64>]) -> Result<Qh<'static>, QhError<'static>> {
+        let coords: Vec<f64> = points.iter().flat_map(|c| [c.x, c.y]).collect();
+        self.build_managed(2, coords)
+    }
+}
+
+impl<'a> Qh<'a> {
+    /// The hull as a [`geo_types::Polygon`]
+    ///
+    /// The exterior ring is counterclockwise and closed (the first point is repeated at the end),
+    /// see [`Qh::hull_polygon_2d`] for the errors.
+    ///
+    /// Requires the `geo` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use geo::{coord, Area, Winding};
+    ///
+    /// let qh = Qh::builder()
+    ///     .build_from_geo(&[
+    ///         coord! { x: 0.0, y: 0.0 },
+    ///         coord! { x: 1.0, y: 0.0 },
+    ///         coord! { x: 0.0, y: 1.0 },
+    ///         coord! { x: 0.25, y: 0.25 },
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let polygon = qh.to_geo_polygon().unwrap();
+    /// assert!(polygon.exterior().is_ccw());
+    /// assert_eq!(polygon.signed_area(), 0.5);
+    /// assert!(polygon.interiors().is_empty());
+    /// ```
+    pub fn to_geo_polygon(&self) -> Result<Polygon<f64>, QhError<'_>> {
+        let exterior: LineString<f64> = self
+            .hull_polygon_2d_coords()?
+            .into_iter()
+            .map(|[x, y]| Coord { x, y })
+            .collect();
+        // closes the ring
+        Ok(Polygon::new(exterior, Vec::new()))
+    }
+}
+
+impl<'b, 'a> TryFrom<&'b Qh<'a>> for Polygon<f64> {
+    type Error = QhError<'b>;
+
+    /// See [`Qh::to_geo_polygon`]
+    fn try_from(qh: &'b Qh<'a>) -> Result<Self, Self::Error> {
+        qh.to_geo_polygon()
+    }
+}
@@ -0,0 +1,138 @@
+//! Conversions from and to the [`glam`] crate
+
+use glam::{DVec3, Vec3};
+
+use crate::{Facet, Qh, QhBuilder, QhError};
+
+impl QhBuilder {
+    /// Build a 3D Qhull instance from [`glam`] points
+    ///
+    /// The slice is read as flat coordinates without copying it, see [`QhBuilder::build_from_slice`]
+    /// for the options that still force a copy.
+    ///
+    /// Requires the `glam` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use glam::DVec3;
+    ///
+    /// let points = [DVec3::ZERO, DVec3::X, DVec3::Y, DVec3::Z, DVec3::splat(0.1)];
+    /// let qh = Qh::builder().build_from_glam(&points).unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    /// assert_eq!(qh.point(1).unwrap(), DVec3::X.to_array());
+    /// ```
+    pub fn build_from_glam(self, points: &[DVec3]) -> Result<Qh<'_>, QhError<'static>> {
+        // DVec3 is a repr(C) struct of 3 f64
+        let coords = unsafe { std::slice::from_raw_parts(points.as_ptr() as *const f64, points.len() * 3) };
+        self.build_from_slice(3, coords)
+    }
+
+    /// Build a 3D Qhull instance from single precision [`glam`] points
+    ///
+    /// The coordinates are converted to `f64` into a buffer owned by the instance,
+    /// the conversion is exact.
+    ///
+    /// Requires the `glam` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use glam::Vec3;
+    ///
+    /// let points = [Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z, Vec3::splat(0.1)];
+    /// let qh = Qh::builder().build_from_glam_f32(&points).unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    /// assert_eq!(qh.point(4).unwrap(), [0.1f32 as f64; 3]);
+    /// ```
+    pub fn build_from_glam_f32(self, points: &[Vec3]) -> Result<Qh<'static>, QhError<'static>> {
+        let coords: Vec<f64> = points.iter().flat_map(|p| p.as_dvec3().to_array()).collect();
+        self.build_managed(3, coords)
+    }
+}
+
+impl<'a> Qh<'a> {
+    /// The [triangle mesh](Qh::triangle_mesh) of a 3D hull as [`glam`] positions and triangles
+    ///
+    /// The triangles are counterclockwise seen from outside the hull, see [`Qh::triangle_mesh`] for the errors.
+    ///
+    /// Requires the `glam` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("100 s D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// let (positions, triangles) = qh.triangle_mesh_glam().unwrap();
+    /// let centroid = positions.iter().sum::<glam::DVec3>() / positions.len() as f64;
+    /// for [a, b, c] in triangles.iter().map(|t| t.map(|i| positions[i as usize])) {
+    ///     // the normals from the winding point away from the centroid
+    ///     let normal = (b - a).cross(c - a);
+    ///     assert!(normal.dot(a - centroid) > 0.0);
+    /// }
+    ///
+    /// let (positions_f32, triangles_f32) = qh.triangle_mesh_glam_f32().unwrap();
+    /// assert_eq!(triangles_f32, triangles);
+    /// assert!(positions.iter().zip(&positions_f32).all(|(p, q)| (p.as_vec3() - *q).length() == 0.0));
+    /// ```
+    pub fn triangle_mesh_glam(&self) -> Result<(Vec<DVec3>, Vec<[u32; 3]>), QhError<'_>> {
+        let mesh = self.triangle_mesh()?;
+        let positions = mesh.positions.into_iter().map(DVec3::from_array).collect();
+        Ok((positions, mesh.triangles))
+    }
+
+    /// The [triangle mesh](Qh::triangle_mesh) of a 3D hull with single precision [`glam`] positions
+    ///
+    /// Same as [`Qh::triangle_mesh_glam`], with the positions rounded to `f32`.
+    /// The rounding moves the positions by up to half an `f32` ulp: the triangles of thin facets
+    /// (almost coplanar points) can become degenerate or change their winding,
+    /// and points of the input may end up slightly outside of the mesh.
+    ///
+    /// Requires the `glam` feature.
+    pub fn triangle_mesh_glam_f32(&self) -> Result<(Vec<Vec3>, Vec<[u32; 3]>), QhError<'_>> {
+        let mesh = self.triangle_mesh()?;
+        let positions = mesh.positions.into_iter().map(|p| DVec3::from_array(p).as_vec3()).collect();
+        Ok((positions, mesh.triangles))
+    }
+}
+
+impl Facet<'_> {
+    /// The normal of the facet as a [`glam::DVec3`], `None` if the hull is not 3-dimensional
+    ///
+    /// See [`Facet::normal`].
+    ///
+    /// Requires the `glam` feature.
+    pub fn normal_dvec3(&self) -> Option<DVec3> {
+        self.normal_array::<3>().map(DVec3::from_array)
+    }
+
+    /// The hyperplane of the facet as a [`glam::DVec3`] normal and an offset
+    ///
+    /// A point `p` is above the facet if `normal.dot(p) + offset > 0`,
+    /// `None` if the hull is not 3-dimensional.
+    ///
+    /// Requires the `glam` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// use glam::DVec3;
+    ///
+    /// let points = [DVec3::ZERO, DVec3::X, DVec3::Y, DVec3::Z];
+    /// let qh = Qh::builder().build_from_glam(&points).unwrap();
+    /// let centroid = points.iter().sum::<DVec3>() / 4.0;
+    /// for facet in qh.facets() {
+    ///     let (normal, offset) = facet.plane().unwrap();
+    ///     assert_eq!(normal, facet.normal_dvec3().unwrap());
+    ///     assert!(normal.dot(centroid) + offset < 0.0);
+    ///     for vertex in facet.vertices().unwrap().iter() {
+    ///         let p = DVec3::from_slice(vertex.point().unwrap());
+    ///         assert!((normal.dot(p) + offset).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn plane(&self) -> Option<(DVec3, f64)> {
+        Some((self.normal_dvec3()?, self.offset()))
+    }
+}
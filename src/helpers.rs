@@ -1,8 +1,11 @@
 use std::{
     ffi::CString,
     os::raw::{c_char, c_int},
+    ptr,
 };
 
+use crate::{io_buffers::IOBuffers, sys, QhError};
+
 /// A trait for types that can be created from a pointer to a C type and a dimension.
 pub trait QhTypeRef: Sized {
     type FFIType;
@@ -28,6 +31,7 @@ pub trait QhTypeRef: Sized {
     fn dim(&self) -> usize;
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct CollectedCoords {
     pub coords: Vec<f64>,
     pub count: usize,
@@ -44,43 +48,245 @@ pub struct CollectedCoords {
 ///     coords,
 ///     count,
 ///     dim,
-/// } = collect_coords([
+/// } = try_collect_coords([
 ///         [0.0, 0.0],
 ///         [1.0, 0.0],
 ///         [0.0, 2.0],
-/// ]);
+/// ]).unwrap();
 /// assert_eq!(coords, vec![0.0, 0.0, 1.0, 0.0, 0.0, 2.0]);
 /// assert_eq!(count, 3);
 /// assert_eq!(dim, 2);
 /// ```
+///
+/// # Errors
+/// An [`InvalidInput`](crate::QhErrorKind::InvalidInput) error if there are no points,
+/// if the first point has no coordinates or if the points have different dimensions.
+/// ```
+/// # use qhull::{helpers::*, QhErrorKind};
+/// let error = try_collect_coords(Vec::<[f64; 2]>::new()).unwrap_err();
+/// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+///
+/// let error = try_collect_coords([vec![0.0, 0.0], vec![1.0]]).unwrap_err();
+/// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+/// ```
+pub fn try_collect_coords<I>(points: impl IntoIterator<Item = I>) -> Result<CollectedCoords, QhError<'static>>
+where
+    I: IntoIterator<Item = f64>,
+{
+    try_collect_coords_with_capacity(points, 0)
+}
+
+/// Collects coordinates from an iterator of points, panicking on invalid input
+///
+/// # Panics
+/// See [`try_collect_coords`].
+#[deprecated(note = "use `try_collect_coords`, which returns an error instead of panicking")]
 pub fn collect_coords<I>(points: impl IntoIterator<Item = I>) -> CollectedCoords
 where
     I: IntoIterator<Item = f64>,
 {
-    let mut dim: Option<usize> = None;
-    let mut coords: Vec<f64> = Vec::new();
-    let mut pt: Vec<f64> = Vec::new();
-    for point in points.into_iter() {
-        pt.clear();
-        pt.extend(point.into_iter());
-        if let Some(d) = dim {
-            assert_eq!(pt.len(), d, "points have different dimensions");
-        } else {
-            dim = Some(pt.len());
+    try_collect_coords(points).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Collects coordinates from an iterator of points, reserving space for `capacity` coordinates
+///
+/// Without a capacity, the space is reserved using the [size hints](Iterator::size_hint)
+/// of the iterators, so that exact-size iterators are collected with a single allocation.
+///
+/// # Example
+/// ```
+/// # use qhull::helpers::*;
+/// use std::{alloc::{GlobalAlloc, Layout, System}, sync::atomic::{AtomicUsize, Ordering}};
+///
+/// struct CountingAllocator;
+/// static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+///
+/// unsafe impl GlobalAlloc for CountingAllocator {
+///     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+///         ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+///         System.alloc(layout)
+///     }
+///     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+///         System.dealloc(ptr, layout)
+///     }
+///     unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+///         ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+///         System.realloc(ptr, layout, new_size)
+///     }
+/// }
+///
+/// #[global_allocator]
+/// static GLOBAL: CountingAllocator = CountingAllocator;
+///
+/// fn main() {
+///     let points = (0..100_000).map(|i| [i as f64, 1.0, 2.0]);
+///
+///     let before = ALLOCATIONS.load(Ordering::Relaxed);
+///     let collected = try_collect_coords(points.clone()).unwrap();
+///     assert_eq!(ALLOCATIONS.load(Ordering::Relaxed) - before, 1);
+///     assert_eq!(collected.coords.capacity(), 300_000);
+///
+///     // with a filter, the size hint is not exact
+///     let before = ALLOCATIONS.load(Ordering::Relaxed);
+///     let collected = try_collect_coords_with_capacity(points.filter(|p| p[0] < 1000.0), 3000).unwrap();
+///     assert_eq!(ALLOCATIONS.load(Ordering::Relaxed) - before, 1);
+///     assert_eq!(collected.count, 1000);
+/// }
+/// ```
+///
+/// # Errors
+/// See [`try_collect_coords`].
+pub fn try_collect_coords_with_capacity<I>(
+    points: impl IntoIterator<Item = I>,
+    capacity: usize,
+) -> Result<CollectedCoords, QhError<'static>>
+where
+    I: IntoIterator<Item = f64>,
+{
+    let mut points = points.into_iter();
+    let mut coords: Vec<f64> = Vec::with_capacity(capacity);
+
+    let Some(first) = points.next().map(IntoIterator::into_iter) else {
+        return Err(QhError::invalid_input("no points"));
+    };
+    let remaining = points.size_hint().0;
+    coords.reserve(first.size_hint().0.saturating_mul(remaining.saturating_add(1)));
+    coords.extend(first);
+    coords.reserve(coords.len().saturating_mul(remaining));
+    let dim = coords.len();
+    if dim == 0 {
+        return Err(QhError::invalid_input("points must have at least one coordinate"));
+    }
+
+    for (index, point) in points.enumerate() {
+        let start = coords.len();
+        coords.extend(point);
+        if coords.len() - start != dim {
+            return Err(different_dimensions(index + 1, coords.len() - start, dim));
         }
-        coords.extend(pt.iter());
     }
-    drop(pt);
-    assert!(!coords.is_empty(), "no points");
-    let dim = dim.unwrap();
-    debug_assert_eq!(coords.len() % dim, 0);
     let count = coords.len() / dim;
-    CollectedCoords { coords, count, dim }
+    Ok(CollectedCoords { coords, count, dim })
+}
+
+/// Collects coordinates from an iterator of points, reserving space for `capacity` coordinates
+/// and panicking on invalid input
+///
+/// # Panics
+/// See [`try_collect_coords`].
+#[deprecated(note = "use `try_collect_coords_with_capacity`, which returns an error instead of panicking")]
+pub fn collect_coords_with_capacity<I>(
+    points: impl IntoIterator<Item = I>,
+    capacity: usize,
+) -> CollectedCoords
+where
+    I: IntoIterator<Item = f64>,
+{
+    try_collect_coords_with_capacity(points, capacity).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn different_dimensions(index: usize, len: usize, dim: usize) -> QhError<'static> {
+    QhError::invalid_input(format!(
+        "points have different dimensions: point {} has {} coordinates, the first point has {}",
+        index, len, dim,
+    ))
+}
+
+/// A source of points given as slices of coordinates
+///
+/// Implemented for every [`IntoIterator`] whose items are [`AsRef<[f64]>`](AsRef),
+/// e.g. `&[&[f64]]`, `Vec<Vec<f64>>`, `&[[f64; N]]` or an iterator of slices.
+/// The number of points is taken from the [size hint](Iterator::size_hint) of the iterator:
+/// slices and vectors know it exactly, so the coordinates are collected with a single allocation.
+///
+/// See [`collect_point_source`] and [`QhBuilder::build_from_source`](crate::QhBuilder::build_from_source).
+pub trait PointSource {
+    /// A point, its slice contains the coordinates
+    type Point: AsRef<[f64]>;
+    /// The iterator over the points
+    type Points: Iterator<Item = Self::Point>;
+
+    /// Iterate over the points
+    fn into_points(self) -> Self::Points;
+}
+
+impl<T> PointSource for T
+where
+    T: IntoIterator,
+    T::Item: AsRef<[f64]>,
+{
+    type Point = T::Item;
+    type Points = T::IntoIter;
+
+    fn into_points(self) -> Self::Points {
+        self.into_iter()
+    }
+}
+
+/// Collects the coordinates of a [`PointSource`]
+///
+/// The dimension is the length of the first point, every other point is checked against it.
+///
+/// # Example
+/// ```
+/// # use qhull::helpers::*;
+/// let expected = vec![0.0, 0.0, 1.0, 0.0, 0.0, 2.0];
+///
+/// let slices: &[&[f64]] = &[&[0.0, 0.0], &[1.0, 0.0], &[0.0, 2.0]];
+/// assert_eq!(collect_point_source(slices).unwrap().coords, expected);
+///
+/// let vecs = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 2.0]];
+/// assert_eq!(collect_point_source(&vecs).unwrap().coords, expected);
+/// assert_eq!(collect_point_source(vecs).unwrap().coords, expected);
+///
+/// let arrays = [[0.0, 0.0], [1.0, 0.0], [0.0, 2.0]];
+/// assert_eq!(collect_point_source(&arrays).unwrap().coords, expected);
+///
+/// let flat = [0.0, 0.0, 1.0, 0.0, 0.0, 2.0];
+/// let CollectedCoords { coords, count, dim } = collect_point_source(flat.chunks_exact(2)).unwrap();
+/// assert_eq!(coords, expected);
+/// assert_eq!(coords.capacity(), 6);
+/// assert_eq!(count, 3);
+/// assert_eq!(dim, 2);
+/// ```
+///
+/// # Errors
+/// See [`try_collect_coords`].
+/// ```
+/// # use qhull::{helpers::*, QhErrorKind};
+/// let points: &[&[f64]] = &[&[0.0, 0.0], &[1.0]];
+/// assert_eq!(collect_point_source(points).unwrap_err().kind, QhErrorKind::InvalidInput);
+/// ```
+pub fn collect_point_source(points: impl PointSource) -> Result<CollectedCoords, QhError<'static>> {
+    let mut points = points.into_points();
+    let Some(first) = points.next() else {
+        return Err(QhError::invalid_input("no points"));
+    };
+    let first = first.as_ref();
+    let dim = first.len();
+    if dim == 0 {
+        return Err(QhError::invalid_input("points must have at least one coordinate"));
+    }
+
+    let remaining = points.size_hint().0;
+    let mut coords = Vec::with_capacity(remaining.saturating_add(1).saturating_mul(dim));
+    coords.extend_from_slice(first);
+    for (index, point) in points.enumerate() {
+        let point = point.as_ref();
+        if point.len() != dim {
+            return Err(different_dimensions(index + 1, point.len(), dim));
+        }
+        coords.extend_from_slice(point);
+    }
+    let count = coords.len() / dim;
+    Ok(CollectedCoords { coords, count, dim })
 }
 
 /// Prepares points for Delaunay triangulation.
 ///
 /// This function builds a paraboloid adding a "z" coordinate to each point.
+/// The coordinates are centered and normalized by the extent of the points along each axis,
+/// unlike qhull: see [`lift_to_paraboloid`] for the lifting of qhull.
 ///
 /// # Example
 /// ```
@@ -94,6 +300,23 @@ where
 /// assert_eq!(count, 3);
 /// assert_eq!(dim, 2);
 /// ```
+///
+/// Axes along which the points do not extend are ignored:
+/// ```
+/// # use qhull::helpers::*;
+/// let xs = [-2.0, 0.5, 1.0, 3.0];
+/// let flat = prepare_delaunay_points(xs.map(|x| [x, 5.0]));
+/// let line = prepare_delaunay_points(xs.map(|x| [x]));
+/// assert!(flat.coords.iter().all(|c| c.is_finite()));
+/// for (flat, line) in flat.coords.chunks(3).zip(line.coords.chunks(2)) {
+///     assert_eq!(flat[0], line[0]);
+///     assert_eq!(flat[1], 5.0);
+///     assert_eq!(flat[2], line[1]);
+/// }
+/// ```
+///
+/// # Panics
+/// See [`try_collect_coords`].
 pub fn prepare_delaunay_points<I>(points: impl IntoIterator<Item = I>) -> CollectedCoords
 where
     I: IntoIterator<Item = f64>,
@@ -105,7 +328,7 @@ where
         mut coords,
         count,
         dim,
-    } = collect_coords(points);
+    } = try_collect_coords(points).unwrap_or_else(|e| panic!("{}", e));
     let orig_dim = dim - 1;
 
     let mut center: Vec<f64> = vec![0.0; orig_dim];
@@ -124,10 +347,19 @@ where
         }
     }
     center.iter_mut().for_each(|coord| *coord /= count as f64);
+    // an axis where all the points have the same coordinate does not contribute to the paraboloid:
+    // its width is replaced by 1 to avoid dividing 0 by 0
     let widths: Vec<f64> = min_coords
         .iter()
         .zip(max_coords.iter())
-        .map(|(min, max)| (max - min) / 2.0)
+        .map(|(min, max)| {
+            let width = (max - min) / 2.0;
+            if width > f64::EPSILON * min.abs().max(max.abs()) {
+                width
+            } else {
+                1.0
+            }
+        })
         .collect();
 
     // build paraboloid
@@ -142,6 +374,271 @@ where
     CollectedCoords { coords, count, dim }
 }
 
+/// Lifts points to the paraboloid, as qhull does for a Delaunay triangulation
+///
+/// `coords` are the flattened coordinates of points of dimension `dim`,
+/// the sum of the squares of the coordinates of each point is appended to it
+/// (the points become of dimension `dim + 1`).
+/// The sum is computed as in `qh_setdelaunay`, so the result is the same to the last bit:
+/// the lower facets of the convex hull of the lifted points are the Delaunay triangulation.
+///
+/// Qhull also scales the lifted coordinate with [`scale_last_coordinate`](crate::QhBuilder::scale_last_coordinate) (`Qbb`),
+/// this function does not.
+/// The scaling maps the coordinate linearly to a new range, it does not change the lower facets,
+/// so the lifted points can be built with or without it
+/// (the coordinates of the instance are then scaled, see [`Qh::scaling`](crate::Qh::scaling)).
+///
+/// # Example
+/// ```
+/// # use qhull::{*, helpers::*};
+/// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.3, 0.7], [0.1, 0.2]];
+/// let mut coords: Vec<f64> = points.iter().flatten().copied().collect();
+/// lift_to_paraboloid(2, &mut coords).unwrap();
+/// assert_eq!(&coords[9..12], &[0.3, 0.7, 0.3 * 0.3 + 0.7 * 0.7]);
+///
+/// // the same coordinates as the ones lifted by qhull
+/// let qh = Qh::builder()
+///     .delaunay(true)
+///     .project_delaunay(true)
+///     .build_from_iter(points)
+///     .unwrap();
+/// for vertex in qh.vertices() {
+///     let i = vertex.index(&qh).unwrap();
+///     assert_eq!(vertex.point().unwrap(), &coords[3 * i..3 * i + 3]);
+/// }
+///
+/// // and the same as `qh_setdelaunay`
+/// let mut point = [0.3, 0.7, 0.0];
+/// unsafe { sys::qh_setdelaunay(Qh::raw_ptr(&qh) as *mut _, 3, 1, point.as_mut_ptr()) };
+/// assert_eq!(point, coords[9..12]);
+///
+/// assert!(lift_to_paraboloid(2, &mut vec![0.0; 3]).is_err());
+/// ```
+///
+/// # Errors
+/// An [`InvalidInput`](crate::QhErrorKind::InvalidInput) error if `dim` is `0`
+/// or if the number of coordinates is not a multiple of `dim`, `coords` is not modified.
+pub fn lift_to_paraboloid(dim: usize, coords: &mut Vec<f64>) -> Result<(), QhError<'static>> {
+    lift_points(dim, coords, |_, paraboloid| paraboloid)
+}
+
+/// Lifts weighted points for a power diagram (regular triangulation)
+///
+/// As [`lift_to_paraboloid`], but the weight of each point is subtracted from its lifted coordinate.
+/// The lower facets of the convex hull of the lifted points are the regular triangulation,
+/// the weighted Delaunay triangulation dual to the power diagram of the points:
+/// a point whose weight is too small may not be a vertex of it.
+/// With all the weights equal, this is the Delaunay triangulation.
+///
+/// The lifted points are an ordinary input, build them without [`delaunay`](crate::QhBuilder::delaunay)
+/// and use the lower facets, see [`Facet::is_lower_delaunay`](crate::Facet::is_lower_delaunay).
+/// As for [`lift_to_paraboloid`], the lifted coordinate may be scaled with
+/// [`scale_last_coordinate`](crate::QhBuilder::scale_last_coordinate).
+///
+/// # Example
+/// ```
+/// # use qhull::{*, helpers::*};
+/// let mut coords = vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.5, 0.5];
+/// lift_with_weights(2, &mut coords, &[0.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+/// assert_eq!(&coords[12..], &[0.5, 0.5, 0.5 - 1.0]);
+///
+/// // the heavy center point is connected to the four corners
+/// let qh = Qh::builder().build_managed(3, coords.clone()).unwrap();
+/// let mut triangles = 0;
+/// for facet in qh.lower_facets() {
+///     let vertices = facet.vertices().unwrap();
+///     assert!(vertices.iter().any(|v| v.index(&qh) == Some(4)));
+///     triangles += 1;
+/// }
+/// assert_eq!(triangles, 4);
+///
+/// // with a light center point, the square is split in two triangles
+/// let mut light = coords.chunks(3).flat_map(|p| &p[..2]).copied().collect();
+/// lift_with_weights(2, &mut light, &[0.0, 0.0, 0.0, 0.0, -1.0]).unwrap();
+/// let mut qh = Qh::builder().build_managed(3, light).unwrap();
+/// qh.triangulate().unwrap();
+/// assert_eq!(qh.lower_facets().count(), 2);
+/// assert!(qh.lower_facets().all(|f| f.vertices().unwrap().iter().all(|v| v.index(&qh) != Some(4))));
+///
+/// assert!(lift_with_weights(2, &mut vec![0.0; 4], &[0.0]).is_err());
+/// ```
+///
+/// # Errors
+/// As [`lift_to_paraboloid`], and if there is not a weight for each point.
+pub fn lift_with_weights(dim: usize, coords: &mut Vec<f64>, weights: &[f64]) -> Result<(), QhError<'static>> {
+    if dim != 0 && coords.len() / dim != weights.len() {
+        return Err(QhError::invalid_input(format!(
+            "got {} weights for {} points",
+            weights.len(),
+            coords.len() / dim,
+        )));
+    }
+    lift_points(dim, coords, |index, paraboloid| paraboloid - weights[index])
+}
+
+/// Appends the lifted coordinate to each point, `lift` gets the index of the point and its sum of squares
+fn lift_points(dim: usize, coords: &mut Vec<f64>, lift: impl Fn(usize, f64) -> f64) -> Result<(), QhError<'static>> {
+    if dim == 0 || !coords.len().is_multiple_of(dim) {
+        return Err(QhError::invalid_input(format!(
+            "{} coordinates are not a list of points of dimension {}",
+            coords.len(),
+            dim,
+        )));
+    }
+    let mut lifted = Vec::with_capacity(coords.len() / dim * (dim + 1));
+    for (index, point) in coords.chunks_exact(dim).enumerate() {
+        // the same order of operations as `qh_setdelaunay`
+        let paraboloid = point[1..].iter().fold(point[0] * point[0], |sum, x| sum + x * x);
+        lifted.extend_from_slice(point);
+        lifted.push(lift(index, paraboloid));
+    }
+    *coords = lifted;
+    Ok(())
+}
+
+/// Dimension of the affine hull of some points
+///
+/// `coords` are the flattened coordinates of points of dimension `dim`.
+/// A direction is counted when the points extend along it by more than `tolerance`.
+/// Returns `0` for a single point (or none) and for points of dimension `0`.
+///
+/// # Example
+/// ```
+/// # use qhull::helpers::*;
+/// let collinear = [0.0, 0.0, 1.0, 2.0, 2.0, 4.0, 3.0, 6.0];
+/// assert_eq!(affine_rank(&collinear, 2, 1e-12), 1);
+///
+/// let triangle = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+/// assert_eq!(affine_rank(&triangle, 2, 1e-12), 2);
+///
+/// assert_eq!(affine_rank(&triangle, 0, 1e-12), 0);
+/// ```
+pub fn affine_rank(coords: &[f64], dim: usize, tolerance: f64) -> usize {
+    if dim == 0 {
+        return 0;
+    }
+    let mut points = coords.chunks_exact(dim);
+    let Some(origin) = points.next() else {
+        return 0;
+    };
+    // orthonormal basis of the directions found so far (Gram-Schmidt)
+    let mut basis: Vec<Vec<f64>> = Vec::with_capacity(dim);
+    let mut residual = vec![0.0; dim];
+    for point in points {
+        if basis.len() == dim {
+            break;
+        }
+        residual.iter_mut().zip(point.iter().zip(origin)).for_each(|(r, (p, o))| *r = p - o);
+        for direction in &basis {
+            let projection: f64 = residual.iter().zip(direction).map(|(r, d)| r * d).sum();
+            residual.iter_mut().zip(direction).for_each(|(r, d)| *r -= projection * d);
+        }
+        let norm = residual.iter().map(|r| r * r).sum::<f64>().sqrt();
+        if norm > tolerance {
+            basis.push(residual.iter().map(|r| r / norm).collect());
+        }
+    }
+    basis.len()
+}
+
+/// Dual points of halfspaces about a feasible point
+///
+/// This is the transform done by qhull before computing a halfspace intersection (`qh_sethalfspace_all`):
+/// each halfspace `normal · x + offset <= 0` becomes the point `normal / -(normal · feasible + offset)`.
+/// The intersection of the halfspaces is the polar dual of the convex hull of these points,
+/// translated to the feasible point.
+///
+/// `dim` is the dimension of the space: `halfspaces` are the flattened halfspaces,
+/// each with `dim` normal coefficients followed by the offset,
+/// and `feasible` is a point with `dim` coordinates clearly inside all of them.
+/// Returns the flattened dual points, `dim` coordinates for each halfspace.
+/// No hull is computed: a qhull instance is only created to run the transform.
+///
+/// # Example
+/// ```
+/// # use qhull::{helpers::*, QhErrorKind};
+/// // the box `-1 <= x <= 1`, `-2 <= y <= 2`
+/// let halfspaces = [
+///     1.0, 0.0, -1.0,
+///     -1.0, 0.0, -1.0,
+///     0.0, 1.0, -2.0,
+///     0.0, -1.0, -2.0,
+/// ];
+///
+/// let dual = halfspaces_to_dual_points(2, &halfspaces, &[0.0, 0.0]).unwrap();
+/// assert_eq!(dual, vec![1.0, 0.0, -1.0, 0.0, 0.0, 0.5, 0.0, -0.5]);
+///
+/// // the distances of the faces from the feasible point change
+/// let dual = halfspaces_to_dual_points(2, &halfspaces, &[0.5, 0.0]).unwrap();
+/// assert_eq!(dual, vec![2.0, 0.0, -1.0 / 1.5, 0.0, 0.0, 0.5, 0.0, -0.5]);
+///
+/// // the feasible point must be inside all the halfspaces
+/// let error = halfspaces_to_dual_points(2, &halfspaces, &[3.0, 0.0]).unwrap_err();
+/// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+/// assert!(error.error_message.unwrap().contains("feasible point is not clearly inside halfspace"));
+///
+/// assert!(halfspaces_to_dual_points(2, &halfspaces[1..], &[0.0, 0.0]).is_err());
+/// assert!(halfspaces_to_dual_points(2, &halfspaces, &[0.0, 0.0, 0.0]).is_err());
+/// ```
+///
+/// # Errors
+/// An [`InvalidInput`](crate::QhErrorKind::InvalidInput) error if `dim` is `0`,
+/// if the length of `halfspaces` is not a multiple of `dim + 1`,
+/// if `feasible` does not have `dim` coordinates
+/// or if the feasible point is not clearly inside a halfspace.
+pub fn halfspaces_to_dual_points(dim: usize, halfspaces: &[f64], feasible: &[f64]) -> Result<Vec<f64>, QhError<'static>> {
+    if dim == 0 || !halfspaces.len().is_multiple_of(dim + 1) {
+        return Err(QhError::invalid_input(format!(
+            "halfspaces must have {} values (the normal and the offset), got {} values",
+            dim + 1,
+            halfspaces.len(),
+        )));
+    }
+    if feasible.len() != dim {
+        return Err(QhError::invalid_input(format!(
+            "feasible point has dimension {}, expected {}",
+            feasible.len(),
+            dim,
+        )));
+    }
+    let count = halfspaces.len() / (dim + 1);
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    let count_c = c_int::try_from(count).map_err(|_| QhError::invalid_input("too many halfspaces"))?;
+
+    let mut buffers = IOBuffers::new(false, true);
+    unsafe {
+        let mut qh: sys::qhT = std::mem::zeroed();
+        sys::qh_init_A(
+            &mut qh,
+            buffers.in_file(),
+            buffers.out_file(),
+            buffers.err_file(),
+            0,
+            ptr::null_mut(),
+        );
+        // qhull only reads the halfspaces and the feasible point
+        let dual = QhError::try_on_raw(&mut qh, &mut buffers, |qh| {
+            sys::qh_sethalfspace_all(
+                qh,
+                (dim + 1) as c_int,
+                count_c,
+                halfspaces.as_ptr() as *mut f64,
+                feasible.as_ptr() as *mut f64,
+            )
+        })
+        .map(|points| {
+            // the points are allocated with `qh_malloc`
+            let dual = std::slice::from_raw_parts(points, count * dim).to_vec();
+            sys::qh_free(points as *mut _);
+            dual
+        });
+        sys::qh_freeqhull(&mut qh, !sys::qh_ALL);
+        dual
+    }
+}
+
 pub struct CArgs {
     args: Vec<CString>,
     args_ptr: Vec<*const c_char>,
@@ -1,25 +1,149 @@
-use crate::{sys, tmp_file::TmpFile};
+use std::{cell::RefCell, ffi::c_void, io::{self, Write}, os::raw::{c_char, c_int}, rc::Rc};
+
+use crate::{sys, tmp_file::TmpFile, QhMessage};
+
+/// A message callback, see [`QhBuilder::message_handler`](crate::QhBuilder::message_handler)
+pub type MessageHandler = Rc<RefCell<Box<dyn FnMut(QhMessage)>>>;
+
+/// Destination of a qhull stream, instead of a `FILE`
+#[derive(Clone)]
+pub enum OutputSink {
+    /// A writer, shared between the builder and the instances it creates
+    Writer(Rc<RefCell<Box<dyn Write>>>),
+    /// A callback receiving each message, shared between the streams
+    Handler(MessageHandler),
+    /// Events of the [`tracing`] crate, one for each qhull message
+    #[cfg(feature = "tracing")]
+    Tracing,
+}
+
+impl OutputSink {
+    fn flush(&self) {
+        match self {
+            OutputSink::Writer(writer) => {
+                if let Ok(mut writer) = writer.try_borrow_mut() {
+                    let _ = writer.flush();
+                }
+            }
+            OutputSink::Handler(_) => {}
+            #[cfg(feature = "tracing")]
+            OutputSink::Tracing => {}
+        }
+    }
+}
 
 pub struct IOBuffers {
     pub out_file: Option<TmpFile>,
     pub err_file: Option<TmpFile>,
+    /// For a file given by the user, the size of the text already taken from `out_file`, see [`IOBuffers::use_out_file`]
+    out_start: Option<u64>,
+    /// For a file given by the user, the size of the text already taken from `err_file`
+    err_start: Option<u64>,
+    /// Owned, allocated with [`Box::into_raw`] because qhull writes through it while `self` is borrowed
+    writers: *mut Writers,
+}
+
+/// The writers attached to a qhull instance
+///
+/// `raw` is given to qhull, its `data` points back to this struct.
+struct Writers {
+    raw: sys::qhull_sys__writers,
+    stdout: Option<OutputSink>,
+    stderr: Option<OutputSink>,
+    /// Whether the text written to stderr is also kept in `error_message`
+    recording: bool,
+    error_message: Vec<u8>,
 }
 
 impl IOBuffers {
     pub fn new(capture_stdout: bool, capture_stderr: bool) -> Self {
+        Self::with_writers(capture_stdout, capture_stderr, None, None)
+    }
+
+    /// Same as [`IOBuffers::new`], but returns an error if a temporary file cannot be created
+    pub fn try_new(capture_stdout: bool, capture_stderr: bool) -> io::Result<Self> {
+        let mut buffers = Self::new(false, false);
+        buffers.out_file = capture_stdout.then(TmpFile::new).transpose()?;
+        buffers.err_file = capture_stderr.then(TmpFile::new).transpose()?;
+        Ok(buffers)
+    }
+
+    /// Create the buffers, the sinks take precedence over the temporary files
+    ///
+    /// No temporary file is created for a stream with a sink.
+    pub fn with_writers(
+        capture_stdout: bool,
+        capture_stderr: bool,
+        stdout: Option<OutputSink>,
+        stderr: Option<OutputSink>,
+    ) -> Self {
+        let tmp_file = |capture: bool, writer: &Option<OutputSink>, what: &str| {
+            (capture && writer.is_none()).then(|| {
+                TmpFile::new().unwrap_or_else(|_| panic!("failed to create temporary file for {}", what))
+            })
+        };
+        let out_file = tmp_file(capture_stdout, &stdout, "stdout");
+        let err_file = tmp_file(capture_stderr, &stderr, "stderr");
+
+        let writers = if stdout.is_some() || stderr.is_some() {
+            let writers = Box::into_raw(Box::new(Writers {
+                raw: sys::qhull_sys__writers {
+                    write: Some(write_to_writer),
+                    data: std::ptr::null_mut(),
+                },
+                stdout,
+                stderr,
+                recording: false,
+                error_message: Vec::new(),
+            }));
+            unsafe { (*writers).raw.data = writers as *mut c_void };
+            writers
+        } else {
+            std::ptr::null_mut()
+        };
+
         Self {
-            out_file: capture_stdout
-                .then(|| TmpFile::new().expect("failed to create temporary file for stdout")),
-            err_file: capture_stderr
-                .then(|| TmpFile::new().expect("failed to create temporary file for stderr")),
+            out_file,
+            err_file,
+            out_start: None,
+            err_start: None,
+            writers,
         }
     }
 
+    /// Capture stdout in a file given by the user, see [`QhBuilder::stdout_file`](crate::QhBuilder::stdout_file)
+    ///
+    /// The file is never replaced: taking its content reads the text written after the previous take,
+    /// starting from the current end of the file.
+    /// Nothing changes if stdout is sent to a [sink](OutputSink).
+    /// Must be called before the streams are given to qhull.
+    pub fn use_out_file(&mut self, mut file: TmpFile) -> io::Result<()> {
+        if !self.writer(|w| w.stdout.is_some()) {
+            self.out_start = Some(file.size()?);
+            self.out_file = Some(file);
+        }
+        Ok(())
+    }
+
+    /// Capture stderr in a file given by the user, see [`QhBuilder::stderr_file`](crate::QhBuilder::stderr_file)
+    ///
+    /// As [`IOBuffers::use_out_file`]: the error messages are read from the file, which is not replaced after an error.
+    pub fn use_err_file(&mut self, mut file: TmpFile) -> io::Result<()> {
+        if !self.writer(|w| w.stderr.is_some()) {
+            self.err_start = Some(file.size()?);
+            self.err_file = Some(file);
+        }
+        Ok(())
+    }
+
     pub fn in_file(&self) -> *mut sys::FILE {
         unsafe { sys::qhull_sys__stdin() }
     }
 
     pub fn out_file(&self) -> *mut sys::FILE {
+        if self.writer(|w| w.stdout.is_some()) {
+            return unsafe { sys::qhull_sys__writer_file(sys::QHULL_SYS_STDOUT_WRITER as _) };
+        }
         self.out_file.as_ref().map_or_else(
             || unsafe { sys::qhull_sys__stdout() },
             |f| f.file_handle() as *mut _,
@@ -27,9 +151,314 @@ impl IOBuffers {
     }
 
     pub fn err_file(&self) -> *mut sys::FILE {
+        if self.writer(|w| w.stderr.is_some()) {
+            return unsafe { sys::qhull_sys__writer_file(sys::QHULL_SYS_STDERR_WRITER as _) };
+        }
         self.err_file.as_ref().map_or_else(
             || unsafe { sys::qhull_sys__stderr() },
             |f| f.file_handle() as *mut _,
         )
     }
+
+    /// Attach the writers to a qhull instance
+    ///
+    /// Must be called after `qh_init_A`, which clears the instance.
+    ///
+    /// # Safety
+    /// `qh` must be valid and must not be used after `self` is dropped
+    pub unsafe fn attach(&self, qh: *mut sys::qhT) {
+        if !self.writers.is_null() {
+            sys::qhull_sys__set_writers(qh, &mut (*self.writers).raw);
+        }
+    }
+
+    /// Start collecting the error messages of a qhull call
+    pub fn begin_error_capture(&mut self) {
+        if let Some(writers) = unsafe { self.writers.as_mut() } {
+            writers.recording = writers.stderr.is_some();
+            writers.error_message.clear();
+        }
+    }
+
+    /// Take the error messages written since [`IOBuffers::begin_error_capture`]
+    ///
+    /// When stderr is captured in a temporary file, the file is replaced with a new one
+    /// (a file given by the user is kept, see [`IOBuffers::use_err_file`]).
+    /// Returns `None` if the error output is not captured, or if the captured file cannot be read.
+    ///
+    /// # Safety
+    /// `qh` must be the instance using these buffers
+    pub unsafe fn take_error_message(&mut self, qh: *mut sys::qhT) -> Option<String> {
+        if let Some(writers) = self.writers.as_mut() {
+            if writers.recording {
+                writers.recording = false;
+                let message = std::mem::take(&mut writers.error_message);
+                return Some(String::from_utf8_lossy(&message).into_owned());
+            }
+        }
+        self.take_err_file(qh).ok().flatten()
+    }
+
+    /// Take the content of the temporary file capturing stderr, replacing it with a new one
+    ///
+    /// If the replacement cannot be created, the file is kept and only the text not yet taken is read.
+    /// Returns `None` if stderr is not captured in a temporary file.
+    ///
+    /// # Safety
+    /// `qh` must be the instance using these buffers
+    pub unsafe fn take_err_file(&mut self, qh: *mut sys::qhT) -> io::Result<Option<String>> {
+        if let Some(start) = &mut self.err_start {
+            return self.err_file.as_mut().map(|file| take_from(file, start)).transpose();
+        }
+        let Some(file) = &self.err_file else {
+            return Ok(None);
+        };
+        let content = match TmpFile::new() {
+            Ok(replacement) => {
+                let err_file = replacement.file_handle();
+                let file = self.err_file.replace(replacement).unwrap();
+                (*qh).ferr = err_file;
+                (*qh).qhmem.ferr = err_file;
+                file.read_so_far()?
+            }
+            Err(_) => file.read_so_far()?,
+        };
+        Ok(Some(String::from_utf8_lossy(&content).into_owned()))
+    }
+
+    /// Take the content of the temporary file capturing stdout, replacing it with a new one
+    ///
+    /// The text already [drained](IOBuffers::drain_out_file) is not returned again.
+    /// As for [`IOBuffers::take_err_file`], the file is kept if the replacement cannot be created.
+    /// Returns `None` if stdout is not captured in a temporary file.
+    ///
+    /// # Safety
+    /// `qh` must be the instance using these buffers
+    pub unsafe fn take_out_file(&mut self, qh: *mut sys::qhT) -> io::Result<Option<String>> {
+        if let Some(start) = &mut self.out_start {
+            return self.out_file.as_mut().map(|file| take_from(file, start)).transpose();
+        }
+        let Some(file) = &self.out_file else {
+            return Ok(None);
+        };
+        let content = match TmpFile::new() {
+            Ok(replacement) => {
+                (*qh).fout = replacement.file_handle();
+                self.out_file.replace(replacement).unwrap().read_so_far()?
+            }
+            Err(_) => file.read_so_far()?,
+        };
+        Ok(Some(String::from_utf8_lossy(&content).into_owned()))
+    }
+
+    /// Read the text written to stdout since the previous drain or take, without replacing the file
+    ///
+    /// qhull can keep writing to the file, at the same position.
+    /// Returns `None` if stdout is not captured in a file.
+    pub fn drain_out_file(&mut self) -> io::Result<Option<String>> {
+        let Some(file) = self.out_file.as_mut() else {
+            return Ok(None);
+        };
+        if let Some(start) = &mut self.out_start {
+            return take_from(file, start).map(Some);
+        }
+        let content = file.read_so_far()?;
+        Ok(Some(String::from_utf8_lossy(&content).into_owned()))
+    }
+
+    /// Start or stop capturing stdout in a temporary file
+    ///
+    /// When the capture stops, the captured text is discarded and qhull writes to the standard output.
+    /// Nothing changes if stdout is sent to a [sink](OutputSink), or if the temporary file cannot be created.
+    ///
+    /// # Safety
+    /// `qh` must be the instance using these buffers
+    pub unsafe fn set_capture_stdout(&mut self, qh: *mut sys::qhT, capture: bool) -> io::Result<()> {
+        if self.writer(|w| w.stdout.is_some()) || capture == self.out_file.is_some() {
+            return Ok(());
+        }
+        let file = capture.then(TmpFile::new).transpose()?;
+        let previous = std::mem::replace(&mut self.out_file, file);
+        self.out_start = None;
+        (*qh).fout = self.out_file();
+        drop(previous);
+        Ok(())
+    }
+
+    /// Start or stop capturing stderr in a temporary file
+    ///
+    /// When the capture stops, the captured text is discarded and qhull writes to the standard error:
+    /// the errors of the following calls have no [message](crate::QhError::error_message).
+    /// Nothing changes if stderr is sent to a [sink](OutputSink), or if the temporary file cannot be created.
+    ///
+    /// # Safety
+    /// `qh` must be the instance using these buffers, no qhull call must be running on it
+    pub unsafe fn set_capture_stderr(&mut self, qh: *mut sys::qhT, capture: bool) -> io::Result<()> {
+        if self.writer(|w| w.stderr.is_some()) || capture == self.err_file.is_some() {
+            return Ok(());
+        }
+        let file = capture.then(TmpFile::new).transpose()?;
+        let previous = std::mem::replace(&mut self.err_file, file);
+        self.err_start = None;
+        let err_file = self.err_file();
+        (*qh).ferr = err_file;
+        (*qh).qhmem.ferr = err_file;
+        drop(previous);
+        Ok(())
+    }
+
+    /// Read the content of the temporary file capturing stderr, without taking it
+    ///
+    /// Returns `None` if stderr is not captured in a temporary file.
+    pub fn peek_err_file(&mut self) -> Option<String> {
+        if let Some(start) = self.err_start {
+            let content = self.err_file.as_mut()?.read_from(start).ok()?;
+            return Some(String::from_utf8_lossy(&content).into_owned());
+        }
+        let mut content = Vec::new();
+        self.err_file.as_mut()?.write_to(&mut content).ok()?;
+        Some(String::from_utf8_lossy(&content).into_owned())
+    }
+
+    fn writer(&self, f: impl FnOnce(&Writers) -> bool) -> bool {
+        unsafe { self.writers.as_ref() }.is_some_and(f)
+    }
+}
+
+/// Read the text written to a file given by the user after `start`, and move `start` to the end
+///
+/// `start` is not moved if the file cannot be read.
+fn take_from(file: &mut TmpFile, start: &mut u64) -> io::Result<String> {
+    let content = file.read_from(*start)?;
+    *start += content.len() as u64;
+    Ok(String::from_utf8_lossy(&content).into_owned())
+}
+
+impl Drop for IOBuffers {
+    fn drop(&mut self) {
+        if !self.writers.is_null() {
+            let writers = unsafe { Box::from_raw(self.writers) };
+            for sink in writers.stdout.iter().chain(&writers.stderr) {
+                sink.flush();
+            }
+        }
+    }
+}
+
+/// Called by `qh_fprintf` for the writer streams
+///
+/// Write errors are ignored, as qhull ignores the errors of `fprintf`.
+unsafe extern "C" fn write_to_writer(
+    data: *mut c_void,
+    stream: c_int,
+    msgcode: c_int,
+    text: *const c_char,
+    len: usize,
+) {
+    let writers = &mut *(data as *mut Writers);
+    let text = std::slice::from_raw_parts(text as *const u8, len);
+    let sink = if stream == sys::QHULL_SYS_STDOUT_WRITER as c_int {
+        &writers.stdout
+    } else {
+        if writers.recording {
+            writers.error_message.extend_from_slice(text);
+        }
+        &writers.stderr
+    };
+    match sink {
+        Some(OutputSink::Writer(writer)) => {
+            if let Ok(mut writer) = writer.try_borrow_mut() {
+                let _ = writer.write_all(text);
+            }
+        }
+        Some(OutputSink::Handler(handler)) => {
+            // a message printed by the handler itself through qhull is dropped
+            if let Ok(mut handler) = handler.try_borrow_mut() {
+                (*handler)(QhMessage::new(msgcode.max(0) as u32, text));
+            }
+        }
+        #[cfg(feature = "tracing")]
+        Some(OutputSink::Tracing) => trace_message(msgcode, text),
+        None => {}
+    }
+}
+
+/// A writer receiving the stdout of an instance, see [`redirect_stdout`]
+struct Redirect<'w> {
+    writer: &'w mut dyn Write,
+    /// The first error of the writer, the following text is discarded
+    error: Option<io::Error>,
+    /// The writers of the instance, they still receive the other streams
+    previous: *mut sys::qhull_sys__writers,
+}
+
+/// Send the text that qhull prints to the stdout writer stream to `writer` while `f` runs
+///
+/// `f` must print to `qhull_sys__writer_file(QHULL_SYS_STDOUT_WRITER)`:
+/// the text is written to `writer` as it is produced, without a temporary file.
+/// The text printed to stderr still goes to the writers of the instance, if any.
+/// Returns the result of `f` and the first error of the writer.
+///
+/// # Safety
+/// `qh` must be valid, and `f` must not change the writers of the instance
+pub(crate) unsafe fn redirect_stdout<R>(
+    qh: *mut sys::qhT,
+    writer: &mut dyn Write,
+    f: impl FnOnce() -> R,
+) -> (R, io::Result<()>) {
+    let previous = (*qh).cpp_user;
+    let mut redirect = Redirect {
+        writer,
+        error: None,
+        previous: previous as *mut sys::qhull_sys__writers,
+    };
+    let mut raw = sys::qhull_sys__writers {
+        write: Some(write_redirected),
+        data: &mut redirect as *mut Redirect as *mut c_void,
+    };
+    sys::qhull_sys__set_writers(qh, &mut raw);
+    let result = f();
+    (*qh).cpp_user = previous;
+    (result, redirect.error.map_or(Ok(()), Err))
+}
+
+/// Called by `qh_fprintf` for the writer streams during [`redirect_stdout`]
+unsafe extern "C" fn write_redirected(
+    data: *mut c_void,
+    stream: c_int,
+    msgcode: c_int,
+    text: *const c_char,
+    len: usize,
+) {
+    let redirect = &mut *(data as *mut Redirect);
+    if stream == sys::QHULL_SYS_STDOUT_WRITER as c_int {
+        if redirect.error.is_none() {
+            let text = std::slice::from_raw_parts(text as *const u8, len);
+            if let Err(error) = redirect.writer.write_all(text) {
+                redirect.error = Some(error);
+            }
+        }
+    } else if let Some(previous) = redirect.previous.as_ref() {
+        if let Some(write) = previous.write {
+            write(previous.data, stream, msgcode, text, len);
+        }
+    }
+}
+
+/// Emit a qhull message as a [`tracing`] event
+///
+/// The level depends on the message code: `6xxx` are errors, `7xxx` are warnings
+/// and the other messages (traces, reports) are debug events.
+#[cfg(feature = "tracing")]
+fn trace_message(code: c_int, text: &[u8]) {
+    let message = String::from_utf8_lossy(text);
+    let message = message.trim_end();
+    if message.is_empty() {
+        return;
+    }
+    match code {
+        6000..=6999 => tracing::error!(target: "qhull", code, "{}", message),
+        7000..=7999 => tracing::warn!(target: "qhull", code, "{}", message),
+        _ => tracing::debug!(target: "qhull", code, "{}", message),
+    }
 }
@@ -1,10 +1,12 @@
 #![doc = include_str!("../README.md")]
 
-use std::{cell::{RefCell, UnsafeCell}, marker::PhantomData, rc::Rc};
+use std::{cell::{RefCell, UnsafeCell}, collections::{HashMap, HashSet, VecDeque}, marker::PhantomData, ops::ControlFlow, rc::Rc};
 
-use helpers::{prepare_delaunay_points, CollectedCoords, QhTypeRef};
+use helpers::{try_collect_coords, CollectedCoords, QhTypeRef};
 use io_buffers::IOBuffers;
+use tmp_file::TmpFile;
 pub use qhull_sys as sys;
+pub use sys::QHULL_LICENSE_TEXT;
 
 mod error;
 pub mod helpers;
@@ -15,8 +17,70 @@ mod builder;
 pub use builder::*;
 mod types;
 pub use types::*;
+mod simplex_transform;
+pub use simplex_transform::*;
+mod print_format;
+pub use print_format::*;
+mod statistics;
+pub use statistics::*;
+mod counters;
+pub use counters::*;
+mod merge_report;
+pub use merge_report::*;
+mod message;
+pub use message::*;
+mod precision_report;
+pub use precision_report::*;
+mod typed;
+pub use typed::*;
+mod triangle_mesh;
+pub use triangle_mesh::*;
+mod delaunay_edge;
+pub use delaunay_edge::*;
+mod voronoi_ridge;
+pub use voronoi_ridge::*;
+mod validation_report;
+pub use validation_report::*;
+mod owned_hull;
+pub use owned_hull::*;
+mod rebuild;
+pub use rebuild::QhRebuildOptions;
+mod dedup;
+mod natural_neighbor;
+mod rotation;
+mod scaling;
+pub use scaling::AxisScaling;
+#[cfg(feature = "geo")]
+mod geo_interop;
+#[cfg(feature = "mint")]
+mod mint_interop;
+#[cfg(feature = "mint")]
+pub use mint_interop::MintPoint;
+#[cfg(feature = "glam")]
+mod glam_interop;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_interop;
+use rebuild::BuildSettings;
+use natural_neighbor::NaturalNeighbors;
+pub mod programs;
+pub mod rbox;
 pub mod examples;
 
+/// Version of the qhull library the crate was built with
+///
+/// This is `qh_version`, the version and date of the qhull sources,
+/// see [`sys::qh_version2_str`] for the long form.
+///
+/// # Example
+/// ```
+/// let version = qhull::qhull_version();
+/// assert!(version.contains("20"));
+/// assert!(qhull::sys::qh_version2_str().contains(version));
+/// ```
+pub fn qhull_version() -> &'static str {
+    sys::qh_version_str()
+}
+
 /// A Qhull instance
 ///
 /// This struct is the main interface to the qhull library.
@@ -29,6 +93,20 @@ pub struct Qh<'a> {
     dim: usize,
     buffers: RefCell<IOBuffers>,
     owned_values: OwnedValues,
+    /// Settings before the first build, for [`Qh::rebuild`]
+    build_settings: Option<rebuild::BuildSettings>,
+    /// Indices of the input points when the duplicates have been removed, see [`QhBuilder::dedup_points`]
+    point_map: Option<dedup::PointMap>,
+    /// Rotation applied to the input points, see [`Qh::input_rotation`]
+    input_rotation: Option<Vec<Vec<f64>>>,
+    /// Scaling of the input coordinates, see [`Qh::scaling`]
+    input_scaling: Vec<scaling::AxisScaling>,
+    /// Seed of the random numbers, see [`QhBuilder::random_seed`]
+    random_seed: Option<u32>,
+    /// Whether [`Qh::compute`] has been called
+    computed: bool,
+    /// Cache of [`Qh::point_vertex_map`]
+    point_vertices: Option<PointVertices>,
     phantom: PhantomData<&'a ()>,
 }
 
@@ -38,156 +116,3415 @@ impl<'a> Qh<'a> {
         QhBuilder::default()
     }
 
-    /// Compute the convex hull
-    pub fn compute(&mut self) -> Result<(), QhError> {
-        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_qhull(qh)) }
+    /// Compute the convex hull
+    ///
+    /// The hull can only be computed once: the next calls fail with [`QhErrorKind::AlreadyComputed`],
+    /// even if the first one failed, see [`Qh::is_computed`].
+    /// Use [`Qh::rebuild`] or [`Qh::reset_with_points`] to compute the hull again.
+    ///
+    /// # Example
+    /// Qhull errors are returned as [`QhError`], the instance can still be used and dropped:
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .compute(false)
+    ///     .skip_validation(true)
+    ///     .build_from_iter([[1.0, 1.0]; 4])
+    ///     .unwrap();
+    ///
+    /// // identical points, qhull fails while searching the initial simplex
+    /// let error = qh.compute().unwrap_err();
+    /// assert_ne!(error.kind.error_code(), 0);
+    /// assert!(error.error_message.unwrap().contains("QH6"));
+    /// assert_eq!(qh.num_facets(), 0);
+    ///
+    /// assert_eq!(qh.compute().unwrap_err().kind, QhErrorKind::AlreadyComputed);
+    /// ```
+    pub fn compute(&mut self) -> Result<(), QhError<'_>> {
+        // a poisoned instance reports its own error
+        if self.computed && !self.is_poisoned() {
+            return Err(QhError::already_computed());
+        }
+        // every slice of coordinates (normals, points, ...) relies on this
+        debug_assert_eq!(self.dim, self.hull_dim(), "cached dimension does not match qhull");
+        self.computed = true;
+        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_qhull(qh)) }
+    }
+
+    /// Check whether the hull has been computed
+    ///
+    /// This is `true` once [`Qh::compute`] has been called, even if it failed,
+    /// and it is always the case for an instance built with [`compute`](QhBuilder::compute) enabled (the default).
+    /// Before that, [`Qh::facets`] and [`Qh::vertices`] are empty and [`Qh::num_facets`] and [`Qh::num_vertices`] are `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .compute(false)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert!(!qh.is_computed());
+    /// assert_eq!(qh.facets().count(), 0);
+    ///
+    /// qh.compute().unwrap();
+    /// assert!(qh.is_computed());
+    /// assert_eq!(qh.facets().count(), 3);
+    /// ```
+    pub fn is_computed(&self) -> bool {
+        self.computed
+    }
+
+    /// First element of a facet or vertex list, null if the hull has not been computed
+    fn list_head<T>(&self, get: impl FnOnce(*mut sys::qhT) -> *mut T) -> *mut T {
+        if self.computed {
+            get(self.qh.get())
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+
+    /// Check the output of the qhull instance
+    pub fn check_output(&mut self) -> Result<(), QhError<'_>> {
+        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_check_output(qh)) }
+    }
+
+    pub fn check_points(&mut self) -> Result<(), QhError<'_>> {
+        unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                println!("qh_check_points!!!");
+                sys::qh_check_points(qh)
+            })
+        }
+    }
+
+    /// Check that the hull is convex
+    ///
+    /// This wraps `qh_checkconvex`: every facet is tested against the vertices and centrums of its neighbors.
+    /// A non-convex hull (e.g. after merging with wide tolerances) results in an error containing qhull's explanation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::{*, helpers::QhTypeRef};
+    /// let points = [
+    ///     [0.0, 0.0, 0.0],
+    ///     [1.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 1.0],
+    /// ];
+    /// let mut qh = Qh::builder().build_from_iter(points).unwrap();
+    /// qh.check_convexity().unwrap();
+    ///
+    /// // corrupt a facet by marking it as flipped (i.e. the interior point is outside of it)
+    /// let mut qh = Qh::builder().build_from_iter(points).unwrap();
+    /// unsafe {
+    ///     let facet = qh.facets().next().unwrap().raw_ptr();
+    ///     (*facet).set_flipped(true as _);
+    /// }
+    /// assert!(qh.check_convexity().is_err());
+    /// ```
+    pub fn check_convexity(&mut self) -> Result<(), QhError<'_>> {
+        unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                sys::qh_checkconvex(qh, (*qh).facet_list, sys::qh_ALGORITHMfault as _)
+            })
+        }
+    }
+
+    /// Run all the integrity checks of qhull
+    ///
+    /// Runs [`Qh::check_output`], the check of the points (`qh_check_points`) and [`Qh::check_convexity`],
+    /// each one even if the previous ones failed, and collects their outcome and the messages
+    /// that qhull printed into a [`ValidationReport`].
+    /// Use [`ValidationReport::is_ok`] for a summary.
+    ///
+    /// The messages are read from the [captured](QhBuilder::capture_stderr) error output,
+    /// which is left untouched unless a check raises an error (the error takes the captured text).
+    /// Before the hull is [computed](Qh::is_computed) there is nothing to check, and the checks pass.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("100 D3").unwrap();
+    /// let mut qh = Qh::builder().keep_coplanar(true).build_managed(dim, coords).unwrap();
+    /// let report = qh.validate();
+    /// assert!(report.is_ok());
+    /// assert_eq!(report.misassigned_coplanar_points, 0);
+    ///
+    /// // a vertex is moved far outside after computing the hull,
+    /// // with `Po` qhull reports the problems without raising errors
+    /// let (dim, coords) = rbox::generate("100 D3").unwrap();
+    /// let mut qh = Qh::builder().force_output(true).build_managed(dim, coords).unwrap();
+    /// let index = qh.vertices().next().unwrap().index(&qh).unwrap();
+    /// unsafe {
+    ///     let point = (*Qh::raw_ptr(&qh)).first_point.add(index * 3);
+    ///     *point *= 10.0;
+    /// }
+    /// let report = qh.validate();
+    /// assert!(!report.is_ok());
+    /// assert!(!report.points.passed());
+    /// assert!(report.points.messages.iter().any(|m| m.severity == Severity::Error));
+    /// ```
+    pub fn validate(&mut self) -> ValidationReport {
+        let output = self.run_check(|qh| unsafe { sys::qh_check_output(qh) });
+        let points = self.run_check(|qh| unsafe { sys::qh_check_points(qh) });
+        let convexity = self.run_check(|qh| unsafe {
+            sys::qh_checkconvex(qh, (*qh).facet_list, sys::qh_ALGORITHMfault as _)
+        });
+
+        let qh = self.qh.get();
+        let tolerance = unsafe { (*qh).DISTround };
+        let dist = |point: *mut f64, facet: *mut sys::facetT| {
+            // qh_distplane does not raise qhull errors
+            let mut dist = 0.0;
+            unsafe { sys::qh_distplane(qh, point, facet, &mut dist) };
+            dist
+        };
+        let mut misassigned_coplanar_points = 0;
+        for facet in self.facets() {
+            let Some((coplanar, neighbors)) = facet.coplanar_set().zip(facet.neighbors()) else {
+                continue;
+            };
+            for point in &coplanar {
+                let (point, facet) = unsafe { (point.raw_ptr(), facet.raw_ptr()) };
+                let own = dist(point, facet);
+                if neighbors.iter().any(|n| dist(point, unsafe { n.raw_ptr() }) > own + tolerance) {
+                    misassigned_coplanar_points += 1;
+                }
+            }
+        }
+
+        ValidationReport {
+            output,
+            points,
+            convexity,
+            max_outside: unsafe { (*qh).max_outside },
+            misassigned_coplanar_points,
+        }
+    }
+
+    /// Run a check of [`Qh::validate`], collecting the messages printed meanwhile
+    fn run_check(&mut self, check: impl FnOnce(*mut sys::qhT)) -> CheckResult {
+        let before = self.buffers.get_mut().peek_err_file().map_or(0, |text| text.len());
+        let after_previous = |text: String| text.get(before..).map(message::parse_messages).unwrap_or_default();
+        match unsafe { Qh::try_on_qh_mut(self, check) } {
+            Ok(()) => CheckResult {
+                error: None,
+                facet: None,
+                messages: self.buffers.get_mut().peek_err_file().map(after_previous).unwrap_or_default(),
+            },
+            Err(error) => CheckResult {
+                error: Some(error.kind),
+                facet: error.face.map(|f| f.id()),
+                // the error took the captured text
+                messages: error.error_message.map(after_previous).unwrap_or_default(),
+            },
+        }
+    }
+
+    /// Compute the hull again on the same points, with different options
+    ///
+    /// The facets, ridges and vertices are freed (`qh_freebuild`), the points are kept
+    /// and the hull is recomputed with the options changed by `reconfigure`.
+    /// Only the options of [`QhRebuildOptions`] can be changed: merge thresholds,
+    /// triangulation and good facet selection, the others keep the value used for the first build.
+    ///
+    /// This method requires `&mut self`: the [`Facet`]s and [`Vertex`]s of the previous hull are not valid anymore.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a noisy sphere
+    /// let (dim, coords) = rbox::generate("500 s W0.01 D3").unwrap();
+    /// let mut qh = Qh::builder().no_premerge(true).build_managed(dim, coords).unwrap();
+    /// let facets = qh.num_facets();
+    /// assert!(qh.facets().all(|f| f.simplicial()));
+    ///
+    /// // merging facets with centrums closer than 0.05 removes the noise
+    /// qh.rebuild(|options| {
+    ///     options.premerge_centrum(0.05);
+    /// })
+    /// .unwrap();
+    /// assert!(qh.num_facets() < facets);
+    /// assert!(qh.facets().any(|f| !f.simplicial()));
+    ///
+    /// qh.rebuild(|options| {
+    ///     options.triangulate(true);
+    /// })
+    /// .unwrap();
+    /// assert!(qh.facets().all(|f| f.simplicial()));
+    /// ```
+    pub fn rebuild(&mut self, reconfigure: impl FnOnce(&mut QhRebuildOptions)) -> Result<(), QhError<'_>> {
+        let mut options = QhRebuildOptions::default();
+        reconfigure(&mut options);
+        let settings = self
+            .build_settings
+            .expect("the settings are captured when the instance is built");
+        self.computed = true;
+        self.point_vertices = None;
+        unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                sys::qh_freebuild(qh, true as _);
+                let qh = &mut *qh;
+                settings.restore(qh);
+                options.apply(qh);
+                // restart the count of builds, used by joggle (`QJ`) and `TRn`
+                qh.build_cnt = 0;
+                qh.QHULLfinished = false as _;
+                qh.hasTriangulation = false as _;
+                sys::qh_qhull(qh);
+                if qh.TRIangulate != 0 {
+                    sys::qh_triangulate(qh);
+                }
+            })
+        }
+    }
+
+    /// Replace the points of the instance and compute their hull
+    ///
+    /// This is faster than building a new instance for each point set:
+    /// the memory pools of qhull and the captured streams are reused.
+    /// The hull and the buffers of the previous points are freed, the instance takes ownership of `points`.
+    ///
+    /// The options given to the [`QhBuilder`] persist across resets, except:
+    /// * the arrays that qhull allocates for the points (thresholds `Pdk`/`PDk`, bounds, feasible point),
+    ///   which are reset to their defaults; the ranges of [`QhBuilder::scale_dimension`] are kept
+    /// * the builder settings that are not qhull options: the input is always [validated](QhBuilder::skip_validation)
+    ///   and the output is not checked, there are no [retries](QhBuilder::retry_on_precision_error)
+    ///
+    /// Options changed with [`Qh::rebuild`] are reverted to the ones given to the builder.
+    /// The statistics are reset.
+    /// The new points are not [deduplicated](QhBuilder::dedup_points).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder().build_from_flat(2, &[0.0, 0.0, 1.0, 0.0, 0.0, 1.0]).unwrap();
+    /// let memory = |qh: &Qh| unsafe { (*Qh::raw_ptr(qh)).qhmem.totlong };
+    /// let mut used = None;
+    ///
+    /// for i in 0..1000 {
+    ///     // the unit square, translated
+    ///     let (x, y) = (i as f64, -(i as f64));
+    ///     let points = vec![x, y, x + 1.0, y, x, y + 1.0, x + 1.0, y + 1.0, x + 0.5, y + 0.5];
+    ///     qh.reset_with_points(2, points).unwrap();
+    ///     assert_eq!(qh.num_points(), 5);
+    ///     assert_eq!(qh.num_facets(), 4);
+    ///
+    ///     // the previous hull is freed
+    ///     assert_eq!(*used.get_or_insert(memory(&qh)), memory(&qh));
+    /// }
+    ///
+    /// let Err(error) = qh.reset_with_points(2, vec![0.0, 0.0, 1.0, 1.0]) else {
+    ///     panic!("two points were accepted");
+    /// };
+    /// assert_eq!(error.kind, QhErrorKind::InvalidInput);
+    /// ```
+    ///
+    /// # Errors
+    /// Same as [`QhBuilder::build`], e.g. if the number of coordinates is not divisible by `dim`.
+    pub fn reset_with_points(&mut self, dim: usize, points: Vec<f64>) -> Result<(), QhError<'_>> {
+        builder::check_layout(dim, points.len())?;
+        let settings = self
+            .build_settings
+            .expect("the settings are captured when the instance is built");
+
+        let qh = self.qh.get_mut();
+        let hull_dim = builder::hull_dim(qh, dim);
+        if hull_dim < 2 {
+            return Err(QhError::unsupported_dimension(format!(
+                "qhull does not support hulls of dimension {}, the dimension must be at least 2",
+                hull_dim,
+            )));
+        }
+        if let Some(matrix) = &self.owned_values.input_rotation {
+            rotation::check_rotation(matrix, rotation::rotation_dim(qh, hull_dim))?;
+        }
+        if let Some(bound) = self.owned_values.scale_bounds.iter().find(|bound| bound.dim >= dim) {
+            return Err(QhError::invalid_input(format!(
+                "cannot scale coordinate {} of points of dimension {}",
+                bound.dim, dim,
+            )));
+        }
+        builder::validate_input(qh, dim, &points)?;
+
+        let num_points = points.len() / dim;
+        self.input_scaling = scaling::input_scaling(&self.owned_values.scale_bounds, dim, &points);
+        unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                sys::qh_freebuild(qh, true as _);
+                sys::qh_freebuffers(qh);
+                let qh = &mut *qh;
+                settings.restore(qh);
+                qh.build_cnt = 0;
+                qh.QHULLfinished = false as _;
+                qh.hasTriangulation = false as _;
+                sys::qh_initstatistics(qh);
+            })
+            .map_err(|e| e.into_static())?;
+        }
+
+        // qhull does not reference the previous points anymore
+        // SAFETY: qhull can modify the points in place (e.g. scaling or projecting them),
+        // the buffer is owned by the instance and dropped with it
+        let points_ptr = self.coords_holder.insert(points).as_mut_ptr();
+        self.point_map = None;
+        self.point_vertices = None;
+        unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                sys::qh_init_B(qh, points_ptr, num_points as _, dim as _, false as _);
+            })
+            .map_err(|e| e.into_static())?;
+            builder::finish_init(self)?;
+        }
+        self.dim = self.qh.get_mut().hull_dim as usize;
+        self.build_settings = Some(BuildSettings::capture(self.qh.get_mut()));
+
+        self.computed = false;
+        self.compute()
+    }
+
+    /// Triangulate the non-simplicial facets
+    ///
+    /// This wraps `qh_triangulate` and has the same effect as building with
+    /// [`triangulate`](QhBuilder::triangulate) (`Qt`), but can be done after the hull has been computed.
+    /// Calling this method on an already triangulated hull does nothing.
+    ///
+    /// # Remarks
+    /// * the facets are replaced by new ones, this is why this method requires `&mut self`:
+    ///   previously obtained [`Facet`]s would not be valid anymore
+    /// * the simplices obtained from the same facet are *tricoplanar*, they share the normal and the center
+    ///   of the original facet unless [`tri_normals`](QhBuilder::tri_normals) (`Q11`) was enabled
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///         [1.0, 0.0, 1.0],
+    ///         [0.0, 1.0, 1.0],
+    ///         [1.0, 1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(qh.num_facets(), 6);
+    /// assert_eq!(qh.simplices().count(), 0);
+    ///
+    /// qh.triangulate().unwrap();
+    /// assert_eq!(qh.num_facets(), 12);
+    /// assert_eq!(qh.simplices().count(), 12);
+    /// ```
+    pub fn triangulate(&mut self) -> Result<(), QhError<'_>> {
+        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_triangulate(qh)) }
+    }
+
+    /// Create the ridges of the simplicial facets
+    ///
+    /// This wraps `qh_makeridges` for each facet, so that [`Qh::ridges`] and [`Facet::ridges`]
+    /// return every ridge of the hull, not only the ones between non-simplicial facets.
+    ///
+    /// # Remarks
+    /// * `qh_makeridges` marks the facets as not simplicial, the flag is restored afterwards:
+    ///   [`Facet::simplicial`], [`Qh::simplices`] and the methods based on it
+    ///   (like [`Qh::simplex_indices`]) are not affected
+    /// * this method requires `&mut self` because the facets are modified
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.ridges().count(), 0);
+    ///
+    /// qh.make_ridges().unwrap();
+    /// assert_eq!(qh.ridges().count(), 6);
+    /// for ridge in qh.ridges() {
+    ///     assert_eq!(ridge.vertices().unwrap().iter().count(), 2);
+    /// }
+    ///
+    /// // the facets are still simplicial
+    /// assert!(qh.facets().all(|f| f.simplicial()));
+    /// assert_eq!(qh.simplex_indices().len(), 4);
+    /// qh.make_ridges().unwrap();
+    /// assert_eq!(qh.ridges().count(), 6);
+    /// ```
+    pub fn make_ridges(&mut self) -> Result<(), QhError<'_>> {
+        let facets: Vec<_> = self.facets().map(|f| unsafe { f.raw_ptr() }).collect();
+        unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                for facet in facets {
+                    let simplicial = (*facet).simplicial();
+                    sys::qh_makeridges(qh, facet);
+                    // the ridges do not change the facet, only how qhull represents it
+                    (*facet).set_simplicial(simplicial);
+                }
+            })
+        }
+    }
+
+    /// Build the vertex-to-facet adjacency
+    ///
+    /// Qhull does not maintain the neighbors of the vertices unless they are needed
+    /// (e.g. for merging or Voronoi output), this method wraps `qh_vertexneighbors`
+    /// to compute them.
+    /// The result is then available through [`Vertex::neighbor_facets`].
+    ///
+    /// Calling this method multiple times is cheap, qhull will not recompute the neighbors
+    /// once they have been built.
+    pub fn compute_vertex_neighbors(&mut self) -> Result<(), QhError<'_>> {
+        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_vertexneighbors(qh)) }
+    }
+
+    /// Whether the instance should be discarded after a fatal error
+    ///
+    /// Qhull exits the process when it fails while handling a previous error.
+    /// Instead, the call returns [`QhErrorKind::ErrorDuringErrorHandling`] and the instance is poisoned:
+    /// its state may be inconsistent, so all the following calls fail with the same error kind.
+    /// Dropping a poisoned instance is safe, but the memory of the hull is not freed.
+    ///
+    /// Ordinary qhull errors do not poison the instance.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .compute(false)
+    ///     .skip_validation(true)
+    ///     .build_from_iter([[1.0, 1.0]; 4])
+    ///     .unwrap();
+    ///
+    /// // a failure does not poison the instance
+    /// assert!(qh.compute().is_err());
+    /// assert!(!qh.is_poisoned());
+    ///
+    /// // simulate an error while qhull is reporting another one
+    /// let error = unsafe {
+    ///     Qh::try_on_qh_mut(&mut qh, |qh| {
+    ///         (*qh).ERREXITcalled = true as _;
+    ///         sys::qh_errexit(qh, sys::qh_ERRqhull as _, std::ptr::null_mut(), std::ptr::null_mut());
+    ///     })
+    /// }.unwrap_err();
+    /// assert_eq!(error.kind, QhErrorKind::ErrorDuringErrorHandling);
+    /// assert!(qh.is_poisoned());
+    /// assert_eq!(qh.compute().unwrap_err().kind, QhErrorKind::ErrorDuringErrorHandling);
+    /// ```
+    pub fn is_poisoned(&self) -> bool {
+        unsafe { (*self.qh.get()).ERREXITcalled != 0 }
+    }
+
+    /// The joggle applied to the input
+    ///
+    /// Returns the maximum perturbation of the coordinates if the input was joggled
+    /// (see [`QhBuilder::joggle`] and [`QhBuilder::retry_on_precision_error`]), `None` otherwise.
+    /// Qhull may increase the joggle while computing the hull (`qh_joggleinput`),
+    /// this is the value of the last attempt (`qh.JOGGLEmax`), see [`Qh::build_count`] for the number of attempts.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("100 D3 t1").unwrap();
+    /// let qh = Qh::builder().joggle(None).random_seed(1).build_managed(dim, coords.clone()).unwrap();
+    /// assert_eq!(qh.joggle_max(), Some(0.0));
+    /// assert!(qh.joggle_applied().unwrap() > 0.0);
+    /// assert!(qh.build_count() >= 1);
+    ///
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// assert_eq!(qh.joggle_max(), None);
+    /// assert_eq!(qh.joggle_applied(), None);
+    /// assert_eq!(qh.build_count(), 0);
+    /// ```
+    pub fn joggle_applied(&self) -> Option<f64> {
+        let joggle = unsafe { (*self.qh.get()).JOGGLEmax };
+        (joggle < sys::REALmax / 2.0).then_some(joggle)
+    }
+
+    /// The joggle applied to the input
+    #[deprecated(note = "renamed to `joggle_applied`")]
+    pub fn joggle_used(&self) -> Option<f64> {
+        self.joggle_applied()
+    }
+
+    /// The joggle requested for the input (`QJn`)
+    ///
+    /// This is the value given to [`QhBuilder::joggle`], or chosen by [`QhBuilder::retry_on_precision_error`]
+    /// or [`QhBuilder::allow_degenerate`] for the last build, before qhull adjusts it.
+    /// It is `Some(0.0)` if qhull chooses the joggle from the input (`QJ` without a value)
+    /// and `None` if the input is not joggled.
+    /// See [`Qh::joggle_applied`] for the joggle that was actually used.
+    pub fn joggle_max(&self) -> Option<f64> {
+        let joggle = self.build_settings.map_or(sys::REALmax, |settings| settings.joggle_max());
+        (joggle < sys::REALmax / 2.0).then_some(joggle)
+    }
+
+    /// Number of times qhull built the hull (`qh.build_cnt`)
+    ///
+    /// Qhull counts the builds only with a joggled input (`QJ`, see [`Qh::joggle_applied`])
+    /// or when it reruns the construction ([`rerun`](QhBuilder::rerun), `TRn`), this is `0` otherwise.
+    /// With joggle, more than `1` build means that qhull restarted the construction
+    /// after a precision error, increasing the joggle.
+    /// The builds done by [`QhBuilder::retry_on_precision_error`] use new instances and are not counted.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("100 D3").unwrap();
+    /// let qh = Qh::builder().rerun(3).build_managed(dim, coords).unwrap();
+    /// assert_eq!(qh.build_count(), 3);
+    /// ```
+    pub fn build_count(&self) -> usize {
+        unsafe { (*self.qh.get()).build_cnt.max(0) as usize }
+    }
+
+    /// The distance of an approximate hull
+    ///
+    /// Returns the minimum distance of a point above a facet for the point to be added to the hull
+    /// (`qh.MINoutside`) if the hull is approximate (see [`QhBuilder::approximate`]), `None` otherwise.
+    pub fn approximation_epsilon(&self) -> Option<f64> {
+        let qh = unsafe { &*self.qh.get() };
+        (qh.APPROXhull != 0).then_some(qh.MINoutside)
+    }
+
+    /// Maximum distance of a point above a facet (`qh.max_outside`)
+    ///
+    /// When facets are merged, the facet hyperplanes are only approximations of the hull:
+    /// each point is below the outer plane of its facet,
+    /// that is the facet hyperplane moved outward by `max_outside` plus [roundoff](Qh::dist_round).
+    /// The true convex hull lies between the inner and the outer planes.
+    /// After [`Qh::compute`], qhull has already checked every point (`qh_check_maxout`)
+    /// unless [`skip_check_max`](QhBuilder::skip_check_max) (`Q5`) is set or the facets are not merged.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // points near the surface of a cube
+    /// let (dim, coords) = rbox::generate("500 W1e-10 D3 t1").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// assert!(qh.max_outside() > 0.0);
+    /// assert!(qh.max_outside() < 1e-12);
+    /// assert!(qh.min_vertex() <= 0.0 && qh.min_vertex() > -1e-12);
+    /// assert!(qh.dist_round() > 0.0 && qh.dist_round() < 1e-12);
+    /// ```
+    pub fn max_outside(&self) -> f64 {
+        unsafe { (*self.qh.get()).max_outside }
+    }
+
+    /// Maximum distance of a vertex below a facet (`qh.min_vertex`), zero or negative
+    ///
+    /// The inner plane of a facet is the facet hyperplane moved inward by `min_vertex` minus [roundoff](Qh::dist_round):
+    /// every vertex is above the inner planes of its facets.
+    /// See [`Qh::max_outside`].
+    pub fn min_vertex(&self) -> f64 {
+        unsafe { (*self.qh.get()).min_vertex }
+    }
+
+    /// Maximum roundoff error of a distance computation (`qh.DISTround`)
+    ///
+    /// Computed by qhull from the dimension and the maximum coordinates of the input,
+    /// unless it is set with the `En` option ([`set_roundoff`](QhBuilder::set_roundoff) and [`dist_round`](QhBuilder::dist_round)).
+    pub fn dist_round(&self) -> f64 {
+        unsafe { (*self.qh.get()).DISTround }
+    }
+
+    /// Feasible point of a halfspace intersection (`qh.feasible_point`)
+    ///
+    /// This is the point given with [`QhBuilder::feasible_point`] or parsed from [`QhBuilder::feasible_string`],
+    /// with one coordinate for each dimension of the [hull](Qh::hull_dim).
+    /// The dual points of the halfspaces are relative to it,
+    /// it is needed to transform the facets back to the intersection points.
+    /// Returns `None` if there is no feasible point.
+    pub fn feasible_point(&self) -> Option<&[f64]> {
+        let point = unsafe { (*self.qh.get()).feasible_point };
+        (!point.is_null()).then(|| unsafe { std::slice::from_raw_parts(point, self.hull_dim()) })
+    }
+
+    /// Rotation applied to the input points
+    ///
+    /// The rows of the orthonormal matrix `R` such that each point `p` given to qhull was replaced by `R * p`,
+    /// with [`QhBuilder::rotate_input`] and/or [`QhBuilder::rotate_random`] (`QRn`).
+    /// The matrix has the dimension of the [hull](Qh::hull_dim): for a Delaunay triangulation,
+    /// the last row and column leave the lifted coordinate unchanged.
+    ///
+    /// The [points](Qh::points), the normals and the other coordinates of the output are rotated,
+    /// multiply them by the transpose of `R` to get back to the input coordinates.
+    /// Returns `None` if the input was not rotated.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [2.0, 0.0], [0.0, 1.0], [0.5, 0.25]];
+    /// let qh = Qh::builder()
+    ///     .rotate_input(vec![vec![0.0, -1.0], vec![1.0, 0.0]])
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    ///
+    /// let rotation = qh.input_rotation().unwrap();
+    /// for (rotated, original) in qh.points().zip(points) {
+    ///     // multiply by the transpose
+    ///     let x = rotation[0][0] * rotated[0] + rotation[1][0] * rotated[1];
+    ///     let y = rotation[0][1] * rotated[0] + rotation[1][1] * rotated[1];
+    ///     assert_eq!([x, y], original);
+    /// }
+    /// ```
+    pub fn input_rotation(&self) -> Option<&[Vec<f64>]> {
+        self.input_rotation.as_deref()
+    }
+
+    /// Coordinates of the input ignored by qhull, see [`QhBuilder::drop_dimensions`]
+    ///
+    /// The dimensions are sorted, the [points](Qh::points) of the instance have the other coordinates.
+    pub fn dropped_dimensions(&self) -> Vec<usize> {
+        let mut dims: Vec<usize> = self
+            .owned_values
+            .scale_bounds
+            .iter()
+            .filter(|bound| bound.is_drop())
+            .map(|bound| bound.dim)
+            .collect();
+        dims.sort();
+        dims
+    }
+
+    /// Scaling applied to each coordinate of the input
+    ///
+    /// One map for each dimension of the [hull](Qh::hull_dim), from the coordinates given to qhull
+    /// to the coordinates of the [points](Qh::points), the normals and the other results:
+    /// * the coordinates set with [`QhBuilder::scale_dimension`] (`Qbk:low QBk:high`)
+    /// * the last coordinate with [`QhBuilder::scale_last_coordinate`] (`Qbb`),
+    ///   for a Delaunay triangulation this is the lifted coordinate, the sum of the squares of the others.
+    ///   It is only known after the hull is [computed](Qh::compute).
+    ///
+    /// The other coordinates are not scaled ([`AxisScaling::IDENTITY`]).
+    /// If the input is also [rotated](Qh::input_rotation), the rotation is applied
+    /// after the scaling of [`QhBuilder::scale_dimension`] and before the scaling of the last coordinate.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .scale_dimension(1, -1.0, 1.0)
+    ///     .build_from_iter([[0.0, 0.0], [4.0, 0.0], [0.0, 4.0], [1.0, 1.0]])
+    ///     .unwrap();
+    ///
+    /// let y = qh.scaling()[1];
+    /// assert_eq!((y.scale, y.offset), (0.5, -1.0));
+    /// assert_eq!(qh.point(2).unwrap(), [0.0, 1.0]);
+    /// assert_eq!(y.invert(qh.point(2).unwrap()[1]), 4.0);
+    /// assert_eq!(qh.scaling()[0], AxisScaling::IDENTITY);
+    /// ```
+    pub fn scaling(&self) -> Vec<AxisScaling> {
+        let mut scaling = vec![AxisScaling::IDENTITY; self.hull_dim()];
+        for (axis, input) in scaling.iter_mut().zip(&self.input_scaling) {
+            *axis = *input;
+        }
+        if let (Some(axis), Some(last)) = (scaling.last_mut(), self.last_scaling()) {
+            *axis = axis.then(&last);
+        }
+        scaling
+    }
+
+    /// Scaling of the last coordinate by [`QhBuilder::scale_last_coordinate`] (`Qbb`), if applied
+    fn last_scaling(&self) -> Option<AxisScaling> {
+        // `qh_scalelast` records the range of the last coordinate
+        let qh = unsafe { &*self.qh.get() };
+        (qh.last_low < sys::REALmax / 2.0).then(|| {
+            let scale = qh.last_newhigh / (qh.last_high - qh.last_low);
+            AxisScaling { scale, offset: -qh.last_low * scale }
+        })
+    }
+
+    /// Creates a new Delaunay triangulation
+    ///
+    /// The points are given in their original dimension,
+    /// qhull lifts them to a paraboloid as the `qdelaunay` program does (`d Qbb Qc Qt`).
+    ///
+    /// See the `examples` directory for an example.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // an anisotropic point set: normalizing the axes independently
+    /// // would flip the diagonal of this quadrilateral
+    /// let qh = Qh::new_delaunay([
+    ///     [0.0, 0.0],
+    ///     [2.0, 0.0],
+    ///     [1.0, 2.0],
+    ///     [1.0, -1.5],
+    /// ]).unwrap();
+    ///
+    /// let mut simplices = qh.simplex_indices();
+    /// simplices.iter_mut().for_each(|s| s.sort());
+    /// simplices.sort();
+    /// // same as `qdelaunay i`
+    /// assert_eq!(simplices, vec![vec![0, 1, 2], vec![0, 1, 3]]);
+    /// ```
+    pub fn new_delaunay<I>(points: impl IntoIterator<Item = I>) -> Result<Self, QhError<'static>>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let CollectedCoords {
+            coords,
+            count: _,
+            dim,
+        } = try_collect_coords(points)?;
+
+        QhBuilder::default()
+            .delaunay(true)
+            .project_delaunay(true)
+            .scale_last(true)
+            .triangulate(true)
+            .keep_coplanar(true)
+            .build_managed(dim, coords)
+    }
+
+    /// Get all the facets in the hull
+    ///
+    /// # Remarks
+    /// * this function will also return the sentinel face, which is the last face in the list of facets.
+    ///   To avoid it, use the [`Qh::facets`] function or just [`filter`](std::iter::Iterator::filter) the iterator
+    ///   checking for [`Facet::is_sentinel`].
+    /// * the iterator is empty before the hull is [computed](Qh::is_computed), qhull has not allocated
+    ///   the lists yet (not even the sentinel).
+    ///   The same holds for [`Qh::all_facets_rev`], [`Qh::all_vertices`], [`Qh::all_vertices_rev`]
+    ///   and for the iterators built on them ([`Qh::facets`], [`Qh::vertices`], [`Qh::simplices`]).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .compute(false)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.all_facets().count(), 0);
+    /// assert_eq!(qh.all_facets_rev().count(), 0);
+    /// assert_eq!(qh.all_vertices().count(), 0);
+    /// assert_eq!(qh.all_vertices_rev().count(), 0);
+    /// assert_eq!(qh.facets().count(), 0);
+    /// assert_eq!(qh.vertices().count(), 0);
+    /// assert_eq!(qh.simplices().count(), 0);
+    /// assert_eq!(qh.num_facets(), 0);
+    /// assert_eq!(qh.num_vertices(), 0);
+    /// assert!(qh.for_each_facet(|_| std::ops::ControlFlow::Break(())).is_continue());
+    /// ```
+    pub fn all_facets(&self) -> impl Iterator<Item = Facet<'_>> {
+        let mut current = Facet::from_ptr(
+            self.list_head(|qh| unsafe { sys::qh_get_facet_list(qh) }),
+            self.dim,
+        );
+
+        std::iter::from_fn(move || current.take().inspect(|v| {
+            current = v.next();
+        }))
+    }
+
+    /// Get all the facets in the hull in reverse order
+    ///
+    /// See [`Qh::all_facets`] for more information.
+    pub fn all_facets_rev(&self) -> impl Iterator<Item = Facet<'_>> {
+        let mut current = Facet::from_ptr(
+            self.list_head(|qh| unsafe { sys::qh_get_facet_tail(qh) }),
+            self.dim,
+        );
+
+        std::iter::from_fn(move || current.take().inspect(|v| {
+            current = v.previous();
+        }))
+    }
+
+    /// Get the facets in the hull
+    ///
+    /// # Remarks
+    /// * this function will not return the sentinel face, which is the last face in the list of facets.
+    ///   To get it, use the [`Qh::all_facets`] function.
+    /// * there are no facets before the hull is [computed](Qh::is_computed).
+    pub fn facets(&self) -> impl Iterator<Item = Facet<'_>> {
+        self.all_facets().filter(|f| !f.is_sentinel())
+    }
+
+    /// Visit the facets of the hull
+    ///
+    /// Same facets as [`Qh::facets`], walking the list of qhull without an iterator.
+    /// The visit stops when `f` returns [`ControlFlow::Break`], which is then returned.
+    ///
+    /// The closure receives a reference that is only valid for the call, it must not retain it
+    /// (copy the [`Facet`] instead).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::ops::ControlFlow;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]])
+    ///     .unwrap();
+    ///
+    /// let mut count = 0;
+    /// let flow = qh.for_each_facet(|_| {
+    ///     count += 1;
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert_eq!((count, flow), (4, ControlFlow::Continue(())));
+    ///
+    /// // stop at the first facet with vertex 3
+    /// let mut visited = 0;
+    /// let flow = qh.for_each_facet(|facet| {
+    ///     visited += 1;
+    ///     let mut found = false;
+    ///     facet.for_each_vertex(|v| found |= v.index(&qh) == Some(3));
+    ///     if found { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    /// });
+    /// assert!(flow.is_break() && visited < 4);
+    /// ```
+    pub fn for_each_facet(&self, mut f: impl FnMut(&Facet<'_>) -> ControlFlow<()>) -> ControlFlow<()> {
+        if !self.computed {
+            return ControlFlow::Continue(());
+        }
+        let mut facet = unsafe { sys::qh_get_facet_list(self.qh.get()) };
+        while let Some(current) = Facet::from_ptr(facet, self.dim) {
+            if !current.is_sentinel() {
+                f(&current)?;
+            }
+            facet = unsafe { (*facet).next };
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Visit the facets reachable from `start` through their neighbors, in breadth-first order
+    ///
+    /// `visit` is called once for each facet with its depth, the number of steps from `start` (which has depth `0`).
+    /// The neighbors of a facet are only explored if `visit` returns `true` for it,
+    /// so returning `false` marks the border of a region.
+    ///
+    /// The visited facets are tracked by id in a set owned by the traversal:
+    /// unlike qhull's own traversals, the `visitid` of the facets (see [`Facet::visit_id`]) is not modified.
+    ///
+    /// # Example
+    /// Segment a triangulated cube into its 6 faces, growing regions of facets with the same normal:
+    /// ```
+    /// # use qhull::*;
+    /// # use std::collections::HashMap;
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let mut qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// qh.triangulate().unwrap();
+    /// assert_eq!(qh.num_facets(), 12);
+    ///
+    /// let mut region: HashMap<u32, usize> = HashMap::new();
+    /// let mut num_regions = 0;
+    /// for seed in qh.facets() {
+    ///     if region.contains_key(&seed.id()) {
+    ///         continue;
+    ///     }
+    ///     let normal = seed.normal().unwrap();
+    ///     qh.facet_bfs(&seed, |facet, _depth| {
+    ///         let dot: f64 = facet.normal().unwrap().iter().zip(normal).map(|(a, b)| a * b).sum();
+    ///         let same_plane = dot > 1.0 - 1e-9;
+    ///         if same_plane {
+    ///             region.insert(facet.id(), num_regions);
+    ///         }
+    ///         same_plane
+    ///     });
+    ///     num_regions += 1;
+    /// }
+    ///
+    /// // two triangles for each face of the cube
+    /// assert_eq!(num_regions, 6);
+    /// for r in 0..num_regions {
+    ///     assert_eq!(region.values().filter(|&&v| v == r).count(), 2);
+    /// }
+    ///
+    /// // the neighbors of a facet are at depth 1
+    /// let start = qh.facets().next().unwrap();
+    /// let mut depths = Vec::new();
+    /// qh.facet_bfs(&start, |_, depth| {
+    ///     depths.push(depth);
+    ///     true
+    /// });
+    /// assert_eq!(depths.len(), 12);
+    /// assert_eq!(depths[..4], [0, 1, 1, 1]);
+    /// assert!(depths.windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    pub fn facet_bfs<'f>(&'f self, start: &Facet<'f>, mut visit: impl FnMut(&Facet<'f>, u32) -> bool) {
+        let mut visited = HashSet::from([start.id()]);
+        let mut queue = VecDeque::from([(*start, 0)]);
+        while let Some((facet, depth)) = queue.pop_front() {
+            if !visit(&facet, depth) {
+                continue;
+            }
+            for neighbor in facet.neighbors().iter().flat_map(|neighbors| neighbors.iter()) {
+                if visited.insert(neighbor.id()) {
+                    queue.push_back((neighbor, depth + 1));
+                }
+            }
+        }
+    }
+
+    /// Get the ridges of the hull, each one once
+    ///
+    /// A ridge is shared by two facets, it appears in the [ridges](Facet::ridges) of both:
+    /// this iterator skips the ridges that were already returned.
+    ///
+    /// # Remarks
+    /// * qhull only creates the ridges that it needs: the non-simplicial facets (e.g. after merging)
+    ///   have ridges, the simplicial ones do not, their neighbors are derived from their vertex sets.
+    ///   Use [`Qh::make_ridges`] to create the missing ridges.
+    /// * there are no ridges before the hull is [computed](Qh::is_computed).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::collections::HashSet;
+    /// // the triangles of each face of the cube are merged
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// assert_eq!(qh.num_facets(), 6);
+    ///
+    /// let ids: Vec<_> = qh.ridges().map(|r| r.id()).collect();
+    /// assert_eq!(ids.len(), 12);
+    /// assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 12);
+    ///
+    /// // every ridge is in the ridges of its two facets
+    /// let in_facets: usize = qh.facets().map(|f| f.ridges().unwrap().iter().count()).sum();
+    /// assert_eq!(in_facets, 2 * 12);
+    /// ```
+    pub fn ridges(&self) -> impl Iterator<Item = Ridge<'_>> {
+        let mut returned = HashSet::new();
+        self.facets()
+            .flat_map(|facet| facet.ridges().into_iter().flatten())
+            .filter(move |ridge| returned.insert(ridge.id()))
+    }
+
+    /// Get all the vertices in the hull
+    ///
+    /// Like [`Qh::all_facets`], the sentinel vertex is included and the iterator is empty
+    /// before the hull is [computed](Qh::is_computed).
+    pub fn all_vertices(&self) -> impl Iterator<Item = Vertex<'_>> {
+        let mut current = Vertex::from_ptr(
+            self.list_head(|qh| unsafe { sys::qh_get_vertex_list(qh) }),
+            self.dim,
+        );
+
+        std::iter::from_fn(move || current.take().inspect(|v| {
+            current = v.next();
+        }))
+    }
+
+    /// Get all the vertices in the hull in reverse order
+    ///
+    /// See [`Qh::all_vertices`] for more information.
+    pub fn all_vertices_rev(&self) -> impl Iterator<Item = Vertex<'_>> {
+        let mut current = Vertex::from_ptr(
+            self.list_head(|qh| unsafe { sys::qh_get_vertex_tail(qh) }),
+            self.dim,
+        );
+
+        std::iter::from_fn(move || current.take().inspect(|v| {
+            current = v.previous();
+        }))
+    }
+
+    /// Get the vertices in the hull
+    ///
+    /// The sentinel vertex is not returned, and there are no vertices before the hull is [computed](Qh::is_computed).
+    pub fn vertices(&self) -> impl Iterator<Item = Vertex<'_>> {
+        self.all_vertices().filter(|v| !v.is_sentinel())
+    }
+
+    /// Number of facets in the hull (sentinel excluded)
+    ///
+    /// This is `0` before the hull is [computed](Qh::is_computed).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # let mut qh = Qh::builder()
+    /// #     .build_from_iter([
+    /// #         [0.0, 0.0],
+    /// #         [1.0, 0.0],
+    /// #         [0.0, 1.0],
+    /// #         [0.25, 0.25]
+    /// #    ]).unwrap();
+    /// assert_eq!(qh.num_facets(), qh.facets().count());
+    /// ```
+    pub fn num_facets(&self) -> usize {
+        if !self.computed {
+            return 0;
+        }
+        unsafe { sys::qh_get_num_facets(self.qh.get()) as _ }
+    }
+
+    /// Number of vertices in the hull (sentinel excluded)
+    ///
+    /// This is `0` before the hull is [computed](Qh::is_computed).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # let mut qh = Qh::builder()
+    /// #     .build_from_iter([
+    /// #         [0.0, 0.0],
+    /// #         [1.0, 0.0],
+    /// #         [0.0, 1.0],
+    /// #         [0.25, 0.25]
+    /// #    ]).unwrap();
+    /// assert_eq!(qh.num_vertices(), qh.vertices().count());
+    /// ```
+    pub fn num_vertices(&self) -> usize {
+        if !self.computed {
+            return 0;
+        }
+        unsafe { sys::qh_get_num_vertices(self.qh.get()) as _ }
+    }
+
+    /// Number of input points
+    ///
+    /// For a Delaunay triangulation with [`at_infinity`](QhBuilder::at_infinity),
+    /// the point at infinity added by qhull is not counted.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # let qh = Qh::builder()
+    /// #     .build_from_iter([
+    /// #         [0.0, 0.0],
+    /// #         [1.0, 0.0],
+    /// #         [0.0, 1.0],
+    /// #         [0.25, 0.25]
+    /// #    ]).unwrap();
+    /// assert_eq!(qh.num_points(), 4);
+    /// ```
+    pub fn num_points(&self) -> usize {
+        let qh = unsafe { &*self.qh.get() };
+        let num_points = qh.num_points as usize;
+        if qh.DELAUNAY != 0 && qh.ATinfinity != 0 {
+            num_points - 1
+        } else {
+            num_points
+        }
+    }
+
+    /// Index in the input of a point of the instance
+    ///
+    /// With [`QhBuilder::dedup_points`], the instance only has the first occurrence of each input point:
+    /// this is the index of that occurrence in the input.
+    /// Otherwise, this is the same index.
+    /// Returns `None` if the index is out of bounds.
+    pub fn original_index(&self, index: usize) -> Option<usize> {
+        match &self.point_map {
+            Some(map) => map.original_index(index),
+            None => (index < self.num_points()).then_some(index),
+        }
+    }
+
+    /// Index in the instance of an input point
+    ///
+    /// The opposite of [`Qh::original_index`]:
+    /// duplicated input points all have the index of their first occurrence.
+    /// Returns `None` if the index is out of bounds.
+    pub fn deduped_index(&self, original: usize) -> Option<usize> {
+        match &self.point_map {
+            Some(map) => map.deduped_index(original),
+            None => (original < self.num_points()).then_some(original),
+        }
+    }
+
+    /// The hull vertex of each point
+    ///
+    /// The result is indexed as [`Qh::points`]: the entry is `None` for the points
+    /// that are not vertices of the hull (e.g. interior or coplanar points).
+    /// This wraps `qh_pointvertex`, which is faster than calling [`Vertex::index`] on every vertex
+    /// when many points are looked up.
+    ///
+    /// The map is cached until the facets or the vertices of the hull change
+    /// (e.g. after [`Qh::triangulate`] or [`Qh::rebuild`]), see also [`Qh::vertex_for_point`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.25, 0.5]];
+    /// let mut qh = Qh::builder().build_from_iter(points).unwrap();
+    ///
+    /// let map = qh.point_vertex_map().unwrap();
+    /// assert_eq!(map.len(), 5);
+    /// assert!(map[4].is_none());
+    /// for (vertex, point) in map.iter().zip(&points).take(4) {
+    ///     assert_eq!(vertex.unwrap().point().unwrap(), point);
+    /// }
+    /// ```
+    pub fn point_vertex_map(&mut self) -> Result<Vec<Option<Vertex<'_>>>, QhError<'_>> {
+        self.update_point_vertices()?;
+        let dim = self.dim;
+        let cache = self.point_vertices.as_ref().expect("the map has just been updated");
+        Ok(cache.vertices.iter().map(|&vertex| Vertex::from_ptr(vertex, dim)).collect())
+    }
+
+    /// The hull vertex of a point
+    ///
+    /// Returns `None` if the point is not a vertex of the hull or if the index is out of bounds.
+    /// This uses the map of [`Qh::point_vertex_map`], the first call builds it.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    ///
+    /// let vertex = qh.vertex_for_point(1).unwrap().unwrap();
+    /// assert_eq!(vertex.point(), Some(&[1.0, 0.0][..]));
+    /// assert!(qh.vertex_for_point(3).unwrap().is_none());
+    /// assert!(qh.vertex_for_point(4).unwrap().is_none());
+    /// ```
+    pub fn vertex_for_point(&mut self, index: usize) -> Result<Option<Vertex<'_>>, QhError<'_>> {
+        self.update_point_vertices()?;
+        let dim = self.dim;
+        let cache = self.point_vertices.as_ref().expect("the map has just been updated");
+        Ok(cache.vertices.get(index).and_then(|&vertex| Vertex::from_ptr(vertex, dim)))
+    }
+
+    /// Build the map of [`Qh::point_vertex_map`] if the hull changed
+    fn update_point_vertices(&mut self) -> Result<(), QhError<'static>> {
+        let state = PointVertices::state(self.qh.get_mut());
+        if self.point_vertices.as_ref().is_some_and(|cache| cache.state == state) {
+            return Ok(());
+        }
+        let num_points = self.num_points();
+        let vertices = unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                let mut set = sys::qh_pointvertex(qh);
+                // the set has an element for each point id, `qh_setzero` sets its size
+                let elements = std::ptr::addr_of!((*set).e) as *const sys::setelemT;
+                let vertices = (0..num_points).map(|i| (*elements.add(i)).p as *mut sys::vertexT).collect();
+                sys::qh_settempfree(qh, &mut set);
+                vertices
+            })
+            .map_err(|e| e.into_static())?
+        };
+        self.point_vertices = Some(PointVertices { state, vertices });
+        Ok(())
+    }
+
+    /// Dimension of the points
+    ///
+    /// This is the dimension of the points returned by [`Qh::points`].
+    ///
+    /// | mode      | [`dim`](Qh::dim) | [`hull_dim`](Qh::hull_dim) | [`input_dim`](Qh::input_dim) |
+    /// |-----------|------------------|----------------------------|------------------------------|
+    /// | hull      | `d`              | `d`                        | `d`                          |
+    /// | Delaunay  | `d`              | `d + 1` (lifted points)    | `d`                          |
+    /// | halfspace | `d`              | `d`                        | `d + 1` (normal and offset)  |
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]];
+    ///
+    /// let qh = Qh::builder().build_from_iter(points).unwrap();
+    /// assert_eq!((qh.dim(), qh.hull_dim(), qh.input_dim()), (2, 2, 2));
+    ///
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    /// assert_eq!((qh.dim(), qh.hull_dim(), qh.input_dim()), (2, 3, 2));
+    /// ```
+    pub fn dim(&self) -> usize {
+        if unsafe { (*self.qh.get()).DELAUNAY != 0 } {
+            self.dim - 1
+        } else {
+            self.dim
+        }
+    }
+
+    /// Dimension of the hull
+    ///
+    /// This is the dimension of the space where qhull computes the hull,
+    /// i.e. the length of [`Facet::normal`] and [`Vertex::point`].  
+    /// See [`Qh::dim`] for how it relates to the other dimensions.
+    pub fn hull_dim(&self) -> usize {
+        unsafe { sys::qh_get_hull_dim(self.qh.get()) as _ }
+    }
+
+    /// Dimension of the input data
+    ///
+    /// This is the dimension of the data given to qhull,
+    /// before any projection (e.g. Delaunay lifting or halfspace duality).  
+    /// See [`Qh::dim`] for how it relates to the other dimensions.
+    pub fn input_dim(&self) -> usize {
+        unsafe { (*self.qh.get()).input_dim as _ }
+    }
+
+    /// Get an input point by index
+    ///
+    /// Returns `None` if the index is out of bounds.  
+    /// See [`Qh::points`] for more information.
+    pub fn point(&self, index: usize) -> Option<&[f64]> {
+        if index >= self.num_points() {
+            return None;
+        }
+        let first_point = unsafe { (*self.qh.get()).first_point as *const f64 };
+        let point = unsafe { std::slice::from_raw_parts(first_point.add(index * self.dim), self.dim) };
+        Some(&point[..self.dim()])
+    }
+
+    /// Iterate over the input points
+    ///
+    /// The points are read from qhull's point array, so they are available
+    /// even if the original data is not at hand.
+    ///
+    /// # Remarks
+    /// * for a Delaunay triangulation, qhull stores the points lifted to the paraboloid;
+    ///   only the original coordinates are returned, without the lifted one.
+    ///   Use [`Vertex::point`] to get the lifted coordinates of the vertices.
+    /// * qhull might have modified the points in place, for example if they were scaled or rotated
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [0.25, 0.25],
+    /// ];
+    ///
+    /// let qh = Qh::builder().build_from_iter(points).unwrap();
+    /// assert!(qh.points().eq(points.iter().map(|p| &p[..])));
+    /// assert_eq!(qh.point(3), Some(&[0.25, 0.25][..]));
+    /// assert_eq!(qh.point(4), None);
+    ///
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    /// assert!(qh.points().eq(points.iter().map(|p| &p[..])));
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = &[f64]> {
+        (0..self.num_points()).map(|i| self.point(i).unwrap())
+    }
+
+    /// Convert a query point to the space of the hull
+    ///
+    /// Checks that the hull is [computed](Qh::is_computed) and the dimension of the point and,
+    /// for Delaunay triangulations, lifts it to the paraboloid as qhull did with the input points.
+    /// The point is scaled and rotated as the input (see [`Qh::scaling`] and [`Qh::input_rotation`]),
+    /// it has the [dimensions](QhBuilder::drop_dimensions) that are not dropped.
+    fn query_point(&self, point: &[f64]) -> Result<Vec<f64>, QhError<'static>> {
+        if !self.computed {
+            return Err(QhError::not_computed());
+        }
+        if point.len() != self.dim() {
+            return Err(QhError::invalid_input(format!(
+                "query point has dimension {}, expected {}",
+                point.len(),
+                self.dim(),
+            )));
+        }
+        let mut coords = point.to_vec();
+        let delaunay = unsafe { (*self.qh.get()).DELAUNAY != 0 };
+        if delaunay {
+            // also scales the lifted coordinate as `qh_scalelast`
+            coords.push(0.0);
+            unsafe { sys::qh_setdelaunay(self.qh.get(), self.dim as _, 1, coords.as_mut_ptr()) };
+        }
+        // the lifted coordinate is computed from the input coordinates before they are scaled
+        for (x, scaling) in coords.iter_mut().zip(&self.input_scaling) {
+            *x = scaling.apply(*x);
+        }
+        if let Some(rotation) = &self.input_rotation {
+            coords = rotation
+                .iter()
+                .map(|row| row.iter().zip(&coords).map(|(a, b)| a * b).sum())
+                .collect();
+        }
+        // `qh_initbuild` scales the last coordinate of the rotated points
+        let last_scaling = self.last_scaling().filter(|_| !delaunay);
+        if let (Some(last), Some(x)) = (last_scaling, coords.last_mut()) {
+            *x = last.apply(*x);
+        }
+        Ok(coords)
+    }
+
+    /// Find the hull vertex nearest to a point
+    ///
+    /// Qhull first locates the facet nearest to the point (`qh_findbestfacet`)
+    /// and then its nearest vertex (`qh_nearvertex`).  
+    /// Returns the vertex and its (Euclidean, not squared) distance from the point.
+    /// For a Delaunay triangulation, the distance is measured in the input space and the
+    /// vertex is one of the vertices of the simplex containing the point.
+    ///
+    /// Returns an error if the point has the wrong dimension, or [`QhErrorKind::NotComputed`]
+    /// if the hull has not been [computed](Qh::is_computed): qhull is not called, as there is no facet to search.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///         [1.0, 0.0, 1.0],
+    ///         [0.0, 1.0, 1.0],
+    ///         [1.0, 1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let (vertex, dist) = qh.nearest_vertex(&[1.1, 1.1, 1.1]).unwrap();
+    /// assert_eq!(vertex.index(&qh), Some(7));
+    /// assert!((dist - (3.0f64 * 0.1 * 0.1).sqrt()).abs() < 1e-12);
+    ///
+    /// assert!(qh.nearest_vertex(&[1.0, 1.0]).is_err());
+    ///
+    /// // the query point is scaled as the input points
+    /// let qh = Qh::builder()
+    ///     .scale_last_coordinate(true)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 500.0],
+    ///         [1.0, 0.0, 500.0],
+    ///         [0.0, 1.0, 500.0],
+    ///         [0.0, 0.0, 1000.0],
+    ///     ])
+    ///     .unwrap();
+    /// let (vertex, dist) = qh.nearest_vertex(&[0.0, 0.0, 500.0]).unwrap();
+    /// assert_eq!(vertex.index(&qh), Some(0));
+    /// assert!(dist.abs() < 1e-12);
+    ///
+    /// let mut qh = Qh::builder()
+    ///     .compute(false)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.nearest_vertex(&[0.1, 0.1]).unwrap_err().kind, QhErrorKind::NotComputed);
+    /// qh.compute().unwrap();
+    /// let (vertex, _) = qh.nearest_vertex(&[0.1, 0.1]).unwrap();
+    /// assert_eq!(vertex.index(&qh), Some(0));
+    /// ```
+    pub fn nearest_vertex(&self, point: &[f64]) -> Result<(Vertex<'_>, f64), QhError<'_>> {
+        let mut point = self.query_point(point)?;
+        let (vertex, dist) = unsafe {
+            Qh::try_on_qh(self, |qh| {
+                let qh = qh as *mut sys::qhT;
+                let mut dist = 0.0;
+                let mut is_outside = 0;
+                let facet = sys::qh_findbestfacet(qh, point.as_mut_ptr(), sys::qh_ALL, &mut dist, &mut is_outside);
+                if (*facet).tricoplanar() != 0 {
+                    // needed to find the vertices of the original facet
+                    sys::qh_vertexneighbors(qh);
+                }
+                let vertex = sys::qh_nearvertex(qh, facet, point.as_mut_ptr(), &mut dist);
+                (vertex, dist)
+            })?
+        };
+        Ok((Vertex::from_ptr(vertex, self.dim).unwrap(), dist))
+    }
+
+    /// Find the input point nearest to a point, for a Delaunay triangulation
+    ///
+    /// The point is located with `qh_findbestfacet` on the lifted point, as in [`Qh::nearest_vertex`]:
+    /// the best lower facet is the simplex containing the point (there is no separate simplex search).
+    /// The search then walks the edges of the triangulation from the nearest vertex of that simplex,
+    /// moving to a closer neighbor until there is none. The neighbors are the vertices of the
+    /// facets around each vertex (`qh_vertexneighbors`), the pairs of [`Qh::site_adjacency`]
+    /// without building the whole list.
+    /// Since a site that is not the nearest always has a Delaunay neighbor closer to the point,
+    /// the result is exact.
+    /// If the point is outside the triangulation (the nearest facet is [upper Delaunay](Facet::upper_delaunay)),
+    /// all the input points are scanned instead.
+    ///
+    /// Returns the index of the input point and its (Euclidean, not squared) distance from the point.
+    /// Input points that are not vertices (e.g. duplicates) are only found through the scan,
+    /// they are at the same distance as a vertex otherwise.
+    /// Distances are measured between the coordinates used by qhull, see [`Qh::scaling`].
+    ///
+    /// Returns an error if the instance is not a Delaunay triangulation, if the point has the wrong dimension
+    /// or if the hull has not been [computed](Qh::is_computed) ([`QhErrorKind::NotComputed`]).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (_, coords) = rbox::generate("500 D2").unwrap();
+    /// let points: Vec<[f64; 2]> = coords.chunks(2).map(|p| [p[0], p[1]]).collect();
+    /// let qh = Qh::new_delaunay(points.iter().copied()).unwrap();
+    ///
+    /// let brute_force = |query: [f64; 2]| {
+    ///     points
+    ///         .iter()
+    ///         .map(|p| ((p[0] - query[0]).powi(2) + (p[1] - query[1]).powi(2)).sqrt())
+    ///         .enumerate()
+    ///         .min_by(|a, b| a.1.total_cmp(&b.1))
+    ///         .unwrap()
+    /// };
+    ///
+    /// // the points are in [-0.5, 0.5], some of the queries are outside
+    /// let mut seed = 12345u64;
+    /// let mut random = || {
+    ///     seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///     (seed >> 11) as f64 / (1u64 << 53) as f64 * 1.4 - 0.7
+    /// };
+    /// for _ in 0..1000 {
+    ///     let query = [random(), random()];
+    ///     let (_, dist) = qh.nearest_site(&query).unwrap();
+    ///     let (_, expected_dist) = brute_force(query);
+    ///     assert!((dist - expected_dist).abs() < 1e-12);
+    /// }
+    ///
+    /// let hull = Qh::builder().build_from_iter(points.iter().copied()).unwrap();
+    /// assert!(hull.nearest_site(&[0.0, 0.0]).is_err());
+    ///
+    /// let qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .compute(false)
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    /// assert_eq!(qh.nearest_site(&[0.0, 0.0]).unwrap_err().kind, QhErrorKind::NotComputed);
+    /// ```
+    pub fn nearest_site(&self, point: &[f64]) -> Result<(usize, f64), QhError<'_>> {
+        if unsafe { (*self.qh.get()).DELAUNAY == 0 } {
+            return Err(QhError::invalid_input(
+                "the nearest site is only available for Delaunay triangulations",
+            ));
+        }
+        let mut point = self.query_point(point)?;
+        let facet = unsafe {
+            Qh::try_on_qh(self, |qh| {
+                let qh = qh as *mut sys::qhT;
+                // the walk goes through the facets of each vertex
+                sys::qh_vertexneighbors(qh);
+                let mut dist = 0.0;
+                let mut is_outside = 0;
+                sys::qh_findbestfacet(qh, point.as_mut_ptr(), sys::qh_ALL, &mut dist, &mut is_outside)
+            })?
+        };
+
+        let target = &point[..self.dim()];
+        let squared_dist = |index: usize| {
+            let site = self.point(index).expect("the index is an input point");
+            site.iter().zip(target).map(|(a, b)| (a - b) * (a - b)).sum::<f64>()
+        };
+        // the vertices of a facet that are input points, with their distances
+        fn sites<'f>(qh: &Qh, facet: &Facet<'f>, dist: impl Fn(usize) -> f64) -> Vec<(Vertex<'f>, usize, f64)> {
+            let vertices = facet.vertices().expect("facets have vertices");
+            vertices
+                .iter()
+                .filter_map(|v| v.index(qh).map(|index| (v, index, dist(index))))
+                .collect()
+        }
+
+        let mut nearest = Facet::from_ptr(facet, self.dim)
+            .filter(|f| !f.upper_delaunay())
+            .and_then(|facet| {
+                sites(self, &facet, squared_dist)
+                    .into_iter()
+                    .min_by(|a, b| a.2.total_cmp(&b.2))
+            });
+        while let Some((vertex, _, dist)) = nearest {
+            let closer = vertex
+                .neighbor_facets()
+                .expect("the vertex neighbors are computed")
+                .iter()
+                .filter(|f| !f.upper_delaunay())
+                .find_map(|f| sites(self, &f, squared_dist).into_iter().find(|site| site.2 < dist));
+            match closer {
+                Some(closer) => nearest = Some(closer),
+                None => break,
+            }
+        }
+
+        let (index, dist) = match nearest {
+            Some((_, index, dist)) => (index, dist),
+            None => (0..self.num_points())
+                .map(|index| (index, squared_dist(index)))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .ok_or_else(|| QhError::invalid_input("there are no input points"))?,
+        };
+        Ok((index, dist.sqrt()))
+    }
+
+    /// Check whether a point is inside the hull
+    ///
+    /// The point is inside if it is below the hyperplane of every facet.
+    /// Points within roundoff error (`qh.DISTround`) of the boundary are considered inside.
+    /// Returns an error if the point has the wrong dimension,
+    /// if the instance is a Delaunay triangulation or a halfspace intersection,
+    /// or if the hull has not been [computed](Qh::is_computed) ([`QhErrorKind::NotComputed`]).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    ///
+    /// assert!(qh.contains(&[0.25, 0.25]).unwrap());
+    /// assert!(qh.contains(&[0.5, 0.5]).unwrap());
+    /// assert!(!qh.contains(&[0.5, 0.51]).unwrap());
+    /// assert!(!qh.contains(&[-0.1, 0.0]).unwrap());
+    ///
+    /// assert!(qh.contains(&[0.0, 0.0, 0.0]).is_err());
+    ///
+    /// // without facets, every point would be inside
+    /// let qh = Qh::builder()
+    ///     .compute(false)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// assert_eq!(qh.contains(&[5.0, 5.0]).unwrap_err().kind, QhErrorKind::NotComputed);
+    /// ```
+    pub fn contains(&self, point: &[f64]) -> Result<bool, QhError<'_>> {
+        let (delaunay, halfspace, tolerance) = unsafe {
+            let qh = &*self.qh.get();
+            (qh.DELAUNAY != 0, qh.HALFspace != 0, qh.DISTround)
+        };
+        if delaunay || halfspace {
+            return Err(QhError::invalid_input(format!(
+                "containment is only available for convex hulls, not for {}",
+                if delaunay { "Delaunay triangulations" } else { "halfspace intersections" },
+            )));
+        }
+        let point = self.query_point(point)?;
+
+        Ok(self.facets().all(|facet| {
+            let normal = facet.normal().expect("hull facets have a normal");
+            let dist = facet.offset() + normal.iter().zip(&point).map(|(n, x)| n * x).sum::<f64>();
+            dist <= tolerance
+        }))
+    }
+
+    /// The facets visible from a point
+    ///
+    /// A facet is visible if the point is above its hyperplane by more than `qh.MINvisible`,
+    /// the threshold used by qhull to find the visible facets when it adds a point to the hull.
+    /// The hull is not modified: the distance of the point from every facet is computed (`qh_distplane`),
+    /// so this is `O(num_facets)`.
+    /// The result is empty if the point is inside the hull.
+    ///
+    /// For a Delaunay triangulation the point is lifted to the paraboloid: the visible
+    /// [lower facets](Qh::lower_facets) are the cells whose circumsphere contains the point.
+    /// Returns an error if the point has the wrong dimension or if the instance is a halfspace intersection.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let cube = [
+    ///     [0.0, 0.0, 0.0],
+    ///     [1.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [1.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 1.0],
+    ///     [1.0, 0.0, 1.0],
+    ///     [0.0, 1.0, 1.0],
+    ///     [1.0, 1.0, 1.0],
+    /// ];
+    ///
+    /// // a point far above the cube only sees the top face
+    /// let qh = Qh::builder().build_from_iter(cube).unwrap();
+    /// let visible = qh.visible_facets(&[0.5, 0.5, 10.0]).unwrap();
+    /// assert_eq!(visible.len(), 1);
+    /// assert_eq!(visible[0].normal().unwrap(), &[0.0, 0.0, 1.0]);
+    ///
+    /// // two triangles if the hull is triangulated
+    /// let mut qh = Qh::builder().build_from_iter(cube).unwrap();
+    /// qh.triangulate().unwrap();
+    /// let visible = qh.visible_facets(&[0.5, 0.5, 10.0]).unwrap();
+    /// assert_eq!(visible.len(), 2);
+    ///
+    /// // a point beyond a corner sees three faces (six triangles), a point inside none
+    /// assert_eq!(qh.visible_facets(&[2.0, 2.0, 2.0]).unwrap().len(), 6);
+    /// assert!(qh.visible_facets(&[0.5, 0.5, 0.5]).unwrap().is_empty());
+    /// assert!(qh.visible_facets(&[0.5, 0.5]).is_err());
+    /// ```
+    pub fn visible_facets(&self, point: &[f64]) -> Result<Vec<Facet<'_>>, QhError<'_>> {
+        let (halfspace, min_visible) = unsafe {
+            let qh = &*self.qh.get();
+            (qh.HALFspace != 0, qh.MINvisible)
+        };
+        if halfspace {
+            return Err(QhError::invalid_input(
+                "visible facets are not available for halfspace intersections",
+            ));
+        }
+        let mut point = self.query_point(point)?;
+
+        Ok(self
+            .facets()
+            .filter(|facet| {
+                // qh_distplane does not raise qhull errors
+                let mut dist = 0.0;
+                unsafe { sys::qh_distplane(self.qh.get(), point.as_mut_ptr(), facet.raw_ptr(), &mut dist) };
+                dist > min_visible
+            })
+            .collect())
+    }
+
+    /// Get the simplicial facets of the hull
+    ///
+    /// Non-simplicial facets are skipped, use [`Qh::triangulate`] or
+    /// build with [`triangulate`](QhBuilder::triangulate) to make every facet simplicial.
+    /// There are no simplices before the hull is [computed](Qh::is_computed).
+    pub fn simplices(&self) -> impl Iterator<Item = Facet<'_>> {
+        self.facets().filter(|f| f.simplicial())
+    }
+
+    /// Get the facets of the lower hull, that are the cells of a Delaunay triangulation
+    ///
+    /// These are the facets for which [`Facet::is_lower_delaunay`] is true:
+    /// for a Delaunay triangulation, the facets that are not [upper Delaunay](Facet::upper_delaunay),
+    /// for a hull of points lifted by hand, the facets whose normal points downwards.
+    /// The facets are not simplicial if the hull is not [triangulated](QhBuilder::triangulate).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (_, coords) = rbox::generate("30 D2").unwrap();
+    /// let points: Vec<[f64; 2]> = coords.chunks(2).map(|p| [p[0], p[1]]).collect();
+    /// let qh = Qh::new_delaunay(points.iter().copied()).unwrap();
+    ///
+    /// // the lower and the upper facets are a partition of the facets
+    /// let lower: Vec<_> = qh.lower_facets().map(|f| f.id()).collect();
+    /// let upper: Vec<_> = qh.upper_facets().map(|f| f.id()).collect();
+    /// assert!(!lower.is_empty() && !upper.is_empty());
+    /// assert_eq!(lower.len() + upper.len(), qh.num_facets());
+    /// assert!(qh.facets().all(|f| lower.contains(&f.id()) != upper.contains(&f.id())));
+    ///
+    /// // the same triangles, with the points lifted to the paraboloid by hand
+    /// let lifted = Qh::builder()
+    ///     .triangulate(true)
+    ///     .build_from_iter(points.iter().map(|p| [p[0], p[1], p[0] * p[0] + p[1] * p[1]]))
+    ///     .unwrap();
+    /// let triangles = |qh: &Qh, facets: &mut dyn Iterator<Item = Facet>| {
+    ///     let mut triangles: Vec<Vec<usize>> = facets
+    ///         .map(|f| {
+    ///             let mut t: Vec<_> = f.vertices().unwrap().iter().map(|v| v.index(qh).unwrap()).collect();
+    ///             t.sort();
+    ///             t
+    ///         })
+    ///         .collect();
+    ///     triangles.sort();
+    ///     triangles
+    /// };
+    /// assert_eq!(
+    ///     triangles(&lifted, &mut lifted.lower_facets()),
+    ///     triangles(&qh, &mut qh.lower_facets()),
+    /// );
+    /// assert_eq!(lifted.lower_facets().count() + lifted.upper_facets().count(), lifted.num_facets());
+    /// ```
+    pub fn lower_facets(&self) -> impl Iterator<Item = Facet<'_>> {
+        self.facets().filter(|f| f.is_lower_delaunay(self))
+    }
+
+    /// Get the facets of the upper hull
+    ///
+    /// These are the [facets](Qh::facets) that are not [lower facets](Qh::lower_facets),
+    /// for a Delaunay triangulation they are the [upper Delaunay](Facet::upper_delaunay) facets.
+    pub fn upper_facets(&self) -> impl Iterator<Item = Facet<'_>> {
+        self.facets().filter(|f| !f.is_lower_delaunay(self))
+    }
+
+    /// The simplices that are part of the triangulation
+    ///
+    /// These are the [simplices](Qh::simplices) that are not [upper Delaunay](Facet::upper_delaunay),
+    /// and whose vertices are all input points (not the point at infinity of `Qz`, for example).
+    /// For a non-Delaunay hull this is usually the same as [`Qh::simplices`].
+    fn lower_simplices(&self) -> impl Iterator<Item = Facet<'_>> {
+        self.simplices().filter(|f| {
+            !f.upper_delaunay()
+                && f.vertices().is_some_and(|vertices| vertices.iter().all(|v| v.index(self).is_some()))
+        })
+    }
+
+    /// Indices of the input points of each simplex
+    ///
+    /// Upper Delaunay facets are skipped, so for a Delaunay triangulation
+    /// this is the list of the triangles (or tetrahedra, ...) of the triangulation.
+    /// The simplices with a vertex that is not an input point are skipped too.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_delaunay([
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [0.25, 0.25],
+    /// ]).unwrap();
+    ///
+    /// let mut simplices = qh.simplex_indices();
+    /// simplices.iter_mut().for_each(|s| s.sort());
+    /// simplices.sort();
+    /// assert_eq!(simplices, vec![vec![0, 1, 3], vec![0, 2, 3], vec![1, 2, 3]]);
+    ///
+    /// // the point at infinity ('Qz') is not an input point
+    /// let mut qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .at_infinity(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25], [1.0, 1.0]])
+    ///     .unwrap();
+    /// qh.triangulate().unwrap();
+    /// assert!(qh.vertices().any(|v| v.index(&qh).is_none()));
+    /// let simplices = qh.simplex_indices();
+    /// assert_eq!(simplices.len(), 4);
+    /// assert!(simplices.iter().flatten().all(|&i| i < 5));
+    /// ```
+    pub fn simplex_indices(&self) -> Vec<Vec<usize>> {
+        self.lower_simplices()
+            .map(|f| {
+                f.vertices()
+                    .map_or_else(Vec::new, |vertices| vertices.iter().filter_map(|v| v.index(self)).collect())
+            })
+            .collect()
+    }
+
+    /// Indices of the vertices of a 2D hull, as a counterclockwise polygon
+    ///
+    /// The facets (edges) are walked through their neighbors, starting from the first one.
+    /// Returns an error if the hull is not 2-dimensional or if it is a Delaunay triangulation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .output_format(PrintFormat::Size)
+    ///     .build_from_iter([[0.0, 0.0], [2.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.0, 2.0]])
+    ///     .unwrap();
+    ///
+    /// let mut polygon = qh.hull_polygon_2d().unwrap();
+    /// let start = polygon.iter().position(|&i| i == 0).unwrap();
+    /// polygon.rotate_left(start);
+    /// assert_eq!(polygon, vec![0, 1, 3, 4]);
+    ///
+    /// // the signed area is positive, and is the area of the hull (`FS` output)
+    /// let coords = qh.hull_polygon_2d_coords().unwrap();
+    /// let area = (0..coords.len())
+    ///     .map(|i| {
+    ///         let (a, b) = (coords[i], coords[(i + 1) % coords.len()]);
+    ///         (a[0] * b[1] - b[0] * a[1]) / 2.0
+    ///     })
+    ///     .sum::<f64>();
+    /// let size = qh.produce_output(PrintFormat::Size).unwrap();
+    /// let volume: f64 = size.lines().nth(1).unwrap().split_whitespace().nth(2).unwrap().parse().unwrap();
+    /// assert!((area - 4.0).abs() < 1e-12);
+    /// assert!((area - volume).abs() < 1e-12);
+    /// ```
+    pub fn hull_polygon_2d(&self) -> Result<Vec<usize>, QhError<'_>> {
+        let (hull_dim, delaunay) = unsafe {
+            let qh = &*self.qh.get();
+            (qh.hull_dim, qh.DELAUNAY != 0)
+        };
+        if hull_dim != 2 || delaunay {
+            return Err(QhError::invalid_input(format!(
+                "the hull polygon is only available for 2-d hulls, not for {}-d {}",
+                hull_dim,
+                if delaunay { "Delaunay triangulations" } else { "hulls" },
+            )));
+        }
+
+        let Some(first) = self.facets().next() else {
+            return Ok(Vec::new());
+        };
+        let num_facets = self.num_facets();
+        let mut polygon = Vec::with_capacity(num_facets);
+        let mut facet = first;
+        loop {
+            let [start, end] = facet.ordered_vertices(self)?[..] else {
+                unreachable!("ordered_vertices returns 2 vertices in 2-d");
+            };
+            polygon.push(start.index(self).expect("hull vertex is not an input point"));
+            // the next edge starts where this one ends
+            let next = facet
+                .neighbors()
+                .and_then(|neighbors| {
+                    neighbors.iter().find(|n| {
+                        n.vertices().is_some_and(|vertices| vertices.iter().any(|v| v.id() == end.id()))
+                    })
+                })
+                .expect("2-d facets have a neighbor for each vertex");
+            if next.id() == first.id() || polygon.len() >= num_facets {
+                break;
+            }
+            facet = next;
+        }
+        Ok(polygon)
+    }
+
+    /// Coordinates of the vertices of a 2D hull, as a counterclockwise polygon
+    ///
+    /// See [`Qh::hull_polygon_2d`].
+    pub fn hull_polygon_2d_coords(&self) -> Result<Vec<[f64; 2]>, QhError<'_>> {
+        let polygon = self.hull_polygon_2d()?;
+        Ok(polygon
+            .into_iter()
+            .map(|i| {
+                let point = self.point(i).expect("hull vertex is not an input point");
+                [point[0], point[1]]
+            })
+            .collect())
+    }
+
+    /// The edges of the hull, as pairs of input point indices
+    ///
+    /// The pairs are sorted (`(a, b)` with `a < b`) and unique, the list is sorted.
+    /// * in 2D the edges are the facets
+    /// * in 3D the edges are the sides of the facets, whose vertices are ordered around the facet (see [`Qh::facet_vertex_incidences`])
+    /// * in higher dimensions the edges are the pairs of vertices of each facet, so every facet must be
+    ///   simplicial: [triangulate](Qh::triangulate) the hull first, an error is returned otherwise
+    ///
+    /// For a Delaunay triangulation, these are the edges of the triangulation:
+    /// the upper Delaunay facets are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let mut qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// assert_eq!(qh.edges().unwrap().len(), 12);
+    ///
+    /// // a diagonal for each face
+    /// qh.triangulate().unwrap();
+    /// assert_eq!(qh.edges().unwrap().len(), 18);
+    ///
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.5, 0.5]])
+    ///     .unwrap();
+    /// assert_eq!(qh.edges().unwrap(), vec![(0, 1), (0, 3), (1, 2), (2, 3)]);
+    ///
+    /// let qh = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]]).unwrap();
+    /// assert_eq!(qh.edges().unwrap(), vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    ///
+    /// // a 4-d cross-polytope is simplicial
+    /// let (dim, coords) = rbox::generate("d D4").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// assert_eq!(qh.edges().unwrap().len(), 24);
+    /// ```
+    pub fn edges(&self) -> Result<Vec<(usize, usize)>, QhError<'_>> {
+        let hull_dim = self.hull_dim();
+        let delaunay = unsafe { (*self.qh.get()).DELAUNAY != 0 };
+        let skipped = |facet: &Facet| delaunay && facet.upper_delaunay();
+        if hull_dim > 3 {
+            if let Some(facet) = self.facets().find(|f| !f.simplicial() && !skipped(f)) {
+                return Err(QhError::invalid_input(format!(
+                    "the edges of a {}-d hull are only available for simplicial facets, f{} is not simplicial: triangulate the hull first",
+                    hull_dim,
+                    facet.id(),
+                )));
+            }
+        }
+
+        let incidences = self.facet_vertex_incidences()?;
+        let mut edges = Vec::new();
+        for (facet, vertices) in self.facets().zip(&incidences) {
+            if skipped(&facet) {
+                continue;
+            }
+            let n = vertices.len();
+            if n == 2 {
+                edges.push((vertices[0], vertices[1]));
+            } else if hull_dim <= 3 {
+                edges.extend((0..n).map(|i| (vertices[i], vertices[(i + 1) % n])));
+            } else {
+                for (i, &a) in vertices.iter().enumerate() {
+                    edges.extend(vertices[i + 1..].iter().map(|&b| (a, b)));
+                }
+            }
+        }
+        for edge in &mut edges {
+            if edge.0 > edge.1 {
+                *edge = (edge.1, edge.0);
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+        Ok(edges)
+    }
+
+    /// Copy the hull into an [`OwnedHull`]
+    ///
+    /// The snapshot does not borrow the instance nor the input points,
+    /// it can be kept after the instance is dropped and sent to other threads.
+    /// The facets and the vertices are in the order of [`Qh::facets`] and [`Qh::vertices`].
+    /// There are no facets nor vertices before the hull is [computed](Qh::is_computed).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// fn snapshot(points: &[[f64; 3]]) -> OwnedHull {
+    ///     Qh::builder().build_from_iter(points.iter().copied()).unwrap().to_owned()
+    /// }
+    /// fn is_send<T: Send>(_: &T) {}
+    ///
+    /// let points = [
+    ///     [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
+    ///     [0.5, 0.5, 0.5],
+    /// ];
+    /// let owned = snapshot(&points);
+    /// is_send(&owned);
+    ///
+    /// let qh = Qh::builder().build_from_iter(points).unwrap();
+    /// assert_eq!(owned, qh.to_owned());
+    /// assert_eq!((owned.dim, owned.hull_dim), (3, 3));
+    /// assert!(!owned.delaunay && !owned.halfspace);
+    ///
+    /// assert_eq!(owned.vertices.len(), qh.vertices().count());
+    /// for (owned, vertex) in owned.vertices.iter().zip(qh.vertices()) {
+    ///     assert_eq!(owned.id, vertex.id());
+    ///     assert_eq!(owned.index, vertex.index(&qh));
+    ///     assert_eq!(&owned.point[..], vertex.point().unwrap());
+    /// }
+    /// assert_eq!(owned.facets.len(), qh.facets().count());
+    /// let facets: Vec<_> = qh.facets().collect();
+    /// for (owned_facet, facet) in owned.facets.iter().zip(&facets) {
+    ///     assert_eq!(owned_facet.id, facet.id());
+    ///     assert_eq!(&owned_facet.normal[..], facet.normal().unwrap());
+    ///     assert_eq!(owned_facet.offset, facet.offset());
+    ///     assert_eq!(owned_facet.simplicial, facet.simplicial());
+    ///     let vertices: Vec<_> = owned_facet.vertices.iter().map(|&v| owned.vertices[v].id).collect();
+    ///     assert!(vertices.iter().copied().eq(facet.vertices().unwrap().iter().map(|v| v.id())));
+    ///     let neighbors: Vec<_> = owned_facet.neighbors.iter().map(|&f| facets[f].id()).collect();
+    ///     assert!(neighbors.iter().copied().eq(facet.neighbors().unwrap().iter().map(|f| f.id())));
+    /// }
+    ///
+    /// for point in [[0.5, 0.5, 0.5], [1.0, 1.0, 1.0], [0.2, 0.9, 0.1], [1.1, 0.5, 0.5], [-0.5, 0.0, 0.0]] {
+    ///     assert_eq!(owned.contains(&point).unwrap(), qh.contains(&point).unwrap());
+    /// }
+    /// assert!(owned.contains(&[0.0, 0.0]).is_err());
+    /// assert!(owned.equations().map(|(n, o)| (n.to_vec(), o)).eq(qh.facets().map(|f| (f.normal().unwrap().to_vec(), f.offset()))));
+    /// assert_eq!(owned.simplex_indices(), qh.simplex_indices());
+    ///
+    /// let delaunay = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]]).unwrap();
+    /// let owned = delaunay.to_owned();
+    /// drop(delaunay);
+    /// let mut simplices = owned.simplex_indices();
+    /// simplices.iter_mut().for_each(|s| s.sort());
+    /// simplices.sort();
+    /// assert_eq!(simplices, vec![vec![0, 1, 3], vec![0, 2, 3], vec![1, 2, 3]]);
+    /// assert!(owned.contains(&[0.1, 0.1]).is_err());
+    /// ```
+    pub fn to_owned(&self) -> OwnedHull {
+        let (delaunay, halfspace, dist_round) = unsafe {
+            let qh = &*self.qh.get();
+            (qh.DELAUNAY != 0, qh.HALFspace != 0, qh.DISTround)
+        };
+        let vertex_positions: HashMap<Vertex, usize> = self
+            .vertices()
+            .enumerate()
+            .map(|(i, v)| (v, i))
+            .collect();
+        let facet_positions: HashMap<Facet, usize> = self
+            .facets()
+            .enumerate()
+            .map(|(i, f)| (f, i))
+            .collect();
+
+        let vertices = self
+            .vertices()
+            .map(|v| OwnedVertex {
+                id: v.id(),
+                index: v.index(self),
+                point: v.point().map(<[f64]>::to_vec).unwrap_or_default(),
+            })
+            .collect();
+        let facets = self
+            .facets()
+            .map(|f| OwnedFacet {
+                id: f.id(),
+                vertices: f
+                    .vertices()
+                    .iter()
+                    .flatten()
+                    .map(|v| vertex_positions[&v])
+                    .collect(),
+                neighbors: f
+                    .neighbors()
+                    .iter()
+                    .flatten()
+                    .filter_map(|n| facet_positions.get(&n).copied())
+                    .collect(),
+                normal: f.normal().map(<[f64]>::to_vec).unwrap_or_default(),
+                offset: f.offset(),
+                simplicial: f.simplicial(),
+                upper_delaunay: f.upper_delaunay(),
+                good: f.good(),
+                top_orient: f.top_orient(),
+            })
+            .collect();
+
+        OwnedHull {
+            dim: self.dim(),
+            hull_dim: self.hull_dim(),
+            delaunay,
+            halfspace,
+            vertices,
+            facets,
+            dist_round,
+            input_scaling: self.input_scaling.clone(),
+            input_rotation: self.input_rotation.clone(),
+        }
+    }
+
+    /// Triangle mesh of the surface of a 3D hull
+    ///
+    /// Each vertex of the hull appears once in the mesh, see [`TriangleMesh`].
+    /// The triangles are counterclockwise seen from outside the hull (their normals point outwards):
+    /// the non-simplicial facets are split in a fan of triangles, from the vertices ordered around the facet
+    /// (see [`Qh::facet_vertex_incidences`]), there is no need to [triangulate](Qh::triangulate) the hull.
+    ///
+    /// Returns an error if the hull is not 3-dimensional or if it is a Delaunay triangulation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// let mesh = qh.triangle_mesh().unwrap();
+    /// assert_eq!(mesh.positions.len(), 8);
+    /// assert_eq!(mesh.triangles.len(), 12);
+    /// for (position, &index) in mesh.positions.iter().zip(&mesh.input_indices) {
+    ///     assert_eq!(&position[..], qh.point(index).unwrap());
+    /// }
+    ///
+    /// // the normals point away from the centroid
+    /// let centroid = mesh.positions.iter().fold([0.0; 3], |c, p| [c[0] + p[0] / 8.0, c[1] + p[1] / 8.0, c[2] + p[2] / 8.0]);
+    /// let sub = |a: [f64; 3], b: [f64; 3]| [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+    /// for triangle in &mesh.triangles {
+    ///     let [a, b, c] = triangle.map(|i| mesh.positions[i as usize]);
+    ///     let (u, v) = (sub(b, a), sub(c, a));
+    ///     let normal = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+    ///     let outwards = sub(a, centroid);
+    ///     assert!(normal[0] * outwards[0] + normal[1] * outwards[1] + normal[2] * outwards[2] > 0.0);
+    /// }
+    ///
+    /// let delaunay = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]]).unwrap();
+    /// assert!(delaunay.triangle_mesh().is_err());
+    /// ```
+    pub fn triangle_mesh(&self) -> Result<TriangleMesh, QhError<'_>> {
+        let (hull_dim, delaunay) = unsafe {
+            let qh = &*self.qh.get();
+            (qh.hull_dim, qh.DELAUNAY != 0)
+        };
+        if hull_dim != 3 || delaunay {
+            return Err(QhError::invalid_input(format!(
+                "the triangle mesh is only available for 3-d hulls, not for {}-d {}",
+                hull_dim,
+                if delaunay { "Delaunay triangulations" } else { "hulls" },
+            )));
+        }
+
+        let mut mesh = TriangleMesh::default();
+        let mut positions: HashMap<usize, u32> = HashMap::new();
+        for vertices in self.facet_vertex_incidences()? {
+            let vertices: Vec<u32> = vertices
+                .into_iter()
+                .map(|index| {
+                    *positions.entry(index).or_insert_with(|| {
+                        let point = self.point(index).expect("hull vertex is not an input point");
+                        mesh.positions.push([point[0], point[1], point[2]]);
+                        mesh.input_indices.push(index);
+                        (mesh.positions.len() - 1) as u32
+                    })
+                })
+                .collect();
+            // the vertices are clockwise seen from outside
+            for i in 1..vertices.len().saturating_sub(1) {
+                mesh.triangles.push([vertices[0], vertices[i + 1], vertices[i]]);
+            }
+        }
+        Ok(mesh)
+    }
+
+    /// Indices of the input points of the vertices of each facet
+    ///
+    /// The result has an entry for each facet of [`Qh::facets`], simplicial or not,
+    /// like the `i` output of qhull (but with all the facets, and without triangulating
+    /// the non-simplicial facets in 4D and higher).
+    /// The vertices are ordered as in the `i` output:
+    /// * in 2D and for simplicial facets, consistently with the orientation of the facet
+    /// * in 3D, around the facet (`qh_facet3vertex`): clockwise seen from outside the hull
+    /// * otherwise, in the order of qhull
+    ///
+    /// The vertices that are not input points (see [`Vertex::index`]) are skipped,
+    /// e.g. the point at infinity of a Delaunay triangulation ([`at_infinity`](QhBuilder::at_infinity)):
+    /// every index is a valid input point.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [1.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///         [1.0, 0.0, 1.0],
+    ///         [0.0, 1.0, 1.0],
+    ///         [1.0, 1.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// // the faces of the cube are squares
+    /// let incidences = qh.facet_vertex_incidences().unwrap();
+    /// assert_eq!(incidences.len(), 6);
+    /// assert!(incidences.iter().all(|face| face.len() == 4));
+    ///
+    /// // same as the `i` output
+    /// fn as_text(incidences: &[Vec<usize>]) -> String {
+    ///     let lines = incidences
+    ///         .iter()
+    ///         .map(|face| face.iter().map(|i| format!("{} ", i)).collect::<String>() + "\n");
+    ///     format!("{}\n{}", incidences.len(), lines.collect::<String>())
+    /// }
+    /// assert_eq!(qh.produce_output(PrintFormat::Incidences).unwrap(), as_text(&incidences));
+    ///
+    /// // in 3D the faces are clockwise seen from outside the hull
+    /// for (face, facet) in incidences.iter().zip(qh.facets()) {
+    ///     let p = |i: usize| qh.point(face[i]).unwrap();
+    ///     let (a, b, c) = (p(0), p(1), p(2));
+    ///     let (u, v) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]]);
+    ///     let cross = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+    ///     let normal = facet.normal().unwrap();
+    ///     assert!(cross.iter().zip(normal).map(|(c, n)| c * n).sum::<f64>() < 0.0);
+    /// }
+    ///
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [2.0, 0.0], [0.0, 2.0], [2.0, 2.0], [1.0, 1.0]])
+    ///     .unwrap();
+    /// let incidences = qh.facet_vertex_incidences().unwrap();
+    /// assert_eq!(qh.produce_output(PrintFormat::Incidences).unwrap(), as_text(&incidences));
+    ///
+    /// // the point at infinity is not an input point
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25], [1.0, 1.0]];
+    /// let qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .at_infinity(true)
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    /// let incidences = qh.facet_vertex_incidences().unwrap();
+    /// let at_infinity = qh.vertices().filter(|v| v.is_at_infinity(&qh)).count();
+    /// assert_eq!(at_infinity, 1);
+    /// for &i in incidences.iter().flatten() {
+    ///     assert_eq!(qh.point(i).unwrap()[..2], points[i]);
+    /// }
+    /// assert_eq!(qh.vertex_facet_incidences().unwrap().len(), qh.num_points());
+    /// ```
+    pub fn facet_vertex_incidences(&self) -> Result<Vec<Vec<usize>>, QhError<'_>> {
+        let hull_dim = self.hull_dim();
+        unsafe {
+            Qh::try_on_qh(self, |qh| {
+                let qh = qh as *mut sys::qhT;
+                let index = |vertex: Vertex| vertex.index(self);
+                self.facets()
+                    .map(|facet| {
+                        if hull_dim == 3 {
+                            let mut vertices = sys::qh_facet3vertex(qh, facet.raw_ptr());
+                            let indices = Set::<Vertex>::maybe_new(vertices, hull_dim)
+                                .map_or_else(Vec::new, |set| set.iter().filter_map(index).collect());
+                            sys::qh_settempfree(qh, &mut vertices);
+                            return indices;
+                        }
+                        let mut indices: Vec<Option<usize>> = facet
+                            .vertices()
+                            .map_or_else(Vec::new, |set| set.iter().map(index).collect());
+                        // same as qh_printfacetNvertex_simplicial, swaps the first two vertices
+                        let oriented = facet.top_orient() ^ (sys::qh_ORIENTclock != 0);
+                        if !oriented && (hull_dim == 2 || facet.simplicial()) && indices.len() >= 2 {
+                            indices.swap(0, 1);
+                        }
+                        indices.into_iter().flatten().collect()
+                    })
+                    .collect()
+            })
+        }
+    }
+
+    /// Indices of the facets containing each input point
+    ///
+    /// This is the transpose of [`Qh::facet_vertex_incidences`]:
+    /// `incidences[i]` lists, in increasing order, the positions in [`Qh::facet_vertex_incidences`]
+    /// (and in [`Qh::facets`]) of the facets having the point `i` as vertex.
+    /// The list is empty for the points that are not vertices of the hull.
+    /// There are [`Qh::num_points`] entries, the vertices that are not input points are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.25, 0.25],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let facets = qh.facet_vertex_incidences().unwrap();
+    /// let incidences = qh.vertex_facet_incidences().unwrap();
+    /// assert_eq!(incidences.len(), 4);
+    /// assert!(incidences[3].is_empty());
+    /// for (point, point_facets) in incidences.iter().enumerate().take(3) {
+    ///     assert_eq!(point_facets.len(), 2);
+    ///     assert!(point_facets.iter().all(|&f| facets[f].contains(&point)));
+    /// }
+    /// ```
+    pub fn vertex_facet_incidences(&self) -> Result<Vec<Vec<usize>>, QhError<'_>> {
+        let facets = self.facet_vertex_incidences()?;
+        let mut incidences = vec![Vec::new(); self.num_points()];
+        for (facet, vertices) in facets.iter().enumerate() {
+            for &vertex in vertices {
+                incidences[vertex].push(facet);
+            }
+        }
+        Ok(incidences)
+    }
+
+    /// Circumcenters of the simplices of a Delaunay triangulation
+    ///
+    /// The result is aligned with [`Qh::simplex_indices`], see [`Facet::circumcenter`].
+    /// These are the vertices of the Voronoi diagram.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [2.0, 2.0]];
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    ///
+    /// // the circumcenters are equidistant from the vertices of their simplex
+    /// let distance = |a: [f64; 2], b: &[f64]| ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+    /// for (simplex, center) in qh.simplex_indices().iter().zip(qh.circumcenters().unwrap()) {
+    ///     let radius = distance(points[simplex[0]], &center);
+    ///     for &vertex in simplex {
+    ///         assert!((distance(points[vertex], &center) - radius).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn circumcenters(&self) -> Result<Vec<Vec<f64>>, QhError<'_>> {
+        self.lower_simplices().map(|f| f.circumcenter(self)).collect()
+    }
+
+    /// Neighboring simplices of each simplex
+    ///
+    /// The result is aligned with [`Qh::simplex_indices`]: `neighbors[i][k]` is the index of the simplex
+    /// sharing the facet opposite to the `k`-th vertex of the `i`-th simplex.
+    /// The entry is `None` if there is no such simplex, i.e. the facet is on the boundary of the
+    /// triangulation or the neighbor is an upper Delaunay facet.
+    ///
+    /// This is the equivalent of the `neighbors` attribute of scipy's `Delaunay`.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_delaunay([
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [0.25, 0.25],
+    /// ]).unwrap();
+    ///
+    /// let simplices = qh.simplex_indices();
+    /// let neighbors = qh.delaunay_neighbors().unwrap();
+    /// assert_eq!(neighbors.len(), 3);
+    ///
+    /// for (i, (simplex, neighbors)) in simplices.iter().zip(&neighbors).enumerate() {
+    ///     // the inner point is shared by all the triangles,
+    ///     // the opposite edge is on the boundary
+    ///     let inner = simplex.iter().position(|&v| v == 3).unwrap();
+    ///     assert_eq!(neighbors[inner], None);
+    ///
+    ///     // each triangle is adjacent to the other two
+    ///     let mut others = neighbors.iter().flatten().copied().collect::<Vec<_>>();
+    ///     others.sort();
+    ///     assert_eq!(others, (0..3).filter(|&j| j != i).collect::<Vec<_>>());
+    /// }
+    /// ```
+    pub fn delaunay_neighbors(&self) -> Result<Vec<Vec<Option<usize>>>, QhError<'_>> {
+        let simplex_index: HashMap<u32, usize> = self
+            .lower_simplices()
+            .enumerate()
+            .map(|(i, f)| (f.id(), i))
+            .collect();
+
+        let neighbors = self
+            .lower_simplices()
+            .map(|f| {
+                let neighbors = f.neighbors().unwrap();
+                f.vertices()
+                    .unwrap()
+                    .iter()
+                    .map(|v| {
+                        // the neighbor opposite to a vertex is the only one that does not contain it
+                        neighbors
+                            .iter()
+                            .find(|n| {
+                                n.vertices()
+                                    .is_some_and(|vertices| vertices.iter().all(|w| w.id() != v.id()))
+                            })
+                            .filter(|n| !n.upper_delaunay())
+                            .and_then(|n| simplex_index.get(&n.id()).copied())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(neighbors)
+    }
+
+    /// Pairs of input points that share an edge of a Delaunay triangulation
+    ///
+    /// These are the sites whose Voronoi cells are adjacent (the natural neighbors).
+    /// The pairs are sorted (`(a, b)` with `a < b`) and unique, the list is sorted.
+    /// The upper Delaunay facets and the point at infinity ([`at_infinity`](QhBuilder::at_infinity)) are excluded.
+    ///
+    /// This is [`Qh::edges`] restricted to the input points:
+    /// in 3D and higher, the triangulation must be [triangulated](QhBuilder::triangulate).
+    /// Returns an error if the instance is not a Delaunay triangulation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [2.0, 0.0], [0.0, 1.0], [2.0, 1.5]];
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    ///
+    /// // the 4 sides of the quadrilateral and one of its diagonals
+    /// let adjacency = qh.site_adjacency().unwrap();
+    /// assert_eq!(adjacency, vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)]);
+    ///
+    /// let qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .at_infinity(true)
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    /// assert_eq!(qh.site_adjacency().unwrap(), adjacency);
+    ///
+    /// let hull = Qh::builder().build_from_iter(points).unwrap();
+    /// assert!(hull.site_adjacency().is_err());
+    /// ```
+    pub fn site_adjacency(&self) -> Result<Vec<(usize, usize)>, QhError<'_>> {
+        if unsafe { (*self.qh.get()).DELAUNAY == 0 } {
+            return Err(QhError::invalid_input(
+                "the site adjacency is only available for Delaunay triangulations",
+            ));
+        }
+        let num_points = self.num_points();
+        let mut adjacency = self.edges()?;
+        adjacency.retain(|&(_, b)| b < num_points);
+        Ok(adjacency)
+    }
+
+    /// Neighbors of each input point in compressed sparse row (CSR) format
+    ///
+    /// Returns `(indptr, indices)`: the neighbors of the point `i` are `indices[indptr[i]..indptr[i + 1]]`,
+    /// sorted, as the `vertex_neighbor_vertices` attribute of scipy's `Delaunay`.
+    /// `indptr` has [`Qh::num_points`]` + 1` entries, the points that are not vertices have no neighbors.
+    ///
+    /// The neighbors are the [site adjacency](Qh::site_adjacency) for a Delaunay triangulation
+    /// and the [edges](Qh::edges) of the hull otherwise, with the same requirements.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (_, coords) = rbox::generate("100 D2").unwrap();
+    /// let points: Vec<[f64; 2]> = coords.chunks(2).map(|p| [p[0], p[1]]).collect();
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    ///
+    /// let (indptr, indices) = qh.vertex_neighbor_vertices().unwrap();
+    /// assert_eq!(indptr.len(), qh.num_points() + 1);
+    /// assert!(indptr.windows(2).all(|w| w[0] <= w[1]));
+    /// assert_eq!(indptr[qh.num_points()], indices.len());
+    /// let neighbors = |i: usize| &indices[indptr[i]..indptr[i + 1]];
+    ///
+    /// let adjacency = qh.site_adjacency().unwrap();
+    /// for i in 0..qh.num_points() {
+    ///     assert!(neighbors(i).windows(2).all(|w| w[0] < w[1]));
+    ///     // the structure is symmetric
+    ///     assert!(neighbors(i).iter().all(|&j| neighbors(j).contains(&i)));
+    ///     let degree = adjacency.iter().filter(|&&(a, b)| a == i || b == i).count();
+    ///     assert_eq!(neighbors(i).len(), degree);
+    /// }
+    ///
+    /// // the vertices of a square, the center is not a vertex of the hull
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.5, 0.5]])
+    ///     .unwrap();
+    /// let (indptr, indices) = qh.vertex_neighbor_vertices().unwrap();
+    /// assert_eq!(indptr, vec![0, 2, 4, 6, 8, 8]);
+    /// assert_eq!(indices, vec![1, 3, 0, 2, 1, 3, 0, 2]);
+    /// ```
+    pub fn vertex_neighbor_vertices(&self) -> Result<(Vec<usize>, Vec<usize>), QhError<'_>> {
+        let delaunay = unsafe { (*self.qh.get()).DELAUNAY != 0 };
+        let edges = if delaunay { self.site_adjacency()? } else { self.edges()? };
+
+        let num_points = self.num_points();
+        let mut indptr = vec![0; num_points + 1];
+        for &(a, b) in &edges {
+            indptr[a + 1] += 1;
+            indptr[b + 1] += 1;
+        }
+        for i in 0..num_points {
+            indptr[i + 1] += indptr[i];
+        }
+        let mut indices = vec![0; indptr[num_points]];
+        let mut next = indptr[..num_points].to_vec();
+        for &(a, b) in &edges {
+            indices[next[a]] = b;
+            next[a] += 1;
+            indices[next[b]] = a;
+            next[b] += 1;
+        }
+        for i in 0..num_points {
+            indices[indptr[i]..indptr[i + 1]].sort_unstable();
+        }
+        Ok((indptr, indices))
+    }
+
+    /// Edges of a 2D Delaunay triangulation, with their triangles
+    ///
+    /// Each edge appears once, with the indices of its points and of the (one or two) triangles
+    /// that share it, see [`DelaunayEdge`].
+    /// The triangles are numbered as in [`Qh::simplex_indices`], the edges are sorted by their points.
+    ///
+    /// Returns an error if the instance is not a 2D Delaunay triangulation,
+    /// or if some of its cells are not triangles (build with [`triangulate`](QhBuilder::triangulate)).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]]).unwrap();
+    /// let triangles = qh.simplex_indices();
+    /// let edges = qh.delaunay_edges_2d().unwrap();
+    ///
+    /// // Euler's formula for a triangulation of a polygon
+    /// let boundary = edges.iter().filter(|e| e.is_boundary()).count();
+    /// assert_eq!(edges.len(), (3 * triangles.len() + boundary) / 2);
+    /// assert_eq!(qh.num_points() + triangles.len(), edges.len() + 1);
+    /// assert_eq!(boundary, 3);
+    /// assert_eq!(edges.len(), 6);
+    ///
+    /// for edge in &edges {
+    ///     let (a, b) = edge.points;
+    ///     assert!(a < b);
+    ///     let (first, second) = edge.simplices;
+    ///     for triangle in std::iter::once(first).chain(second) {
+    ///         assert!(triangles[triangle].contains(&a) && triangles[triangle].contains(&b));
+    ///     }
+    ///     assert_ne!(Some(first), second);
+    /// }
+    /// // the edges are the pairs of adjacent sites
+    /// assert!(edges.iter().map(|e| e.points).eq(qh.site_adjacency().unwrap()));
+    ///
+    /// let qh = Qh::new_delaunay([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.2, 0.2, 0.2]]).unwrap();
+    /// assert!(qh.delaunay_edges_2d().is_err());
+    /// let hull = Qh::builder().build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]).unwrap();
+    /// assert!(hull.delaunay_edges_2d().is_err());
+    /// ```
+    pub fn delaunay_edges_2d(&self) -> Result<Vec<DelaunayEdge>, QhError<'_>> {
+        let delaunay = unsafe { (*self.qh.get()).DELAUNAY != 0 };
+        if !delaunay || self.dim() != 2 {
+            return Err(QhError::invalid_input(format!(
+                "the Delaunay edges are only available for 2-d Delaunay triangulations, not for {}-d {}",
+                self.dim(),
+                if delaunay { "Delaunay triangulations" } else { "hulls" },
+            )));
+        }
+        if let Some(facet) = self.lower_facets().find(|f| !f.simplicial()) {
+            return Err(QhError::invalid_input(format!(
+                "facet f{} of the Delaunay triangulation is not a triangle, the triangulation must be triangulated",
+                facet.id(),
+            )));
+        }
+
+        let mut edges: HashMap<(usize, usize), DelaunayEdge> = HashMap::new();
+        for (simplex, points) in self.simplex_indices().iter().enumerate() {
+            for i in 0..3 {
+                let (a, b) = (points[i], points[(i + 1) % 3]);
+                let points = (a.min(b), a.max(b));
+                edges
+                    .entry(points)
+                    .and_modify(|edge| edge.simplices.1 = Some(simplex))
+                    .or_insert(DelaunayEdge { points, simplices: (simplex, None) });
+            }
+        }
+        let mut edges: Vec<DelaunayEdge> = edges.into_values().collect();
+        edges.sort_unstable_by_key(|edge| edge.points);
+        Ok(edges)
+    }
+
+    /// Natural neighbor (Sibson) coordinates of a point in a 2D Delaunay triangulation
+    ///
+    /// Returns the input index of each natural neighbor of the point with its weight, sorted by index.
+    /// The weight of a site is the area that its Voronoi cell would lose to the point if the point was inserted
+    /// in the triangulation, relative to the area of the Voronoi cell of the point.
+    /// The triangulation is not modified: the areas are computed from the triangles whose circumcircles
+    /// contain the point (the cavity of the Bowyer-Watson algorithm).
+    /// The weights are positive, they sum to one, and their weighted sum of the sites is the point.
+    ///
+    /// A point on a site has only that site, a point on the boundary of the triangulation
+    /// has the two sites of its edge, with linear weights.
+    /// Every call scans the triangles, use [`Qh::natural_neighbor_interpolate`] for many points.
+    ///
+    /// The cells of the triangulation do not need to be [triangulated](QhBuilder::triangulate).
+    /// Returns an error if the instance is not a 2D Delaunay triangulation,
+    /// if the point has the wrong dimension or if it is outside of the triangulation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.4, 0.3]];
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    ///
+    /// let query = [0.6, 0.55];
+    /// let weights = qh.natural_neighbor_weights(&query).unwrap();
+    /// assert!(weights.iter().all(|&(_, w)| w > 0.0));
+    /// assert!((weights.iter().map(|(_, w)| w).sum::<f64>() - 1.0).abs() < 1e-12);
+    /// for axis in 0..2 {
+    ///     let coord: f64 = weights.iter().map(|&(i, w)| w * points[i][axis]).sum();
+    ///     assert!((coord - query[axis]).abs() < 1e-12);
+    /// }
+    ///
+    /// // the center of a square of 4 sites only has the 4 corners, with the same weight
+    /// let qh = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [2.0, 2.0]]).unwrap();
+    /// let weights = qh.natural_neighbor_weights(&[0.5, 0.5]).unwrap();
+    /// assert_eq!(weights.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    /// assert!(weights.iter().all(|(_, w)| (w - 0.25).abs() < 1e-12));
+    ///
+    /// assert_eq!(qh.natural_neighbor_weights(&[1.0, 0.0]).unwrap(), vec![(1, 1.0)]);
+    /// assert_eq!(qh.natural_neighbor_weights(&[0.25, 0.0]).unwrap(), vec![(0, 0.75), (1, 0.25)]);
+    /// assert!(qh.natural_neighbor_weights(&[-1.0, 0.0]).is_err());
+    /// assert!(qh.natural_neighbor_weights(&[0.5, 0.5, 0.5]).is_err());
+    /// ```
+    pub fn natural_neighbor_weights(&self, query: &[f64]) -> Result<Vec<(usize, f64)>, QhError<'_>> {
+        let neighbors = NaturalNeighbors::new(self)?;
+        Self::natural_neighbors_of(&neighbors, self.query_point(query)?)
+    }
+
+    /// Natural neighbor (Sibson) interpolation in a 2D Delaunay triangulation
+    ///
+    /// `values` has a value for each input point, the result has the interpolated value of each query point,
+    /// the weighted sum of the values of its [natural neighbors](Qh::natural_neighbor_weights).
+    /// The interpolation is exact for linear functions and it is smooth except at the sites.
+    ///
+    /// Returns an error if the number of values is not [`Qh::num_points`]
+    /// or for the same reasons as [`Qh::natural_neighbor_weights`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (_, coords) = rbox::generate("50 D2").unwrap();
+    /// let points: Vec<[f64; 2]> = coords.chunks(2).map(|p| [p[0], p[1]]).collect();
+    /// let qh = Qh::new_delaunay(points.iter().copied()).unwrap();
+    ///
+    /// let linear = |p: &[f64]| 3.0 * p[0] - 2.0 * p[1] + 1.0;
+    /// let values: Vec<f64> = points.iter().map(|p| linear(p)).collect();
+    ///
+    /// // points inside the convex hull of the sites, and the sites themselves
+    /// let queries = (0..100)
+    ///     .map(|i| [0.2 * (i as f64 * 0.37).sin(), 0.2 * (i as f64 * 0.61).cos()])
+    ///     .chain(points.iter().copied())
+    ///     .collect::<Vec<_>>();
+    /// let interpolated = qh.natural_neighbor_interpolate(&values, &queries).unwrap();
+    /// for (query, value) in queries.iter().zip(&interpolated) {
+    ///     assert!((value - linear(query)).abs() < 1e-9);
+    /// }
+    ///
+    /// assert!(qh.natural_neighbor_interpolate(&values[1..], &queries).is_err());
+    /// ```
+    pub fn natural_neighbor_interpolate<P: AsRef<[f64]>>(
+        &self,
+        values: &[f64],
+        queries: impl IntoIterator<Item = P>,
+    ) -> Result<Vec<f64>, QhError<'_>> {
+        if values.len() != self.num_points() {
+            return Err(QhError::invalid_input(format!(
+                "{} values for {} points",
+                values.len(),
+                self.num_points(),
+            )));
+        }
+        let neighbors = NaturalNeighbors::new(self)?;
+        queries
+            .into_iter()
+            .map(|query| {
+                let weights = Self::natural_neighbors_of(&neighbors, self.query_point(query.as_ref())?)?;
+                Ok(weights.iter().map(|&(index, weight)| weight * values[index]).sum())
+            })
+            .collect()
+    }
+
+    /// Natural neighbor weights of a point converted with [`Qh::query_point`]
+    fn natural_neighbors_of(
+        neighbors: &NaturalNeighbors,
+        query: Vec<f64>,
+    ) -> Result<Vec<(usize, f64)>, QhError<'static>> {
+        neighbors.weights([query[0], query[1]]).ok_or_else(|| {
+            QhError::invalid_input(format!(
+                "the point ({}, {}) is outside of the triangulation",
+                query[0], query[1],
+            ))
+        })
     }
 
-    /// Check the output of the qhull instance
-    pub fn check_output(&mut self) -> Result<(), QhError> {
-        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_check_output(qh)) }
-    }
+    /// Ridges of the Voronoi diagram of a Delaunay triangulation
+    ///
+    /// Each ridge separates the cells of two sites, see [`VoronoiRidge`].
+    /// These are the ridges of the `Fi` (bounded) and `Fo` (unbounded) outputs of qvoronoi, visited by `qh_eachvoronoi_all`.
+    /// Qhull switches the centers of the facets to Voronoi centers (see [`Facet::voronoi_center`])
+    /// and computes the neighbors of the vertices.
+    /// The ridges of the point at infinity ([`at_infinity`](QhBuilder::at_infinity)) are skipped.
+    ///
+    /// Returns an error if the instance is not a Delaunay triangulation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // the Voronoi diagram of the corners of a square has a vertex in the center and 4 unbounded ridges,
+    /// // the cocircular sites need a point at infinity ('Qz')
+    /// let mut qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .at_infinity(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]])
+    ///     .unwrap();
+    /// let mut ridges = qh.voronoi_ridges().unwrap();
+    /// ridges.iter_mut().for_each(|r| r.sites = (r.sites.0.min(r.sites.1), r.sites.0.max(r.sites.1)));
+    /// ridges.sort_by_key(|r| r.sites);
+    /// assert_eq!(ridges.iter().map(|r| r.sites).collect::<Vec<_>>(), vec![(0, 1), (0, 2), (1, 3), (2, 3)]);
+    ///
+    /// let expected = [[0.0, -1.0], [-1.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+    /// for (ridge, expected) in ridges.iter().zip(expected) {
+    ///     assert_eq!(ridge.vertices, vec![vec![0.5, 0.5]]);
+    ///     let RidgeKind::UnboundedRay { direction } = &ridge.kind else {
+    ///         panic!("ridge {:?} is bounded", ridge.sites);
+    ///     };
+    ///     assert!(direction.iter().zip(expected).all(|(d, e)| (d - e).abs() < 1e-12));
+    ///     // the direction is in the hyperplane of the ridge
+    ///     assert!(direction.iter().zip(&ridge.normal).map(|(d, n)| d * n).sum::<f64>().abs() < 1e-12);
+    /// }
+    ///
+    /// // with a site in the middle, its 4 ridges are bounded
+    /// let mut qh = Qh::new_delaunay([[0.0, 0.0], [2.0, 0.0], [0.0, 2.0], [2.0, 2.0], [1.0, 0.9]]).unwrap();
+    /// let ridges = qh.voronoi_ridges().unwrap();
+    /// let (bounded, unbounded): (Vec<_>, Vec<_>) = ridges.iter().partition(|r| r.is_bounded());
+    /// assert_eq!(bounded.len(), 4);
+    /// assert!(bounded.iter().all(|r| r.sites.0 == 4 || r.sites.1 == 4));
+    /// assert!(bounded.iter().all(|r| r.vertices.len() == 2));
+    /// assert_eq!(unbounded.len(), 4);
+    ///
+    /// // the unbounded ridges go away from every site, also in 3D
+    /// let (dim, coords) = rbox::generate("50 D3").unwrap();
+    /// let sites = coords.chunks(dim).map(<[f64]>::to_vec).collect::<Vec<_>>();
+    /// let mut qh = Qh::builder().delaunay(true).project_delaunay(true).build_managed(dim, coords).unwrap();
+    /// for ridge in qh.voronoi_ridges().unwrap() {
+    ///     let RidgeKind::UnboundedRay { direction } = &ridge.kind else {
+    ///         continue;
+    ///     };
+    ///     let site = &sites[ridge.sites.0];
+    ///     for other in &sites {
+    ///         let along = direction.iter().zip(other).zip(site).map(|((d, o), s)| d * (o - s)).sum::<f64>();
+    ///         assert!(along < 1e-12);
+    ///     }
+    /// }
+    ///
+    /// let mut hull = Qh::builder().build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]).unwrap();
+    /// assert!(hull.voronoi_ridges().is_err());
+    /// ```
+    pub fn voronoi_ridges(&mut self) -> Result<Vec<VoronoiRidge>, QhError<'_>> {
+        let (delaunay, upper) = unsafe {
+            let qh = &*self.qh.get();
+            (qh.DELAUNAY != 0, qh.UPPERdelaunay)
+        };
+        if !delaunay {
+            return Err(QhError::invalid_input(
+                "the Voronoi ridges are only available for Delaunay triangulations",
+            ));
+        }
 
-    pub fn check_points(&mut self) -> Result<(), QhError> {
-        unsafe {
+        struct RawRidge {
+            sites: (*mut sys::vertexT, *mut sys::vertexT),
+            centers: Vec<*mut sys::facetT>,
+            normal: Vec<f64>,
+            offset: f64,
+            unbounded: bool,
+        }
+        // the ridges are collected in `qh.cpp_other`, which qhull reserves for its users:
+        // `fp` is only passed to the callback by qh_eachvoronoi_all, it is null
+        unsafe extern "C" fn collect(
+            qh: *mut sys::qhT,
+            _fp: *mut sys::FILE,
+            vertex: *mut sys::vertexT,
+            vertex_a: *mut sys::vertexT,
+            centers: *mut sys::setT,
+            unbounded: std::ffi::c_uint,
+        ) {
+            let ridges = &mut *((*qh).cpp_other as *mut Vec<RawRidge>);
+            let dim = ((*qh).hull_dim - 1) as usize;
+            let mut offset = 0.0;
+            // a pointer into qh.gm_matrix, computes the centers of the facets
+            let normal = sys::qh_detvnorm(qh, vertex, vertex_a, centers, &mut offset);
+            let centers = std::slice::from_raw_parts(
+                std::ptr::addr_of_mut!((*centers).e) as *const *mut sys::facetT,
+                sys::qh_setsize(qh, centers) as usize,
+            );
+            ridges.push(RawRidge {
+                sites: (vertex, vertex_a),
+                centers: centers.iter().copied().filter(|&f| (*f).visitid != 0).collect(),
+                normal: std::slice::from_raw_parts(normal, dim).to_vec(),
+                offset,
+                unbounded: unbounded != 0,
+            });
+        }
+
+        let mut raw: Vec<RawRidge> = Vec::new();
+        let raw_ptr = &mut raw as *mut Vec<RawRidge> as *mut std::ffi::c_void;
+        let previous = self.qh.get_mut().cpp_other;
+        let result = unsafe {
             Qh::try_on_qh_mut(self, |qh| {
-                println!("qh_check_points!!!");
-                sys::qh_check_points(qh)
+                (*qh).cpp_other = raw_ptr;
+                sys::qh_eachvoronoi_all(qh, std::ptr::null_mut(), Some(collect), upper, sys::qh_RIDGE_qh_RIDGEall, true as _);
             })
-        }
+            .map_err(|e| e.into_static())
+        };
+        self.qh.get_mut().cpp_other = previous;
+        result?;
+
+        let dim = self.dim();
+        // the outward normal of a facet of the hull of the sites, from a Voronoi vertex and a neighbor at infinity
+        let direction = |a: *mut sys::vertexT, b: *mut sys::vertexT| -> Option<Vec<f64>> {
+            let (a, b) = (Vertex::from_ptr(a, self.dim)?, Vertex::from_ptr(b, self.dim)?);
+            let contains = |facet: &Facet, vertex: &Vertex| {
+                facet.vertices().is_some_and(|set| set.iter().any(|v| v.ptr_eq(vertex)))
+            };
+            let is_center = |facet: &Facet| facet.upper_delaunay() == (upper != 0);
+            for inner in a.neighbor_facets()?.iter().filter(|f| is_center(f) && contains(f, &b)) {
+                let outer = inner.neighbors()?.iter().filter(|f| !is_center(f) && contains(f, &a) && contains(f, &b));
+                for outer in outer {
+                    let (face, others): (Vec<_>, Vec<_>) = inner.vertices()?.iter().partition(|v| contains(&outer, v));
+                    let face = face.iter().map(|v| v.point().map(|p| &p[..dim])).collect::<Option<Vec<_>>>()?;
+                    let inside = &others.first()?.point()?[..dim];
+                    if let Some(direction) = voronoi_ridge::outward_direction(&face, inside) {
+                        // the cells of the furthest-site diagram go to infinity on the other side
+                        return Some(if upper != 0 { direction.iter().map(|x| -x).collect() } else { direction });
+                    }
+                }
+            }
+            None
+        };
+        let ridges = raw
+            .into_iter()
+            .filter_map(|ridge| {
+                let site = |vertex| Vertex::from_ptr(vertex, self.dim).and_then(|v| v.index(self));
+                let sites = (site(ridge.sites.0)?, site(ridge.sites.1)?);
+                let vertices = ridge
+                    .centers
+                    .iter()
+                    .map(|&f| {
+                        let facet = Facet::from_ptr(f, self.dim).expect("Voronoi vertices are facets");
+                        facet.voronoi_center(self).expect("the Voronoi centers are computed").to_vec()
+                    })
+                    .collect();
+                let kind = if ridge.unbounded {
+                    let direction = direction(ridge.sites.0, ridge.sites.1).unwrap_or_else(|| vec![0.0; dim]);
+                    RidgeKind::UnboundedRay { direction }
+                } else {
+                    RidgeKind::Bounded
+                };
+                Some(VoronoiRidge {
+                    sites,
+                    vertices,
+                    normal: ridge.normal,
+                    offset: ridge.offset,
+                    kind,
+                })
+            })
+            .collect();
+        Ok(ridges)
     }
 
-    /// Creates a new Delaunay triangulation
+    /// Barycentric transforms of each simplex
     ///
-    /// See the `examples` directory for an example.
-    pub fn new_delaunay<I>(points: impl IntoIterator<Item = I>) -> Result<Self, QhError<'static>>
-    where
-        I: IntoIterator<Item = f64>,
-    {
-        let CollectedCoords {
-            coords,
-            count: _,
-            dim,
-        } = prepare_delaunay_points(points);
+    /// The result is aligned with [`Qh::simplex_indices`], see [`SimplexTransform`] for more information.
+    /// For a Delaunay triangulation, the lifted coordinate is ignored and the transforms
+    /// act on the original points.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [0.25, 0.25],
+    /// ];
+    /// let qh = Qh::new_delaunay(points).unwrap();
+    ///
+    /// let transforms = qh.barycentric_transforms().unwrap();
+    /// for (simplex, transform) in qh.simplex_indices().iter().zip(&transforms) {
+    ///     for (k, &v) in simplex.iter().enumerate() {
+    ///         let coords = transform.barycentric(&points[v]);
+    ///         for (j, c) in coords.iter().enumerate() {
+    ///             let expected = if j == k { 1.0 } else { 0.0 };
+    ///             assert!((c - expected).abs() < 1e-12);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn barycentric_transforms(&self) -> Result<Vec<SimplexTransform>, QhError<'_>> {
+        let dim = self.dim();
 
-        // TODO check correctness, use qdelaunay as reference
-        QhBuilder::default()
-            .delaunay(true)
-            .upper_delaunay(true)
-            .scale_last(true)
-            .triangulate(true)
-            .keep_coplanar(true)
-            .build_managed(dim, coords)
+        let transforms = self
+            .lower_simplices()
+            .map(|f| {
+                let vertices = f
+                    .vertices()
+                    .unwrap()
+                    .iter()
+                    .map(|v| &v.point().unwrap()[..dim])
+                    .collect::<Vec<_>>();
+                SimplexTransform::new(&vertices)
+            })
+            .collect();
+
+        Ok(transforms)
     }
 
-    /// Get all the facets in the hull
+    /// Produce the output of qhull in the given format
     ///
-    /// # Remarks
-    /// * this function will also return the sentinel face, which is the last face in the list of facets.
-    ///   To avoid it, use the [`Qh::facets`] function or just [`filter`](std::iter::Iterator::filter) the iterator
-    ///   checking for [`Facet::is_sentinel`].
-    pub fn all_facets(&self) -> impl Iterator<Item = Facet> {
-        let mut current = Facet::from_ptr(
-            unsafe { sys::qh_get_facet_list(self.qh.get() as *mut _) },
-            self.dim,
-        );
+    /// This is the output that the qhull programs print for the corresponding option
+    /// (e.g. `o` for [`PrintFormat::Off`]), see [`PrintFormat`] for the available formats.
+    ///
+    /// The output is captured in a temporary file regardless of [`QhBuilder::capture_stdout`],
+    /// the output stream and the [output formats](QhBuilder::output_format) of the instance
+    /// are restored before returning.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [1.0, 1.0],
+    ///         [0.5, 0.5],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let off = qh.produce_output(PrintFormat::Off).unwrap();
+    /// let mut lines = off.lines();
+    /// assert_eq!(lines.next(), Some("2"));
+    /// // 5 points, 4 facets
+    /// assert_eq!(lines.next().unwrap().split_whitespace().collect::<Vec<_>>(), ["5", "4", "4"]);
+    ///
+    /// let extremes = qh.produce_output(PrintFormat::Extremes).unwrap();
+    /// assert_eq!(extremes.lines().next(), Some("4"));
+    /// ```
+    pub fn produce_output(&mut self, format: PrintFormat) -> Result<String, QhError<'_>> {
+        let out_file = self.produce_output_file(format)?;
+        out_file
+            .read_as_string_and_close()
+            .map_err(|e| QhError::io_failed("failed to read the captured output", e))
+    }
 
-        std::iter::from_fn(move || current.take().map(|v| {
-            current = v.next();
-            v
-        }))
+    /// Counters of the hull construction
+    ///
+    /// See [`QhCounters`] for the available values.
+    /// The counters are read from the qhull instance on each call,
+    /// they can be used to monitor a hull while it is being built.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("1000 D3").unwrap();
+    /// let mut qh = Qh::builder().compute(false).build_managed(dim, coords).unwrap();
+    /// assert_eq!(qh.num_facets(), 0);
+    ///
+    /// qh.compute().unwrap();
+    /// let counters = qh.counters();
+    /// assert_eq!(counters.num_outside, 0);
+    /// assert_eq!(counters.num_visible, 0);
+    /// assert_eq!(counters.num_facets, qh.num_facets());
+    /// assert_eq!(counters.num_vertices, qh.num_vertices());
+    /// ```
+    pub fn counters(&self) -> QhCounters {
+        unsafe { QhCounters::from_raw(self.qh.get()) }
     }
 
-    /// Get all the facets in the hull in reverse order
+    /// Indices of the input points of the initial simplex
     ///
-    /// See [`Qh::all_facets`] for more information.
-    pub fn all_facets_rev(&self) -> impl Iterator<Item = Facet> {
-        let mut current = Facet::from_ptr(
-            unsafe { sys::qh_get_facet_tail(self.qh.get() as *mut _) },
-            self.dim,
-        );
+    /// Qhull starts from a simplex of [`hull_dim`](Qh::hull_dim)` + 1` points
+    /// (chosen among the points with extreme coordinates, or among all the points with
+    /// [`all_points`](QhBuilder::all_points) (`Qs`)), then adds the other points one at a time.
+    /// Together with the [seed](QhBuilder::random_seed) this is useful to reproduce precision problems.
+    ///
+    /// The simplex is recovered from the hull: qhull creates its vertices first, so they have the lowest ids.
+    /// Returns `None` if the hull has not been [computed](Qh::is_computed),
+    /// if a vertex of the initial simplex was later removed from the hull (e.g. by merging facets)
+    /// or if it is not an input point (the point [at infinity](QhBuilder::at_infinity)).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("100 D3").unwrap();
+    /// let mut qh = Qh::builder().compute(false).build_managed(dim, coords).unwrap();
+    /// assert_eq!(qh.initial_simplex(), None);
+    ///
+    /// qh.compute().unwrap();
+    /// let simplex = qh.initial_simplex().unwrap();
+    /// assert_eq!(simplex.len(), 4);
+    /// for (i, index) in simplex.iter().enumerate() {
+    ///     assert!(!simplex[..i].contains(index));
+    ///     assert!(qh.vertices().any(|v| v.index(&qh) == Some(*index)));
+    /// }
+    ///
+    /// // for a Delaunay triangulation the simplex is in the lifted dimension
+    /// let qh = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.3, 0.4]]).unwrap();
+    /// assert_eq!(qh.initial_simplex().unwrap().len(), 4);
+    /// ```
+    pub fn initial_simplex(&self) -> Option<Vec<usize>> {
+        if !self.computed {
+            return None;
+        }
+        // qh_initialvertices creates the vertices right after the sentinel (id 0)
+        let size = self.hull_dim() + 1;
+        let mut simplex = self
+            .vertices()
+            .filter(|v| (1..=size as u32).contains(&v.id()))
+            .map(|v| Some((v.id(), v.index(self)?)))
+            .collect::<Option<Vec<_>>>()?;
+        if simplex.len() != size {
+            return None;
+        }
+        simplex.sort_unstable();
+        Some(simplex.into_iter().map(|(_, index)| index).collect())
+    }
 
-        std::iter::from_fn(move || current.take().map(|v| {
-            current = v.previous();
-            v
-        }))
+    /// Statistics of the computation
+    ///
+    /// See [`QhStatistics`] for the available values.
+    /// The aggregate statistics are collected from the current facets by `qh_collectstatistics`,
+    /// the counters are read before collecting them, so that they are not affected.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("1000 D3").unwrap();
+    /// let mut qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// let stats = qh.statistics().unwrap();
+    /// assert!(stats.distance_tests > 0);
+    /// assert!(stats.facets_created >= qh.num_facets());
+    /// assert_eq!(stats.build_count, 0);
+    ///
+    /// let mut qh = Qh::builder().joggle(None).build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]).unwrap();
+    /// assert!(qh.statistics().unwrap().build_count >= 1);
+    /// ```
+    pub fn statistics(&mut self) -> Result<QhStatistics, QhError<'_>> {
+        let counters = unsafe { QhStatistics::from_raw(self.qh.get()) };
+        let collected = unsafe {
+            Qh::try_on_qh_mut(self, |qh| {
+                sys::qh_collectstatistics(qh);
+                QhStatistics::from_raw(qh)
+            })?
+        };
+        Ok(QhStatistics {
+            max_facet_merges: collected.max_facet_merges,
+            ..counters
+        })
     }
 
-    /// Get the facets in the hull
+    /// Summary of the facet merges
     ///
-    /// # Remarks
-    /// * this function will not return the sentinel face, which is the last face in the list of facets.
-    ///   To get it, use the [`Qh::all_facets`] function.
-    pub fn facets(&self) -> impl Iterator<Item = Facet> {
-        self.all_facets().filter(|f| !f.is_sentinel())
+    /// Walks the facets of the hull and counts the merges and the merge flags,
+    /// see [`MergeReport`].
+    /// The ids of the facets merged at least `threshold` times are reported in [`MergeReport::over_threshold`],
+    /// this helps to find the wide facets when tuning the merge options (`C-n`, `A-n`).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a noisy sphere: points rounded to integer coordinates
+    /// let (dim, coords) = rbox::generate("1000 s z B10 t1").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// let report = qh.merge_report(3);
+    /// assert!(report.merged_facets > 0);
+    /// assert!(report.total_merges >= report.merged_facets);
+    /// assert!(!report.over_threshold.is_empty());
+    /// for facet in qh.facets().filter(|f| report.over_threshold.contains(&f.id())) {
+    ///     assert!(facet.merge_count() >= 3);
+    /// }
+    /// ```
+    pub fn merge_report(&self, threshold: u32) -> MergeReport {
+        let mut report = MergeReport::default();
+        for facet in self.facets() {
+            let merges = facet.num_merge();
+            if merges > 0 {
+                report.merged_facets += 1;
+                report.total_merges += merges as usize;
+            }
+            if merges >= threshold {
+                report.over_threshold.push(facet.id());
+            }
+            report.merge_ridge += facet.merge_ridge() as usize;
+            report.merge_horizon += facet.merge_horizon() as usize;
+            report.coplanar_horizon += facet.coplanar_horizon() as usize;
+            report.degenerate += facet.degenerate() as usize;
+            report.redundant += facet.redundant() as usize;
+        }
+        report
     }
 
-    pub fn all_vertices(&self) -> impl Iterator<Item = Vertex> {
-        let mut current = Vertex::from_ptr(
-            unsafe { sys::qh_get_vertex_list(self.qh.get() as *mut _) },
-            self.dim,
-        );
+    /// Precision problems reported by qhull
+    ///
+    /// Combines the precision counters of qhull, [`qh.max_outside`](QhStatistics::max_outside) and `qh.min_vertex`
+    /// with the precision errors and warnings in the [captured](QhBuilder::capture_stderr) error output,
+    /// see [`PrecisionReport`].
+    /// The captured output is not taken, unlike [`Qh::messages`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // a pyramid whose base is a grid of nearly coplanar points
+    /// let mut points = Vec::new();
+    /// for i in 0..10 {
+    ///     for j in 0..10 {
+    ///         let noise = ((i * 7 + j * 13) % 11) as f64 - 5.0;
+    ///         points.push([i as f64, j as f64, noise * 1e-13]);
+    ///     }
+    /// }
+    /// points.push([4.5, 4.5, 5.0]);
+    /// let qh = Qh::builder().build_from_iter(points).unwrap();
+    ///
+    /// let report = qh.precision_report();
+    /// assert!(report.had_precision_warnings);
+    /// assert!(report.coplanar_horizon_facets > 0);
+    /// assert!(report.max_outside >= 0.0 && report.min_vertex <= 0.0);
+    ///
+    /// // a well-conditioned input
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    ///     .unwrap();
+    /// let report = qh.precision_report();
+    /// assert!(!report.had_precision_warnings);
+    /// assert!(report.facets.is_empty());
+    ///
+    /// // other warnings are not about precision ('QH7040', 'Qbb' is meant for Delaunay triangulations)
+    /// let mut qh = Qh::builder()
+    ///     .scale_last(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    /// let report = qh.precision_report();
+    /// assert!(report.warnings.is_empty());
+    /// assert!(!report.had_precision_warnings);
+    /// assert!(qh.messages().iter().any(|m| m.code == 7040));
+    ///
+    /// // cubes, exactly coplanar points and points on a sphere have no precision problems
+    /// for spec in ["c", "c 50", "c 200 W0", "100 s", "1000 s D4"] {
+    ///     let (dim, coords) = rbox::generate(spec).unwrap();
+    ///     let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///     let report = qh.precision_report();
+    ///     assert!(!report.had_precision_warnings, "{}: {:?}", spec, report);
+    ///     assert!(report.facets.is_empty());
+    /// }
+    /// ```
+    pub fn precision_report(&self) -> PrecisionReport {
+        let text = self.buffers.borrow_mut().peek_err_file();
+        let messages = text.map_or_else(Vec::new, |text| message::parse_messages(&text));
+        let mut report = unsafe { PrecisionReport::from_raw(self.qh.get(), messages) };
+        let mentioned: Vec<u32> = report
+            .warnings
+            .iter()
+            .flat_map(|warning| precision_report::mentioned_facets(&warning.text))
+            .collect();
+        report.facets = self
+            .facets()
+            .filter(|f| f.flipped() || mentioned.contains(&f.id()))
+            .map(|f| f.id())
+            .collect();
+        report
+    }
 
-        std::iter::from_fn(move || current.take().map(|v| {
-            current = v.next();
-            v
-        }))
+    /// Take the messages that qhull printed to the captured error output
+    ///
+    /// The [captured](QhBuilder::capture_stderr) text is split into [`QhMessage`]s at the message codes
+    /// and the capture starts again empty.
+    /// Errors and warnings always start with their code,
+    /// use [`annotate_output`](QhBuilder::annotate_output) (`Ta`) to get the code of every message:
+    /// otherwise, the other messages are merged with the previous one.
+    ///
+    /// Returns an empty list if stderr is not captured in a temporary file: with a
+    /// [`message_handler`](QhBuilder::message_handler) the messages are delivered to the handler,
+    /// with a [writer](QhBuilder::stderr_writer) as text.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // 'Qbb' is meant for Delaunay triangulations
+    /// let mut qh = Qh::builder()
+    ///     .scale_last(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    ///
+    /// let messages = qh.messages();
+    /// let warning = messages.iter().find(|m| m.code == 7040).unwrap();
+    /// assert_eq!(warning.severity, Severity::Warning);
+    /// assert!(warning.text.starts_with("QH7040 qhull option warning"));
+    ///
+    /// // the messages have been taken
+    /// assert!(qh.messages().is_empty());
+    /// ```
+    pub fn messages(&mut self) -> Vec<QhMessage> {
+        self.take_stderr()
+            .ok()
+            .flatten()
+            .map_or_else(Vec::new, |text| message::parse_messages(&text))
     }
 
-    pub fn all_vertices_rev(&self) -> impl Iterator<Item = Vertex> {
-        let mut current = Vertex::from_ptr(
-            unsafe { sys::qh_get_vertex_tail(self.qh.get() as *mut _) },
-            self.dim,
-        );
+    /// Take the text that qhull printed to the captured output
+    ///
+    /// The capture starts again empty.
+    /// Returns `None` if stdout is not [captured](QhBuilder::capture_stdout) in a temporary file.
+    ///
+    /// # Errors
+    /// Returns an error if the captured text cannot be read.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .capture_stdout(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    ///
+    /// // print the summary to qhull's stdout
+    /// unsafe {
+    ///     Qh::try_on_qh_mut(&mut qh, |qh| sys::qh_printsummary(qh, (*qh).fout)).unwrap();
+    /// }
+    /// assert!(qh.take_stdout().unwrap().unwrap().contains("Number of vertices: 3"));
+    /// assert_eq!(qh.take_stdout().unwrap().unwrap(), "");
+    ///
+    /// qh.set_capture_stdout(false).unwrap();
+    /// assert_eq!(qh.take_stdout().unwrap(), None);
+    /// // taking does not start the capture
+    /// assert_eq!(qh.take_stdout().unwrap(), None);
+    /// ```
+    pub fn take_stdout(&mut self) -> Result<Option<String>, QhError<'static>> {
+        unsafe { self.buffers.get_mut().take_out_file(self.qh.get()) }
+            .map_err(|e| QhError::io_failed("failed to read the captured output", e))
+    }
 
-        std::iter::from_fn(move || current.take().map(|v| {
-            current = v.previous();
-            v
-        }))
+    /// Read the text that qhull printed to the captured output since the previous drain or take
+    ///
+    /// Unlike [`Qh::take_stdout`], the capture file is not replaced and qhull keeps writing to it,
+    /// so the output of successive calls on the instance can be followed as it grows.
+    /// The drained text is not returned again by [`Qh::take_stdout`].
+    /// Returns `None` if stdout is not [captured](QhBuilder::capture_stdout) in a file,
+    /// or if called while a qhull call is running on the instance (e.g. inside [`Qh::try_on_qh`]):
+    /// the output can only be drained between calls.
+    ///
+    /// # Errors
+    /// Returns an error if the captured text cannot be read.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .capture_stdout(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    /// let print_summary = |qh: &mut Qh| unsafe {
+    ///     Qh::try_on_qh_mut(qh, |qh| sys::qh_printsummary(qh, (*qh).fout)).unwrap();
+    /// };
+    ///
+    /// print_summary(&mut qh);
+    /// let first = qh.drain_stdout().unwrap().unwrap();
+    /// assert!(first.contains("Number of vertices: 3"));
+    /// assert_eq!(qh.drain_stdout().unwrap().unwrap(), "");
+    ///
+    /// // qhull writes after the drained text
+    /// print_summary(&mut qh);
+    /// print_summary(&mut qh);
+    /// assert_eq!(qh.drain_stdout().unwrap().unwrap(), first.repeat(2));
+    /// print_summary(&mut qh);
+    /// assert_eq!(qh.take_stdout().unwrap().unwrap(), first);
+    /// assert_eq!(qh.drain_stdout().unwrap().unwrap(), "");
+    ///
+    /// // nothing can be drained during a call
+    /// let during = unsafe { Qh::try_on_qh(&qh, |_| qh.drain_stdout()) }.unwrap();
+    /// assert_eq!(during.unwrap(), None);
+    /// ```
+    pub fn drain_stdout(&self) -> Result<Option<String>, QhError<'static>> {
+        let Ok(mut buffers) = self.buffers.try_borrow_mut() else {
+            return Ok(None);
+        };
+        buffers
+            .drain_out_file()
+            .map_err(|e| QhError::io_failed("failed to read the captured output", e))
     }
 
-    pub fn vertices(&self) -> impl Iterator<Item = Vertex> {
-        self.all_vertices().filter(|v| !v.is_sentinel())
+    /// Take the text that qhull printed to the captured error output
+    ///
+    /// The capture starts again empty, use [`Qh::messages`] to split the text into messages.
+    /// Returns `None` if stderr is not [captured](QhBuilder::capture_stderr) in a temporary file.
+    ///
+    /// # Errors
+    /// Returns an error if the captured text cannot be read.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// // 'Qbb' is meant for Delaunay triangulations
+    /// let mut qh = Qh::builder()
+    ///     .scale_last(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    ///
+    /// assert!(qh.take_stderr().unwrap().unwrap().contains("QH7040"));
+    /// assert_eq!(qh.take_stderr().unwrap().unwrap(), "");
+    /// ```
+    pub fn take_stderr(&mut self) -> Result<Option<String>, QhError<'static>> {
+        unsafe { self.buffers.get_mut().take_err_file(self.qh.get()) }
+            .map_err(|e| QhError::io_failed("failed to read the captured error output", e))
     }
 
-    /// Number of facets in the hull (sentinel excluded)
+    /// Start or stop capturing the output of qhull
+    ///
+    /// Same as [`QhBuilder::capture_stdout`], after the instance has been built.
+    /// When the capture stops, the text not yet [taken](Qh::take_stdout) is discarded.
+    /// Nothing changes if the output is sent to a [writer](QhBuilder::stdout_writer)
+    /// or a [message handler](QhBuilder::message_handler).
+    ///
+    /// # Errors
+    /// Returns an error if the temporary file cannot be created, the output is then unchanged.
+    pub fn set_capture_stdout(&mut self, capture: bool) -> Result<(), QhError<'static>> {
+        unsafe { self.buffers.get_mut().set_capture_stdout(self.qh.get(), capture) }
+            .map_err(|e| QhError::io_failed("failed to create temporary file for stdout", e))
+    }
+
+    /// Start or stop capturing the error output of qhull
+    ///
+    /// Same as [`QhBuilder::capture_stderr`], after the instance has been built.
+    /// When the capture stops, the text not yet [taken](Qh::take_stderr) is discarded,
+    /// and the errors have no [message](QhError::error_message) since qhull writes to the standard error.
+    /// Nothing changes if the error output is sent to a [writer](QhBuilder::stderr_writer)
+    /// or a [message handler](QhBuilder::message_handler).
+    ///
+    /// # Errors
+    /// Returns an error if the temporary file cannot be created, the error output is then unchanged.
     ///
     /// # Example
     /// ```
     /// # use qhull::*;
-    /// # let mut qh = Qh::builder()
-    /// #     .build_from_iter([
-    /// #         [0.0, 0.0],
-    /// #         [1.0, 0.0],
-    /// #         [0.0, 1.0],
-    /// #         [0.25, 0.25]
-    /// #    ]).unwrap();
-    /// assert_eq!(qh.num_facets(), qh.facets().count());
+    /// let mut qh = Qh::builder()
+    ///     .capture_stderr(false)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]])
+    ///     .unwrap();
+    /// assert_eq!(qh.take_stderr().unwrap(), None);
+    /// // taking does not start the capture
+    /// assert_eq!(qh.take_stderr().unwrap(), None);
+    ///
+    /// qh.set_capture_stderr(true).unwrap();
+    /// assert_eq!(qh.take_stderr().unwrap().unwrap(), "");
     /// ```
-    pub fn num_facets(&self) -> usize {
-        unsafe { sys::qh_get_num_facets(self.qh.get()) as _ }
+    pub fn set_capture_stderr(&mut self, capture: bool) -> Result<(), QhError<'static>> {
+        unsafe { self.buffers.get_mut().set_capture_stderr(self.qh.get(), capture) }
+            .map_err(|e| QhError::io_failed("failed to create temporary file for stderr", e))
     }
 
-    /// Number of vertices in the hull (sentinel excluded)
+    /// Human-readable summary of the hull
+    ///
+    /// This is the summary that qhull prints to stderr with
+    /// [`print_summary`](QhBuilder::print_summary) (`s` option of the qhull programs).
+    /// The summary is written to a temporary file by `qh_printsummary`,
+    /// while errors are still reported to the error stream of the instance.
     ///
     /// # Example
     /// ```
     /// # use qhull::*;
-    /// # let mut qh = Qh::builder()
-    /// #     .build_from_iter([
-    /// #         [0.0, 0.0],
-    /// #         [1.0, 0.0],
-    /// #         [0.0, 1.0],
-    /// #         [0.25, 0.25]
-    /// #    ]).unwrap();
-    /// assert_eq!(qh.num_vertices(), qh.vertices().count());
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let mut qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// let summary = qh.summary().unwrap();
+    /// assert!(summary.contains("Convex hull of 8 points in 3-d"));
+    /// assert!(summary.contains("Number of facets: 6"));
     /// ```
-    pub fn num_vertices(&self) -> usize {
-        unsafe { sys::qh_get_num_vertices(self.qh.get()) as _ }
+    pub fn summary(&mut self) -> Result<String, QhError<'_>> {
+        let out_file = TmpFile::new()
+            .map_err(|e| QhError::io_failed("failed to create temporary file for the summary", e))?;
+        let fp = out_file.file_handle();
+        unsafe { Qh::try_on_qh_mut(self, |qh| sys::qh_printsummary(qh, fp))? };
+        out_file
+            .read_as_string_and_close()
+            .map_err(|e| QhError::io_failed("failed to read the captured summary", e))
     }
 
-    pub fn simplices(&self) -> impl Iterator<Item = Facet> {
-        self.facets().filter(|f| f.simplicial())
+    /// Write the Geomview output of the hull
+    ///
+    /// This is the output of the `G` option of the qhull programs,
+    /// it is written to the writer while qhull produces it, as with [`QhBuilder::stdout_writer`].
+    /// The Geomview options of the builder are honored,
+    /// e.g. [`print_centrums`](QhBuilder::print_centrums) (`Gc`),
+    /// [`print_ridges`](QhBuilder::print_ridges) (`Gr`),
+    /// [`print_spheres`](QhBuilder::print_spheres) (`Gv`)
+    /// and [`drop_dim`](QhBuilder::drop_dim) (`GDn`) to project 4-d hulls to 3-d.
+    ///
+    /// Geomview output is only available for 2-d, 3-d and 4-d hulls,
+    /// qhull reports an error for the other dimensions.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("c D4").unwrap();
+    ///
+    /// let mut qh = Qh::builder().build_managed(dim, coords.clone()).unwrap();
+    /// let mut output = Vec::new();
+    /// qh.write_geomview(&mut output).unwrap();
+    /// assert!(output.starts_with(b"LIST"));
+    /// assert_eq!(String::from_utf8(output).unwrap(), qh.produce_output(PrintFormat::Geomview).unwrap());
+    ///
+    /// // drop the last coordinate to view the hull in 3-d
+    /// let mut qh = Qh::builder().drop_dim(3).build_managed(dim, coords).unwrap();
+    /// let mut output = Vec::new();
+    /// qh.write_geomview(&mut output).unwrap();
+    /// assert!(output.starts_with(b"{appearance {+edge -evert linewidth 2} LIST"));
+    /// ```
+    pub fn write_geomview(&mut self, mut writer: impl std::io::Write) -> Result<(), QhError<'_>> {
+        let qh = self.qh.get();
+        let fout = unsafe { sys::qhull_sys__writer_file(sys::QHULL_SYS_STDOUT_WRITER as _) };
+        let (result, written) = unsafe {
+            io_buffers::redirect_stdout(qh, &mut writer, || self.produce_output_to(PrintFormat::Geomview, fout))
+        };
+        result?;
+        written.and_then(|_| writer.flush()).map_err(QhError::write_failed)
+    }
+
+    /// Run `qh_produce_output` with the given format on a new temporary file
+    fn produce_output_file(&mut self, format: PrintFormat) -> Result<TmpFile, QhError<'_>> {
+        let out_file = TmpFile::new()
+            .map_err(|e| QhError::io_failed("failed to create temporary file for the output", e))?;
+        self.produce_output_to(format, out_file.file_handle())?;
+        Ok(out_file)
+    }
+
+    /// Run `qh_produce_output` with the given format, printing to `fout`
+    fn produce_output_to(&mut self, format: PrintFormat, fout: *mut sys::FILE) -> Result<(), QhError<'_>> {
+        let qh = self.qh.get_mut();
+        let previous_fout = std::mem::replace(&mut qh.fout, fout);
+        let previous_print_out = qh.PRINTout;
+        qh.PRINTout = [sys::qh_PRINT_qh_PRINTnone; sys::qh_PRINT_qh_PRINTEND as usize];
+        qh.PRINTout[0] = format.to_raw();
+
+        let result = unsafe {
+            QhError::try_on_raw(self.qh.get(), &mut self.buffers.borrow_mut(), |qh| {
+                sys::qh_produce_output(qh)
+            })
+        };
+
+        let qh = self.qh.get_mut();
+        qh.fout = previous_fout;
+        qh.PRINTout = previous_print_out;
+
+        result
     }
 
     /// Try a function on the qhull instance
@@ -202,6 +3539,7 @@ impl<'a> Qh<'a> {
     /// ```
     /// # use qhull::*;
     /// # let mut qh = Qh::builder()
+    /// #     .compute(false)
     /// #     .build_from_iter([
     /// #         [0.0, 0.0],
     /// #         [1.0, 0.0],
@@ -219,6 +3557,7 @@ impl<'a> Qh<'a> {
     /// ```
     /// # use qhull::*;
     /// # let mut qh = Qh::builder()
+    /// #     .compute(false)
     /// #     .build_from_iter([
     /// #         [0.0, 0.0],
     /// #         [1.0, 0.0],
@@ -240,20 +3579,30 @@ impl<'a> Qh<'a> {
         qh: &'b Qh,
         f: impl FnOnce(*const sys::qhT) -> R,
     ) -> Result<R, QhError<'b>> {
-        unsafe { QhError::try_on_raw(qh.qh.get(), &mut qh.buffers.borrow_mut().err_file, |p| f(p)) }
+        unsafe { QhError::try_on_raw(qh.qh.get(), &mut qh.buffers.borrow_mut(), |p| f(p)) }
     }
 
+    /// Try a function on the qhull instance, with mutable access
+    ///
+    /// See [`Qh::try_on_qh`] for more information.
+    ///
+    /// # Safety
+    /// This function is unsafe because it provides a way to access and possibly modify the qhull instance.
     pub unsafe fn try_on_qh_mut<'b, R>(
         qh: &'b mut Qh,
         f: impl FnOnce(*mut sys::qhT) -> R,
     ) -> Result<R, QhError<'b>> {
-        unsafe { QhError::try_on_raw(qh.qh.get(), &mut qh.buffers.borrow_mut().err_file, f) }
+        unsafe { QhError::try_on_raw(qh.qh.get(), &mut qh.buffers.borrow_mut(), f) }
     }
 
     /// Get the pointer to the raw qhT instance
     ///
     /// # Warning
     /// Prefer using the [`Qh::try_on_qh`] when calling a fallible qhull function.
+    ///
+    /// # Safety
+    /// * the pointer must not outlive the qhull instance
+    /// * the qhull instance must not be invalidated through the pointer
     pub unsafe fn raw_ptr(qh: &Qh) -> *const sys::qhT {
         qh.qh.get()
     }
@@ -262,19 +3611,85 @@ impl<'a> Qh<'a> {
     ///
     /// # Warning
     /// Prefer using the [`Qh::try_on_qh_mut`] when calling a fallible qhull function.
+    ///
+    /// # Safety
+    /// * the pointer must not outlive the qhull instance
+    /// * the qhull instance must not be invalidated through the pointer
     pub unsafe fn raw_ptr_mut(qh: &mut Qh) -> *mut sys::qhT {
         qh.qh.get_mut()
     }
 }
 
+/// A short summary of the instance
+///
+/// Only a few fields of qhull are read, the facets and vertices are not traversed.
+/// The counts are `None` if the hull has not been computed.
+/// `command` is the qhull command line, empty if the instance was configured with [`QhBuilder`].
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]];
+///
+/// let qh = Qh::builder().compute(false).build_from_iter(points).unwrap();
+/// assert_eq!(
+///     format!("{:?}", qh),
+///     "Qh { dim: 2, hull_dim: 2, num_points: 4, num_facets: None, num_vertices: None, \
+///     delaunay: false, voronoi: false, halfspace: false, command: \"qhull\" }",
+/// );
+///
+/// let qh = Qh::new_delaunay(points).unwrap();
+/// assert_eq!(
+///     format!("{:?}", qh),
+///     "Qh { dim: 2, hull_dim: 3, num_points: 4, num_facets: Some(4), num_vertices: Some(4), \
+///     delaunay: true, voronoi: false, halfspace: false, command: \"qhull d Qbb Qt Qc\" }",
+/// );
+/// ```
+impl<'a> std::fmt::Debug for Qh<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let qh = unsafe { &*self.qh.get() };
+        let computed = self.computed;
+        let command = unsafe { std::ffi::CStr::from_ptr(qh.qhull_command.as_ptr()) };
+        f.debug_struct("Qh")
+            .field("dim", &self.dim())
+            .field("hull_dim", &qh.hull_dim)
+            .field("num_points", &self.num_points())
+            .field("num_facets", &computed.then_some(qh.num_facets))
+            .field("num_vertices", &computed.then_some(qh.num_vertices))
+            .field("delaunay", &(qh.DELAUNAY != 0))
+            .field("voronoi", &(qh.VORONOI != 0))
+            .field("halfspace", &(qh.HALFspace != 0))
+            .field("command", &command.to_string_lossy())
+            .finish()
+    }
+}
+
 impl<'a> Drop for Qh<'a> {
     fn drop(&mut self) {
+        if self.is_poisoned() {
+            // the data structures may be inconsistent, leaking them is safer than freeing them
+            return;
+        }
         unsafe {
             sys::qh_freeqhull(self.qh.get_mut(), !sys::qh_ALL);
         }
     }
 }
 
+/// The vertex of each point (`qh_pointvertex`), see [`Qh::point_vertex_map`]
+struct PointVertices {
+    /// Ids of the next facet and vertex, and number of vertices, when the map was built
+    state: (u32, u32, i32),
+    vertices: Vec<*mut sys::vertexT>,
+}
+
+impl PointVertices {
+    /// Changes when facets or vertices are created or deleted
+    fn state(qh: &sys::qhT) -> (u32, u32, i32) {
+        (qh.facet_id as _, qh.vertex_id as _, qh.num_vertices as _)
+    }
+}
+
 #[derive(Default)]
 #[allow(unused)]
 struct OwnedValues {
@@ -285,7 +3700,9 @@ struct OwnedValues {
     lower_threshold: Option<Rc<Vec<f64>>>,
     upper_bound: Option<Rc<Vec<f64>>>,
     lower_bound: Option<Rc<Vec<f64>>>,
-    feasible_point: Option<Rc<Vec<f64>>>,
-    feasible_string: Option<Rc<Vec<i8>>>,
     near_zero: Option<Rc<Vec<f64>>>,
+    input_rotation: Option<Rc<Vec<Vec<f64>>>>,
+    scale_bounds: Vec<scaling::ScaleBound>,
+    /// Number of coordinates given with [`QhBuilder::feasible_point`], checked against the hull dimension
+    feasible_point_len: Option<usize>,
 }
@@ -0,0 +1,27 @@
+/// Summary of the facet merges of a hull
+///
+/// See [`Qh::merge_report`](crate::Qh::merge_report).
+///
+/// # Remarks
+/// Qhull clears the merge flags of a facet (`mergeridge`, `mergehorizon`, ...) once the facet has been processed,
+/// so the flag counts are usually zero for a completed hull.
+/// They are mainly useful when inspecting a hull whose construction failed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeReport {
+    /// Number of facets that are the result of at least one merge
+    pub merged_facets: usize,
+    /// Total number of merges of the facets, see [`Facet::num_merge`](crate::Facet::num_merge)
+    pub total_merges: usize,
+    /// Number of facets with the `mergeridge` flag (merged because of a duplicate ridge)
+    pub merge_ridge: usize,
+    /// Number of facets with the `mergehorizon` flag (new facet coplanar with its horizon facet)
+    pub merge_horizon: usize,
+    /// Number of facets with the `coplanarhorizon` flag (horizon facet coplanar with a new point)
+    pub coplanar_horizon: usize,
+    /// Number of facets with the `degenerate` flag (fewer than `dim` neighbors)
+    pub degenerate: usize,
+    /// Number of facets with the `redundant` flag (vertices included in a neighbor)
+    pub redundant: usize,
+    /// Ids of the facets merged at least `threshold` times
+    pub over_threshold: Vec<u32>,
+}
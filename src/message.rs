@@ -0,0 +1,111 @@
+use crate::sys;
+
+/// A message printed by qhull, see [`QhBuilder::message_handler`](crate::QhBuilder::message_handler)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QhMessage {
+    /// The qhull message code, e.g. `6154` for `QH6154`
+    pub code: u32,
+    /// Severity of the message, derived from the code
+    pub severity: Severity,
+    /// The text as printed by qhull
+    ///
+    /// Errors and warnings start with their code (e.g. `QH6154 `),
+    /// a message can be a fragment of a line: qhull prints some lines with several calls.
+    pub text: String,
+}
+
+/// Severity of a [`QhMessage`], from the ranges of the message codes (`MSG_*` in `user_r.h`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Trace and memory messages, `0..6000`
+    Trace,
+    /// Output (`9000..10000`), printed to stdout
+    Output,
+    /// Informational messages such as the summary and the statistics, `8000..9000` and `11000..`
+    Info,
+    /// Warnings, `7000..8000`
+    Warning,
+    /// Errors, `6000..7000` and qhull internal errors `10000..11000`
+    Error,
+}
+
+impl Severity {
+    /// Severity of a qhull message code
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// assert_eq!(Severity::from_code(1001), Severity::Trace);
+    /// assert_eq!(Severity::from_code(6154), Severity::Error);
+    /// assert_eq!(Severity::from_code(7088), Severity::Warning);
+    /// assert_eq!(Severity::from_code(8200), Severity::Info);
+    /// assert_eq!(Severity::from_code(9001), Severity::Output);
+    /// assert_eq!(Severity::from_code(10071), Severity::Error);
+    /// ```
+    pub fn from_code(code: u32) -> Self {
+        match code {
+            0..sys::MSG_ERROR => Severity::Trace,
+            sys::MSG_ERROR..sys::MSG_WARNING => Severity::Error,
+            sys::MSG_WARNING..sys::MSG_STDERR => Severity::Warning,
+            sys::MSG_STDERR..sys::MSG_OUTPUT => Severity::Info,
+            sys::MSG_OUTPUT..sys::MSG_QHULL_ERROR => Severity::Output,
+            sys::MSG_QHULL_ERROR..sys::MSG_FIX => Severity::Error,
+            _ => Severity::Info,
+        }
+    }
+}
+
+impl QhMessage {
+    pub(crate) fn new(code: u32, text: &[u8]) -> Self {
+        Self {
+            code,
+            severity: Severity::from_code(code),
+            text: String::from_utf8_lossy(text).into_owned(),
+        }
+    }
+}
+
+/// Split the text printed by qhull into messages
+///
+/// A message starts at each `[QHnnnn]` code (annotated output, `Ta`) and at each line starting with `QHnnnn `
+/// (errors and warnings are always prefixed with their code).
+/// The text before the first code is a message with code `0`.
+pub(crate) fn parse_messages(text: &str) -> Vec<QhMessage> {
+    /// Length of the code marker at the start of `text` and the code
+    fn marker(text: &str, line_start: bool) -> Option<(usize, u32)> {
+        let (rest, annotated) = match text.strip_prefix("[QH") {
+            Some(rest) => (rest, true),
+            None if line_start => (text.strip_prefix("QH")?, false),
+            None => return None,
+        };
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let terminator = if annotated { ']' } else { ' ' };
+        if digits < 4 || !rest[digits..].starts_with(terminator) {
+            return None;
+        }
+        let code = rest[..digits].parse().ok()?;
+        Some((text.len() - rest.len() + digits + 1, code))
+    }
+
+    let mut messages = Vec::new();
+    let mut start = 0;
+    let mut code = 0;
+    let mut position = 0;
+    while position < text.len() {
+        let line_start = position == 0 || text.as_bytes()[position - 1] == b'\n';
+        if let Some((len, next_code)) = marker(&text[position..], line_start) {
+            if position > start {
+                messages.push(QhMessage::new(code, &text.as_bytes()[start..position]));
+            }
+            start = position;
+            code = next_code;
+            position += len;
+        } else {
+            position += text[position..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    if text.len() > start {
+        messages.push(QhMessage::new(code, &text.as_bytes()[start..]));
+    }
+    messages
+}
@@ -0,0 +1,113 @@
+//! Conversions from and to the [`mint`] crate
+
+use crate::{Facet, Qh, QhBuilder, QhError, Vertex};
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for mint::Point2<f64> {}
+    impl Sealed for mint::Point3<f64> {}
+}
+
+/// Points of the [`mint`] crate accepted by [`QhBuilder::build_from_mint`]
+///
+/// This trait is sealed, it is implemented for [`mint::Point2<f64>`] and [`mint::Point3<f64>`],
+/// which are `repr(C)` structs of contiguous coordinates.
+///
+/// Requires the `mint` feature.
+pub trait MintPoint: sealed::Sealed {
+    /// Number of coordinates of the point
+    const DIM: usize;
+}
+
+impl MintPoint for mint::Point2<f64> {
+    const DIM: usize = 2;
+}
+
+impl MintPoint for mint::Point3<f64> {
+    const DIM: usize = 3;
+}
+
+impl QhBuilder {
+    /// Build a Qhull instance from [`mint`] points
+    ///
+    /// The slice is read as flat coordinates without copying it, see [`QhBuilder::build_from_slice`]
+    /// for the options that still force a copy.
+    ///
+    /// Requires the `mint` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [
+    ///     mint::Point2 { x: 0.0, y: 0.0 },
+    ///     mint::Point2 { x: 1.0, y: 0.0 },
+    ///     mint::Point2 { x: 0.0, y: 1.0 },
+    ///     mint::Point2 { x: 0.25, y: 0.25 },
+    /// ];
+    /// let qh = Qh::builder().build_from_mint(&points).unwrap();
+    /// assert_eq!(qh.num_vertices(), 3);
+    /// // the points are used directly
+    /// assert_eq!(qh.point(0).unwrap().as_ptr(), &points[0].x as *const f64);
+    ///
+    /// for vertex in qh.vertices() {
+    ///     let point = vertex.to_mint_point2().unwrap();
+    ///     assert_eq!(point, points[vertex.index(&qh).unwrap()]);
+    ///     assert_eq!(vertex.to_mint_point3(), None);
+    /// }
+    /// assert!(qh.facets().all(|f| f.normal_mint().is_none()));
+    ///
+    /// let points = [
+    ///     mint::Point3 { x: 0.0, y: 0.0, z: 0.0 },
+    ///     mint::Point3 { x: 1.0, y: 0.0, z: 0.0 },
+    ///     mint::Point3 { x: 0.0, y: 1.0, z: 0.0 },
+    ///     mint::Point3 { x: 0.0, y: 0.0, z: 1.0 },
+    /// ];
+    /// let qh = Qh::builder().build_from_mint(&points).unwrap();
+    /// assert_eq!(qh.num_facets(), 4);
+    /// for vertex in qh.vertices() {
+    ///     assert_eq!(vertex.to_mint_point3().unwrap(), points[vertex.index(&qh).unwrap()]);
+    /// }
+    /// let bottom = qh
+    ///     .facets()
+    ///     .filter_map(|f| f.normal_mint())
+    ///     .find(|n| n.z < -0.5)
+    ///     .unwrap();
+    /// assert_eq!(bottom, mint::Vector3 { x: 0.0, y: 0.0, z: -1.0 });
+    /// ```
+    pub fn build_from_mint<P: MintPoint>(self, points: &[P]) -> Result<Qh<'_>, QhError<'static>> {
+        // the points are repr(C) structs of `P::DIM` f64
+        let coords = unsafe { std::slice::from_raw_parts(points.as_ptr() as *const f64, points.len() * P::DIM) };
+        self.build_from_slice(P::DIM, coords)
+    }
+}
+
+impl Vertex<'_> {
+    /// The point of the vertex as a [`mint::Point2`], `None` if it does not have 2 coordinates
+    ///
+    /// Requires the `mint` feature.
+    pub fn to_mint_point2(&self) -> Option<mint::Point2<f64>> {
+        let point: [f64; 2] = self.point()?.try_into().ok()?;
+        Some(point.into())
+    }
+
+    /// The point of the vertex as a [`mint::Point3`], `None` if it does not have 3 coordinates
+    ///
+    /// The vertices of a 2D Delaunay triangulation have 3 coordinates, the last one is the lifted coordinate.
+    ///
+    /// Requires the `mint` feature.
+    pub fn to_mint_point3(&self) -> Option<mint::Point3<f64>> {
+        let point: [f64; 3] = self.point()?.try_into().ok()?;
+        Some(point.into())
+    }
+}
+
+impl Facet<'_> {
+    /// The normal of the facet as a [`mint::Vector3`], `None` if the hull is not 3-dimensional
+    ///
+    /// See [`Facet::normal`].
+    ///
+    /// Requires the `mint` feature.
+    pub fn normal_mint(&self) -> Option<mint::Vector3<f64>> {
+        self.normal_array::<3>().map(Into::into)
+    }
+}
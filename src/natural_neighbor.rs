@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{Qh, QhError};
+
+/// Relative tolerance of the geometric predicates
+const EPS: f64 = 1e-12;
+
+/// A 2D Delaunay triangulation prepared for natural neighbor (Sibson) interpolation
+///
+/// See [`Qh::natural_neighbor_weights`](crate::Qh::natural_neighbor_weights).
+/// The weight of a site is the area that its Voronoi cell would lose to the query point
+/// if the point was inserted, divided by the area of the new cell of the query point.
+/// The point is not inserted: the triangles whose circumcircles contain it
+/// (the cavity of the Bowyer-Watson algorithm) are enough to compute the areas.
+pub(crate) struct NaturalNeighbors {
+    /// Coordinates of the sites
+    sites: Vec<[f64; 2]>,
+    /// Index in the input of each site
+    indices: Vec<usize>,
+    /// Triangles, as counterclockwise sites
+    triangles: Vec<[usize; 3]>,
+    /// Circumcenter and squared circumradius of each triangle
+    circles: Vec<([f64; 2], f64)>,
+    /// Triangle of each directed edge, counterclockwise in the triangle
+    edges: HashMap<(usize, usize), usize>,
+}
+
+impl NaturalNeighbors {
+    /// Collect the triangles of a 2D Delaunay triangulation
+    pub(crate) fn new<'a>(qh: &'a Qh) -> Result<Self, QhError<'a>> {
+        let delaunay = unsafe { (*qh.qh.get()).DELAUNAY != 0 };
+        if !delaunay || qh.dim() != 2 {
+            return Err(QhError::invalid_input(format!(
+                "natural neighbor interpolation is only available for 2-d Delaunay triangulations, not for {}-d {}",
+                qh.dim(),
+                if delaunay { "Delaunay triangulations" } else { "hulls" },
+            )));
+        }
+        let mut this = Self {
+            sites: Vec::new(),
+            indices: Vec::new(),
+            triangles: Vec::new(),
+            circles: Vec::new(),
+            edges: HashMap::new(),
+        };
+        let mut site_of_vertex: HashMap<u32, usize> = HashMap::new();
+        for facet in qh.lower_facets() {
+            let polygon: Vec<usize> = facet
+                .ordered_vertices(qh)?
+                .into_iter()
+                .map(|vertex| {
+                    *site_of_vertex.entry(vertex.id()).or_insert_with(|| {
+                        let point = vertex.point().expect("vertices have a point");
+                        this.sites.push([point[0], point[1]]);
+                        this.indices.push(vertex.index(qh).expect("Delaunay vertex is not an input point"));
+                        this.sites.len() - 1
+                    })
+                })
+                .collect();
+            // the sites of a non-simplicial facet are cocircular, any triangulation is a Delaunay triangulation
+            for i in 1..polygon.len() - 1 {
+                let mut triangle = [polygon[0], polygon[i], polygon[i + 1]];
+                let [a, b, c] = triangle.map(|site| this.sites[site]);
+                if orient(a, b, c) < 0.0 {
+                    triangle.swap(1, 2);
+                }
+                let index = this.triangles.len();
+                for i in 0..3 {
+                    this.edges.insert((triangle[i], triangle[(i + 1) % 3]), index);
+                }
+                this.circles.push(circumcircle(a, b, c));
+                this.triangles.push(triangle);
+            }
+        }
+        Ok(this)
+    }
+
+    /// Natural neighbor weights of a point, as pairs of input index and weight
+    ///
+    /// Returns `None` if the point is outside of the triangulation.
+    pub(crate) fn weights(&self, q: [f64; 2]) -> Option<Vec<(usize, f64)>> {
+        if let Some(site) = self.sites.iter().position(|&p| p == q) {
+            return Some(vec![(self.indices[site], 1.0)]);
+        }
+
+        // the triangle containing the point, or the hull edge it lies on
+        let mut start = None;
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            let mut inside = true;
+            for i in 0..3 {
+                let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+                let (pa, pb) = (self.sites[a], self.sites[b]);
+                let side = orient(pa, pb, q);
+                let tolerance = EPS * dist2(pa, pb);
+                if side < -tolerance {
+                    inside = false;
+                    break;
+                }
+                if side <= tolerance && !self.edges.contains_key(&(b, a)) {
+                    // on the hull the Voronoi cell of the point would be unbounded, interpolate along the edge
+                    let t = ((q[0] - pa[0]) * (pb[0] - pa[0]) + (q[1] - pa[1]) * (pb[1] - pa[1])) / dist2(pa, pb);
+                    if (0.0..=1.0).contains(&t) {
+                        return Some(vec![(self.indices[a], 1.0 - t), (self.indices[b], t)]);
+                    }
+                }
+            }
+            if inside {
+                start = Some(index);
+                break;
+            }
+        }
+        let start = start?;
+
+        // the cavity: the triangles whose circumcircle contains the point, connected to the first one
+        let in_circle = |triangle: usize| {
+            let (center, radius2) = self.circles[triangle];
+            dist2(center, q) < radius2
+        };
+        let mut cavity = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(triangle) = queue.pop_front() {
+            let t = self.triangles[triangle];
+            for i in 0..3 {
+                if let Some(&neighbor) = self.edges.get(&(t[(i + 1) % 3], t[i])) {
+                    if !cavity.contains(&neighbor) && in_circle(neighbor) {
+                        cavity.insert(neighbor);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        // the boundary of the cavity, counterclockwise
+        let mut next: HashMap<usize, usize> = HashMap::new();
+        let mut previous: HashMap<usize, usize> = HashMap::new();
+        for &triangle in &cavity {
+            let t = self.triangles[triangle];
+            for i in 0..3 {
+                let (a, b) = (t[i], t[(i + 1) % 3]);
+                let inner = self.edges.get(&(b, a)).is_some_and(|n| cavity.contains(n));
+                if !inner {
+                    next.insert(a, b);
+                    previous.insert(b, a);
+                }
+            }
+        }
+
+        // the area stolen from each site is bounded by the new Voronoi edge between the point and the site
+        // and by the old Voronoi edges of the site, around the vertices of the cavity
+        let mut weights: Vec<(usize, f64)> = next
+            .iter()
+            .map(|(&site, &next)| {
+                let previous = previous[&site];
+                let p = self.sites[site];
+                let mut polygon = vec![circumcircle(q, p, self.sites[next]).0];
+                let mut w = next;
+                while w != previous {
+                    let triangle = self.edges[&(site, w)];
+                    polygon.push(self.circles[triangle].0);
+                    let t = self.triangles[triangle];
+                    w = t[(t.iter().position(|&v| v == w).unwrap() + 1) % 3];
+                }
+                polygon.push(circumcircle(q, self.sites[previous], p).0);
+                (self.indices[site], area(&polygon))
+            })
+            .collect();
+        let total: f64 = weights.iter().map(|(_, area)| area).sum();
+        for (_, weight) in &mut weights {
+            *weight /= total;
+        }
+        weights.sort_unstable_by_key(|(index, _)| *index);
+        Some(weights)
+    }
+}
+
+/// Twice the signed area of the triangle `abc`, positive if counterclockwise
+fn orient(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+fn dist2(a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)
+}
+
+/// Center and squared radius of the circle through three points
+fn circumcircle(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> ([f64; 2], f64) {
+    let (bx, by) = (b[0] - a[0], b[1] - a[1]);
+    let (cx, cy) = (c[0] - a[0], c[1] - a[1]);
+    let d = 2.0 * (bx * cy - by * cx);
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    let center = [(cy * b2 - by * c2) / d, (bx * c2 - cx * b2) / d];
+    let radius2 = center[0] * center[0] + center[1] * center[1];
+    ([a[0] + center[0], a[1] + center[1]], radius2)
+}
+
+/// Signed area of a polygon, positive if counterclockwise
+fn area(polygon: &[[f64; 2]]) -> f64 {
+    let n = polygon.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+            a[0] * b[1] - a[1] * b[0]
+        })
+        .sum::<f64>()
+        / 2.0
+}
@@ -0,0 +1,134 @@
+use crate::{AxisScaling, QhError};
+
+/// A copy of a hull that does not depend on the [`Qh`](crate::Qh) instance
+///
+/// See [`Qh::to_owned`](crate::Qh::to_owned).
+/// The snapshot only contains plain data: it can outlive the instance and the points it was built from,
+/// and it can be sent to other threads.
+/// The facets and the vertices are in the order of [`Qh::facets`](crate::Qh::facets)
+/// and [`Qh::vertices`](crate::Qh::vertices), they refer to each other by their position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedHull {
+    /// Dimension of the input points, see [`Qh::dim`](crate::Qh::dim)
+    pub dim: usize,
+    /// Dimension of the hull, see [`Qh::hull_dim`](crate::Qh::hull_dim)
+    pub hull_dim: usize,
+    /// Whether the hull is a Delaunay triangulation
+    pub delaunay: bool,
+    /// Whether the hull is a halfspace intersection
+    pub halfspace: bool,
+    /// The vertices of the hull
+    pub vertices: Vec<OwnedVertex>,
+    /// The facets of the hull
+    pub facets: Vec<OwnedFacet>,
+    /// Roundoff error for distances (`qh.DISTround`), the tolerance of [`OwnedHull::contains`]
+    pub dist_round: f64,
+    /// Scaling of the coordinates of the query points, as in the instance
+    pub(crate) input_scaling: Vec<AxisScaling>,
+    /// Rotation of the query points, as in the instance
+    pub(crate) input_rotation: Option<Vec<Vec<f64>>>,
+}
+
+/// A vertex of an [`OwnedHull`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedVertex {
+    /// Id of the vertex in qhull, see [`Vertex::id`](crate::Vertex::id)
+    pub id: u32,
+    /// Index of the input point, see [`Vertex::index`](crate::Vertex::index)
+    pub index: Option<usize>,
+    /// Coordinates of the vertex, see [`Vertex::point`](crate::Vertex::point)
+    ///
+    /// For a Delaunay triangulation, the point is lifted and has [`hull_dim`](OwnedHull::hull_dim) coordinates.
+    pub point: Vec<f64>,
+}
+
+/// A facet of an [`OwnedHull`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedFacet {
+    /// Id of the facet in qhull, see [`Facet::id`](crate::Facet::id)
+    pub id: u32,
+    /// Positions of the vertices in [`OwnedHull::vertices`], in the order of qhull
+    pub vertices: Vec<usize>,
+    /// Positions of the neighboring facets in [`OwnedHull::facets`]
+    pub neighbors: Vec<usize>,
+    /// Unit normal of the hyperplane, pointing outwards, see [`Facet::normal`](crate::Facet::normal)
+    pub normal: Vec<f64>,
+    /// Offset of the hyperplane, see [`Facet::offset`](crate::Facet::offset)
+    pub offset: f64,
+    /// See [`Facet::simplicial`](crate::Facet::simplicial)
+    pub simplicial: bool,
+    /// See [`Facet::upper_delaunay`](crate::Facet::upper_delaunay)
+    pub upper_delaunay: bool,
+    /// See [`Facet::good`](crate::Facet::good)
+    pub good: bool,
+    /// See [`Facet::top_orient`](crate::Facet::top_orient)
+    pub top_orient: bool,
+}
+
+impl OwnedHull {
+    /// Hyperplane of each facet, as normal and offset
+    ///
+    /// A point `x` is below the facet if `normal · x + offset < 0`.
+    pub fn equations(&self) -> impl Iterator<Item = (&[f64], f64)> + '_ {
+        self.facets.iter().map(|f| (&f.normal[..], f.offset))
+    }
+
+    /// Check whether a point is inside the hull, see [`Qh::contains`](crate::Qh::contains)
+    pub fn contains(&self, point: &[f64]) -> Result<bool, QhError<'static>> {
+        if self.delaunay || self.halfspace {
+            return Err(QhError::invalid_input(format!(
+                "containment is only available for convex hulls, not for {}",
+                if self.delaunay { "Delaunay triangulations" } else { "halfspace intersections" },
+            )));
+        }
+        if point.len() != self.dim {
+            return Err(QhError::invalid_input(format!(
+                "query point has dimension {}, expected {}",
+                point.len(),
+                self.dim,
+            )));
+        }
+        let mut point = point.to_vec();
+        for (x, scaling) in point.iter_mut().zip(&self.input_scaling) {
+            *x = scaling.apply(*x);
+        }
+        if let Some(rotation) = &self.input_rotation {
+            point = rotation
+                .iter()
+                .map(|row| row.iter().zip(&point).map(|(a, b)| a * b).sum())
+                .collect();
+        }
+        Ok(self.equations().all(|(normal, offset)| {
+            let dist = offset + normal.iter().zip(&point).map(|(n, x)| n * x).sum::<f64>();
+            dist <= self.dist_round
+        }))
+    }
+
+    /// Indices of the input points of each simplex, see [`Qh::simplex_indices`](crate::Qh::simplex_indices)
+    ///
+    /// As for the instance, the simplices with a vertex that is not an input point
+    /// (the point at infinity, see [`QhBuilder::at_infinity`](crate::QhBuilder::at_infinity)) are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .at_infinity(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.5, 0.25]])
+    ///     .unwrap();
+    /// qh.triangulate().unwrap();
+    ///
+    /// let owned = qh.to_owned();
+    /// assert_eq!(owned.simplex_indices(), qh.simplex_indices());
+    /// assert!(owned.simplex_indices().iter().flatten().all(|&i| i < 5));
+    /// ```
+    pub fn simplex_indices(&self) -> Vec<Vec<usize>> {
+        self.facets
+            .iter()
+            .filter(|f| f.simplicial && !f.upper_delaunay)
+            .filter_map(|f| f.vertices.iter().map(|&v| self.vertices[v].index).collect())
+            .collect()
+    }
+}
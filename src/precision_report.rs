@@ -0,0 +1,89 @@
+use crate::{sys, QhMessage};
+
+/// Codes of the precision errors and warnings of qhull (`qhull precision error`, `qhull precision warning`)
+const PRECISION_CODES: &[u32] = &[
+    6012, 6109, 6110, 6111, 6112, 6113, 6114, 6115, 6116, 6117, 6118, 6136, 6154, 6239, 6240, 6297, 6298, 6347, 6348,
+    6354, 6379, 6380, 6417, 6418, 6422, 7062, 7081, 7082, 7083, 7085, 7086, 7087, 7088,
+];
+
+/// Precision problems of a hull
+///
+/// See [`Qh::precision_report`](crate::Qh::precision_report).
+/// The counters are the "precision problems" statistics of qhull (printed with `Ts`),
+/// they are collected even without the statistics option.
+/// Qhull corrects these problems by merging facets (unless `Q0`): a hull with problems is still valid,
+/// but its facets are wider than the roundoff error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrecisionReport {
+    /// Maximum distance of a point above a facet (`qh.max_outside`)
+    pub max_outside: f64,
+    /// Maximum distance of a vertex below a facet, negative (`qh.min_vertex`)
+    pub min_vertex: f64,
+    /// Number of horizon facets coplanar with a new point
+    ///
+    /// Exactly coplanar input, such as the faces of a cube, is merged this way without making
+    /// the facets wider: [`had_precision_warnings`](PrecisionReport::had_precision_warnings) only
+    /// considers these merges when `max_outside` or `min_vertex` is not zero.
+    pub coplanar_horizon_facets: usize,
+    /// Number of points coplanar with a facet while partitioning the points
+    ///
+    /// Not a precision problem by itself, as the points on the facets of the hull are counted:
+    /// it is not considered by [`had_precision_warnings`](PrecisionReport::had_precision_warnings).
+    pub coplanar_points: usize,
+    /// Number of flipped facets (the interior point is above the facet)
+    pub flipped_facets: usize,
+    /// Number of coplanar and concave half ridges in the output
+    pub nonconvex_ridges: usize,
+    /// Number of nearly singular hyperplanes and zero divisors while computing hyperplanes
+    pub singular_hyperplanes: usize,
+    /// The precision errors and warnings captured in the error output
+    ///
+    /// The messages are selected by their code: the ones printed as `qhull precision error`
+    /// or `qhull precision warning` by qhull.
+    pub warnings: Vec<QhMessage>,
+    /// Whether qhull reported any precision problem, as a counter or as a warning
+    pub had_precision_warnings: bool,
+    /// Ids of the facets of the hull that are flipped or mentioned by a precision warning
+    pub facets: Vec<u32>,
+}
+
+impl PrecisionReport {
+    /// Read the counters of a qhull instance and combine them with the captured messages
+    ///
+    /// # Safety
+    /// `qh` must point to a valid instance
+    pub(crate) unsafe fn from_raw(qh: *const sys::qhT, messages: Vec<QhMessage>) -> Self {
+        let qh = &*qh;
+        let counter = |id: sys::qh_statistics| qh.qhstat.stats[id as usize].i.max(0) as usize;
+        let warnings: Vec<_> = messages
+            .into_iter()
+            .filter(|m| PRECISION_CODES.contains(&m.code))
+            .collect();
+        let mut report = Self {
+            max_outside: qh.max_outside,
+            min_vertex: qh.min_vertex,
+            coplanar_horizon_facets: counter(sys::qh_statistics_Zcoplanarhorizon),
+            coplanar_points: counter(sys::qh_statistics_Zcoplanarpart),
+            flipped_facets: counter(sys::qh_statistics_Zflippedfacets),
+            nonconvex_ridges: counter(sys::qh_statistics_Zcoplanarridges) + counter(sys::qh_statistics_Zconcaveridges),
+            singular_hyperplanes: counter(sys::qh_statistics_Znearlysingular)
+                + counter(sys::qh_statistics_Zback0)
+                + counter(sys::qh_statistics_Zgauss0),
+            had_precision_warnings: false,
+            warnings,
+            facets: Vec::new(),
+        };
+        report.had_precision_warnings = !report.warnings.is_empty()
+            || (report.coplanar_horizon_facets > 0 && (report.max_outside > 0.0 || report.min_vertex < 0.0))
+            || report.flipped_facets > 0
+            || report.nonconvex_ridges > 0
+            || report.singular_hyperplanes > 0;
+        report
+    }
+}
+
+/// Ids of the facets mentioned in a message (`f123`)
+pub(crate) fn mentioned_facets(text: &str) -> impl Iterator<Item = u32> + '_ {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter_map(|word| word.strip_prefix('f')?.parse().ok())
+}
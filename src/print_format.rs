@@ -0,0 +1,115 @@
+use crate::sys;
+
+/// An output format of qhull
+///
+/// Each variant corresponds to one of the `qh_PRINT*` constants
+/// and to an option of the qhull programs, given in the documentation of the variant.
+/// See the [qhull documentation](http://www.qhull.org/html/qh-optf.htm) for a description of the formats.
+///
+/// Output in these formats can be obtained with [`Qh::produce_output`](crate::Qh::produce_output).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrintFormat {
+    /// `Fa`: area of each facet
+    Area,
+    /// `FV`: average of the vertices
+    Average,
+    /// `Fc`: coplanar points of each facet
+    Coplanars,
+    /// `FC`: centrum or Voronoi center of each facet
+    Centrums,
+    /// `f`: all the fields of the facets
+    Facets,
+    /// `FF`: all the fields of the facets, without the ridges
+    FacetsXRidge,
+    /// `G`: Geomview output
+    Geomview,
+    /// `FI`: facet ids
+    Ids,
+    /// `Fi`: inner planes of the facets
+    Inner,
+    /// `Fn`: neighboring facets of each facet
+    Neighbors,
+    /// `n`: facet normals with offsets
+    Normals,
+    /// `Fo`: outer planes of the facets
+    Outer,
+    /// `FM`: Maple output
+    Maple,
+    /// `i`: vertices incident to each facet
+    Incidences,
+    /// `m`: Mathematica output
+    Mathematica,
+    /// `Fm`: merge count of each facet
+    Merges,
+    /// `o`: OFF file format
+    Off,
+    /// `FO`: options and precision constants
+    Options,
+    /// `Fp`: intersection points of halfspaces
+    PointIntersect,
+    /// `FP`: nearest vertex of each coplanar point
+    PointNearest,
+    /// `p`: vertex coordinates (or Voronoi centers)
+    Points,
+    /// `FQ`: the qhull command and the input
+    Qhull,
+    /// `FS`: total area and volume
+    Size,
+    /// `Fs`: summary of the hull
+    Summary,
+    /// `Ft`: triangulation with added centrums
+    Triangles,
+    /// `Fv`: vertices of each facet
+    Vertices,
+    /// `FN`: neighboring facets of each vertex
+    VertexNeighbors,
+    /// `Fx`: extreme points, i.e. the vertices of the hull
+    Extremes,
+}
+
+impl PrintFormat {
+    /// The `qh_PRINT*` constant of the format
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// assert_eq!(PrintFormat::Off.to_raw(), sys::qh_PRINT_qh_PRINToff);
+    /// ```
+    pub fn to_raw(self) -> sys::qh_PRINT {
+        match self {
+            PrintFormat::Area => sys::qh_PRINT_qh_PRINTarea,
+            PrintFormat::Average => sys::qh_PRINT_qh_PRINTaverage,
+            PrintFormat::Coplanars => sys::qh_PRINT_qh_PRINTcoplanars,
+            PrintFormat::Centrums => sys::qh_PRINT_qh_PRINTcentrums,
+            PrintFormat::Facets => sys::qh_PRINT_qh_PRINTfacets,
+            PrintFormat::FacetsXRidge => sys::qh_PRINT_qh_PRINTfacets_xridge,
+            PrintFormat::Geomview => sys::qh_PRINT_qh_PRINTgeom,
+            PrintFormat::Ids => sys::qh_PRINT_qh_PRINTids,
+            PrintFormat::Inner => sys::qh_PRINT_qh_PRINTinner,
+            PrintFormat::Neighbors => sys::qh_PRINT_qh_PRINTneighbors,
+            PrintFormat::Normals => sys::qh_PRINT_qh_PRINTnormals,
+            PrintFormat::Outer => sys::qh_PRINT_qh_PRINTouter,
+            PrintFormat::Maple => sys::qh_PRINT_qh_PRINTmaple,
+            PrintFormat::Incidences => sys::qh_PRINT_qh_PRINTincidences,
+            PrintFormat::Mathematica => sys::qh_PRINT_qh_PRINTmathematica,
+            PrintFormat::Merges => sys::qh_PRINT_qh_PRINTmerges,
+            PrintFormat::Off => sys::qh_PRINT_qh_PRINToff,
+            PrintFormat::Options => sys::qh_PRINT_qh_PRINToptions,
+            PrintFormat::PointIntersect => sys::qh_PRINT_qh_PRINTpointintersect,
+            PrintFormat::PointNearest => sys::qh_PRINT_qh_PRINTpointnearest,
+            PrintFormat::Points => sys::qh_PRINT_qh_PRINTpoints,
+            PrintFormat::Qhull => sys::qh_PRINT_qh_PRINTqhull,
+            PrintFormat::Size => sys::qh_PRINT_qh_PRINTsize,
+            PrintFormat::Summary => sys::qh_PRINT_qh_PRINTsummary,
+            PrintFormat::Triangles => sys::qh_PRINT_qh_PRINTtriangles,
+            PrintFormat::Vertices => sys::qh_PRINT_qh_PRINTvertices,
+            PrintFormat::VertexNeighbors => sys::qh_PRINT_qh_PRINTvneighbors,
+            PrintFormat::Extremes => sys::qh_PRINT_qh_PRINTextremes,
+        }
+    }
+
+    /// Whether the format needs the facet areas (`qh.GETarea`)
+    pub(crate) fn needs_area(self) -> bool {
+        matches!(self, PrintFormat::Area | PrintFormat::Size)
+    }
+}
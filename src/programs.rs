@@ -0,0 +1,173 @@
+//! In-process runners for the qhull programs
+//!
+//! The functions in this module run the bundled qhull programs (`qconvex`, `qdelaunay`, ...)
+//! as the command line tools would, but without touching the standard streams of the process:
+//! the input points are written to a temporary file and the output is captured.
+//!
+//! This is mainly useful for testing the safe API against the reference programs.
+//!
+//! # Example
+//! ```
+//! # use qhull::*;
+//! let points = [
+//!     0.0, 0.0,
+//!     1.0, 0.0,
+//!     0.0, 1.0,
+//!     0.25, 0.25,
+//! ];
+//!
+//! // print the vertex indices of the hull
+//! let output = programs::qconvex("Fx", &points, 2).unwrap();
+//! assert_eq!(output.exit_code, 0);
+//! assert_eq!(output.stdout, "3\n0\n1\n2\n");
+//! ```
+//!
+//! # Remarks
+//! * the programs catch their own qhull errors, so failures are reported through [`ProgramOutput::exit_code`]
+//! * options that make the program print its help (e.g. `-?`) write it to the captured stdout
+//! * qhull memory-leak warnings are still printed to the real stderr
+
+use std::{ffi::CString, os::raw::c_char, ptr};
+
+use crate::{io_buffers::IOBuffers, sys, tmp_file::TmpFile, QhError};
+
+/// The captured output of a program run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramOutput {
+    /// Value returned by the program, `0` on success or the qhull error code
+    pub exit_code: i32,
+    /// Everything the program printed to stdout
+    pub stdout: String,
+    /// Everything the program printed to stderr
+    pub stderr: String,
+}
+
+/// Run `qconvex` on the given points
+///
+/// `points` are the flattened coordinates of points of dimension `dim`.
+pub fn qconvex(options: &str, points: &[f64], dim: usize) -> Result<ProgramOutput, QhError<'static>> {
+    run("qconvex", sys::qhull_sys__qconvex_main, options, Some((points, dim)))
+}
+
+/// Run `qdelaunay` on the given points
+///
+/// See [`qconvex`] for the meaning of the arguments.
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// let output = programs::qdelaunay("i", &[0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.25, 0.25], 2).unwrap();
+/// assert_eq!(output.stdout.lines().next(), Some("3"));
+/// ```
+pub fn qdelaunay(options: &str, points: &[f64], dim: usize) -> Result<ProgramOutput, QhError<'static>> {
+    run("qdelaunay", sys::qhull_sys__qdelaunay_main, options, Some((points, dim)))
+}
+
+/// Run `qhalf` on the given halfspaces
+///
+/// Each halfspace is given by `dim` values: the normal coefficients followed by the offset.
+/// The feasible point can be given with the `H` option.
+pub fn qhalf(options: &str, halfspaces: &[f64], dim: usize) -> Result<ProgramOutput, QhError<'static>> {
+    run("qhalf", sys::qhull_sys__qhalf_main, options, Some((halfspaces, dim)))
+}
+
+/// Run `qhull` on the given points
+///
+/// See [`qconvex`] for the meaning of the arguments.
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// // errors are reported through the exit code
+/// let output = programs::qhull("", &[0.0, 0.0, 1.0, 1.0, 2.0, 2.0], 2).unwrap();
+/// assert_ne!(output.exit_code, 0);
+/// assert!(output.stderr.contains("QH6154"));
+/// ```
+pub fn qhull(options: &str, points: &[f64], dim: usize) -> Result<ProgramOutput, QhError<'static>> {
+    run("qhull", sys::qhull_sys__qhull_main, options, Some((points, dim)))
+}
+
+/// Run `qvoronoi` on the given points
+///
+/// See [`qconvex`] for the meaning of the arguments.
+pub fn qvoronoi(options: &str, points: &[f64], dim: usize) -> Result<ProgramOutput, QhError<'static>> {
+    run("qvoronoi", sys::qhull_sys__qvoronoi_main, options, Some((points, dim)))
+}
+
+/// Run `rbox`
+///
+/// See also [`rbox::generate`](crate::rbox::generate) to get the points as data.
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// let output = programs::rbox("4 D2 n").unwrap();
+/// assert_eq!(output.stdout.lines().take(2).collect::<Vec<_>>(), ["2", "4"]);
+/// ```
+pub fn rbox(options: &str) -> Result<ProgramOutput, QhError<'static>> {
+    run("rbox", sys::qhull_sys__rbox_main, options, None)
+}
+
+type ProgramMain = unsafe extern "C" fn(argc: i32, argv: *mut *mut c_char) -> i32;
+
+fn run(
+    name: &str,
+    main: ProgramMain,
+    options: &str,
+    input: Option<(&[f64], usize)>,
+) -> Result<ProgramOutput, QhError<'static>> {
+    let args = std::iter::once(name)
+        .chain(options.split_whitespace())
+        .map(CString::new)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| QhError::invalid_input("program options contain a nul character"))?;
+    let mut argv = args
+        .iter()
+        .map(|arg| arg.as_ptr() as *mut c_char)
+        .chain(std::iter::once(ptr::null_mut()))
+        .collect::<Vec<_>>();
+
+    let mut in_file =
+        TmpFile::new().map_err(|e| QhError::io_failed("failed to create temporary file for stdin", e))?;
+    if let Some((points, dim)) = input {
+        if dim == 0 || points.len() % dim != 0 {
+            return Err(QhError::invalid_input("points.len() is not a multiple of dim"));
+        }
+        in_file
+            .write_and_rewind(format_points(points, dim).as_bytes())
+            .map_err(|e| QhError::io_failed("failed to write the input points", e))?;
+    }
+    let mut buffers = IOBuffers::new(true, true);
+
+    let exit_code = unsafe {
+        sys::qhull_sys__set_program_files(in_file.file_handle(), buffers.out_file(), buffers.err_file());
+        // the programs set their own error handler,
+        // qhull errors will never reach this stack frame
+        let exit_code = main(args.len() as _, argv.as_mut_ptr());
+        sys::qhull_sys__set_program_files(ptr::null_mut(), ptr::null_mut(), ptr::null_mut());
+        exit_code
+    };
+
+    let read = |file: Option<TmpFile>, what: &str| match file {
+        Some(file) => file
+            .read_as_string_and_close()
+            .map_err(|e| QhError::io_failed(&format!("failed to read the captured {}", what), e)),
+        None => Ok(String::new()),
+    };
+    Ok(ProgramOutput {
+        exit_code,
+        stdout: read(buffers.out_file.take(), "stdout")?,
+        stderr: read(buffers.err_file.take(), "stderr")?,
+    })
+}
+
+/// Points in the qhull input format
+fn format_points(points: &[f64], dim: usize) -> String {
+    let mut input = format!("{}\n{}\n", dim, points.len() / dim);
+    for point in points.chunks_exact(dim) {
+        let line = point.iter().map(|c| format!("{:e}", c)).collect::<Vec<_>>();
+        input.push_str(&line.join(" "));
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,183 @@
+//! Point generation using qhull's `rbox`
+//!
+//! This module drives the `rbox` code bundled with qhull and returns the generated points
+//! instead of printing them, so that the same inputs used by qhull's own tests
+//! can be used in Rust code, for example in tests and benchmarks.
+//!
+//! See the [rbox documentation](http://www.qhull.org/html/rbox.htm) for the available options.
+//!
+//! # Example
+//! ```
+//! # use qhull::*;
+//! // 10 random points in a 3D cube, plus the vertices of the cube
+//! let (dim, coords) = rbox::generate("10 c D3").unwrap();
+//! assert_eq!(dim, 3);
+//! assert_eq!(coords.len(), (10 + 8) * 3);
+//!
+//! let qh = Qh::builder().build_managed(dim, coords).unwrap();
+//! assert_eq!(qh.num_vertices(), 8);
+//! ```
+
+use std::{error::Error, ffi::CString, fmt::Display, io, ptr};
+
+use crate::{io_buffers::IOBuffers, sys};
+
+/// Maximum length of the rbox command, including the `rbox ` prefix
+///
+/// `qh_rboxpoints` silently truncates longer commands.
+const MAX_COMMAND_LEN: usize = 250 - 50 - 1;
+
+/// An error generated by [`generate`]
+#[derive(Debug)]
+pub enum RboxError {
+    /// The specification cannot be passed to rbox
+    /// (it contains a nul character or it is too long)
+    InvalidSpec(String),
+    /// rbox failed, usually because of an invalid option
+    Rbox {
+        /// rbox exit code
+        code: i32,
+        /// Message printed by rbox
+        message: String,
+    },
+    /// The output of rbox could not be read as a list of points
+    ///
+    /// This happens for output formats that are not supported, such as `Fd` (cdd format).
+    Output(String),
+    /// Failed to create the temporary files capturing the output of rbox, or to read them
+    Io(io::Error),
+}
+
+impl Display for RboxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RboxError::InvalidSpec(msg) => write!(f, "invalid rbox specification: {}", msg),
+            RboxError::Rbox { code, message } => {
+                write!(f, "rbox failed with code {}", code)?;
+                if !message.is_empty() {
+                    write!(f, "\n{}", message)?;
+                }
+                Ok(())
+            }
+            RboxError::Output(msg) => write!(f, "unexpected rbox output: {}", msg),
+            RboxError::Io(err) => write!(f, "failed to capture rbox output: {}", err),
+        }
+    }
+}
+
+impl Error for RboxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RboxError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RboxError {
+    fn from(err: io::Error) -> Self {
+        RboxError::Io(err)
+    }
+}
+
+/// Generate points using rbox
+///
+/// `spec` is the list of rbox options, without the program name (e.g. `"100 s D3"`).
+/// Returns the dimension of the points and their flattened coordinates,
+/// ready to be used with [`QhBuilder::build_managed`](crate::QhBuilder::build_managed).
+///
+/// Unless a seed is given with `tN`, rbox uses the same seed on every call,
+/// so the output is deterministic.
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// // the vertices of a square
+/// let (dim, coords) = rbox::generate("c D2").unwrap();
+/// assert_eq!(dim, 2);
+/// assert_eq!(coords.len(), 4 * 2);
+/// assert!(coords.iter().all(|c| c.abs() == 0.5));
+///
+/// // the same seed produces the same points
+/// assert_eq!(rbox::generate("10 t5").unwrap(), rbox::generate("10 t5").unwrap());
+///
+/// // errors are reported instead of printed
+/// assert!(matches!(rbox::generate("10 D0"), Err(rbox::RboxError::Rbox { .. })));
+/// assert!(matches!(rbox::generate("10 ?"), Err(rbox::RboxError::Rbox { .. })));
+/// assert!(matches!(rbox::generate("10\0"), Err(rbox::RboxError::InvalidSpec(_))));
+/// ```
+pub fn generate(spec: &str) -> Result<(usize, Vec<f64>), RboxError> {
+    let command = format!("rbox {}", spec);
+    if command.len() > MAX_COMMAND_LEN {
+        return Err(RboxError::InvalidSpec(format!(
+            "specification is too long ({} characters, max {})",
+            spec.len(),
+            MAX_COMMAND_LEN - "rbox ".len(),
+        )));
+    }
+    let command = CString::new(command)
+        .map_err(|_| RboxError::InvalidSpec("specification contains a nul character".into()))?;
+
+    let mut buffers = IOBuffers::try_new(true, true)?;
+
+    let code = unsafe {
+        let mut qh: sys::qhT = std::mem::zeroed();
+        sys::qh_init_A(
+            &mut qh,
+            buffers.in_file(),
+            buffers.out_file(),
+            buffers.err_file(),
+            0,
+            ptr::null_mut(),
+        );
+        // rbox traps its own errors, no need for `QhError::try_on_raw`
+        let code = sys::qh_rboxpoints(&mut qh, command.as_ptr() as *mut _);
+        sys::qh_freeqhull(&mut qh, !sys::qh_ALL);
+        code
+    };
+
+    if code != 0 {
+        let message = buffers.err_file.take().unwrap().read_as_string_and_close()?;
+        return Err(RboxError::Rbox { code, message });
+    }
+
+    let output = buffers.out_file.take().unwrap().read_as_string_and_close()?;
+    parse_points(&output)
+}
+
+/// Parse points in the qhull input format
+///
+/// The first line contains the dimension (possibly followed by a comment),
+/// the second line the number of points and the rest the coordinates.
+fn parse_points(output: &str) -> Result<(usize, Vec<f64>), RboxError> {
+    let mut lines = output.lines();
+
+    let dim = lines
+        .next()
+        .and_then(|line| line.split_whitespace().next())
+        .and_then(|dim| dim.parse::<usize>().ok())
+        .ok_or_else(|| RboxError::Output("missing dimension".into()))?;
+    let count = lines
+        .next()
+        .and_then(|line| line.trim().parse::<usize>().ok())
+        .ok_or_else(|| RboxError::Output("missing number of points".into()))?;
+
+    let coords = lines
+        .flat_map(|line| line.split_whitespace())
+        .map(|c| {
+            c.parse::<f64>()
+                .map_err(|_| RboxError::Output(format!("invalid coordinate {:?}", c)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if coords.len() != dim * count {
+        return Err(RboxError::Output(format!(
+            "expected {} points of dimension {}, got {} coordinates",
+            count,
+            dim,
+            coords.len(),
+        )));
+    }
+
+    Ok((dim, coords))
+}
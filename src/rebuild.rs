@@ -0,0 +1,194 @@
+use std::os::raw::{c_int, c_uint};
+
+use crate::sys;
+
+/// Options that can be changed when rebuilding a hull, see [`Qh::rebuild`](crate::Qh::rebuild)
+///
+/// The options that are not set keep the value given to the [`QhBuilder`](crate::QhBuilder),
+/// options changed by a previous rebuild are not kept.
+#[derive(Debug, Clone, Default)]
+pub struct QhRebuildOptions {
+    premerge_centrum: Option<f64>,
+    postmerge_centrum: Option<f64>,
+    premerge_cos: Option<f64>,
+    postmerge_cos: Option<f64>,
+    triangulate: Option<bool>,
+    good_point: Option<Option<usize>>,
+    only_good: Option<bool>,
+}
+
+impl QhRebuildOptions {
+    /// Centrum radius for pre-merging (`C-n`), enables pre-merging
+    pub fn premerge_centrum(&mut self, radius: f64) -> &mut Self {
+        self.premerge_centrum = Some(radius);
+        self
+    }
+
+    /// Centrum radius for post-merging (`Cn`), enables post-merging
+    pub fn postmerge_centrum(&mut self, radius: f64) -> &mut Self {
+        self.postmerge_centrum = Some(radius);
+        self
+    }
+
+    /// Maximum cosine of the angle between adjacent facets for pre-merging (`A-n`), enables pre-merging
+    pub fn premerge_cos(&mut self, cos: f64) -> &mut Self {
+        self.premerge_cos = Some(cos);
+        self
+    }
+
+    /// Maximum cosine of the angle between adjacent facets for post-merging (`An`), enables post-merging
+    pub fn postmerge_cos(&mut self, cos: f64) -> &mut Self {
+        self.postmerge_cos = Some(cos);
+        self
+    }
+
+    /// Triangulate the non-simplicial facets after the build (`Qt`), see [`Qh::triangulate`](crate::Qh::triangulate)
+    pub fn triangulate(&mut self, triangulate: bool) -> &mut Self {
+        self.triangulate = Some(triangulate);
+        self
+    }
+
+    /// Good facets are the ones visible from the given input point (`QGn`), `None` to disable
+    pub fn good_point(&mut self, point: Option<usize>) -> &mut Self {
+        self.good_point = Some(point);
+        self
+    }
+
+    /// Only process the points with good visible or horizon facets (`Qg`)
+    pub fn only_good(&mut self, only_good: bool) -> &mut Self {
+        self.only_good = Some(only_good);
+        self
+    }
+
+    /// Apply the options to an instance whose hull has been freed
+    ///
+    /// The flags that depend on the options are updated as `qh_initflags` and `qh_initqhull_globals` would.
+    pub(crate) fn apply(&self, qh: &mut sys::qhT) {
+        if let Some(radius) = self.premerge_centrum {
+            qh.premerge_centrum = radius;
+            qh.PREmerge = true as _;
+            qh.MERGING = true as _;
+        }
+        if let Some(radius) = self.postmerge_centrum {
+            qh.postmerge_centrum = radius;
+            qh.POSTmerge = true as _;
+            qh.MERGING = true as _;
+        }
+        if let Some(cos) = self.premerge_cos {
+            qh.premerge_cos = cos;
+            qh.PREmerge = true as _;
+            qh.MERGING = true as _;
+        }
+        if let Some(cos) = self.postmerge_cos {
+            qh.postmerge_cos = cos;
+            qh.POSTmerge = true as _;
+            qh.MERGING = true as _;
+        }
+        if let Some(triangulate) = self.triangulate {
+            qh.TRIangulate = triangulate as _;
+        }
+        if let Some(point) = self.good_point {
+            qh.GOODpoint = point.map_or(0, |i| i as c_int + 1);
+            qh.GOODpointp = std::ptr::null_mut();
+        }
+        if let Some(only_good) = self.only_good {
+            qh.ONLYgood = only_good as _;
+        }
+
+        let zero_centrum = qh.MERGING != 0
+            && qh.POSTmerge == 0
+            && qh.premerge_cos > sys::REALmax / 2.0
+            && qh.premerge_centrum == 0.0;
+        qh.ZEROcentrum = zero_centrum as _;
+        qh.ZEROall_ok = zero_centrum as _;
+        qh.DOcheckmax = (qh.SKIPcheckmax == 0 && (qh.MERGING != 0 || qh.APPROXhull != 0)) as _;
+        qh.KEEPnearinside = (qh.DOcheckmax != 0
+            && !(qh.KEEPinside != 0 && qh.KEEPcoplanar != 0)
+            && qh.NOnearinside == 0) as _;
+        if qh.MERGING != 0 {
+            qh.CENTERtype = sys::qh_CENTER_qh_AScentrum;
+        }
+    }
+}
+
+/// The settings of an instance before its first build
+///
+/// `qh_qhull` adjusts some of them for roundoff error (`qh_detroundoff`)
+/// or derives them from the others, and [`QhRebuildOptions`] changes them:
+/// they are restored before a rebuild.
+#[derive(Clone, Copy)]
+pub(crate) struct BuildSettings {
+    premerge_centrum: f64,
+    postmerge_centrum: f64,
+    premerge_cos: f64,
+    postmerge_cos: f64,
+    min_visible: f64,
+    max_coplanar: f64,
+    min_outside: f64,
+    merging: c_uint,
+    pre_merge: c_uint,
+    post_merge: c_uint,
+    zero_centrum: c_uint,
+    zero_all_ok: c_uint,
+    check_max: c_uint,
+    keep_near_inside: c_uint,
+    center_type: sys::qh_CENTER,
+    triangulate: c_uint,
+    good_point: c_int,
+    only_good: c_uint,
+    /// Not restored: on a rebuild qhull joggles the original points again from the adjusted value
+    joggle_max: f64,
+}
+
+impl BuildSettings {
+    pub(crate) fn capture(qh: &sys::qhT) -> Self {
+        Self {
+            premerge_centrum: qh.premerge_centrum,
+            postmerge_centrum: qh.postmerge_centrum,
+            premerge_cos: qh.premerge_cos,
+            postmerge_cos: qh.postmerge_cos,
+            min_visible: qh.MINvisible,
+            max_coplanar: qh.MAXcoplanar,
+            min_outside: qh.MINoutside,
+            merging: qh.MERGING,
+            pre_merge: qh.PREmerge,
+            post_merge: qh.POSTmerge,
+            zero_centrum: qh.ZEROcentrum,
+            zero_all_ok: qh.ZEROall_ok,
+            check_max: qh.DOcheckmax,
+            keep_near_inside: qh.KEEPnearinside,
+            center_type: qh.CENTERtype,
+            triangulate: qh.TRIangulate,
+            good_point: qh.GOODpoint,
+            only_good: qh.ONLYgood,
+            joggle_max: qh.JOGGLEmax,
+        }
+    }
+
+    /// The joggle before qhull adjusts it (`QJn`), see [`Qh::joggle_max`](crate::Qh::joggle_max)
+    pub(crate) fn joggle_max(&self) -> f64 {
+        self.joggle_max
+    }
+
+    pub(crate) fn restore(&self, qh: &mut sys::qhT) {
+        qh.premerge_centrum = self.premerge_centrum;
+        qh.postmerge_centrum = self.postmerge_centrum;
+        qh.premerge_cos = self.premerge_cos;
+        qh.postmerge_cos = self.postmerge_cos;
+        qh.MINvisible = self.min_visible;
+        qh.MAXcoplanar = self.max_coplanar;
+        qh.MINoutside = self.min_outside;
+        qh.MERGING = self.merging;
+        qh.PREmerge = self.pre_merge;
+        qh.POSTmerge = self.post_merge;
+        qh.ZEROcentrum = self.zero_centrum;
+        qh.ZEROall_ok = self.zero_all_ok;
+        qh.DOcheckmax = self.check_max;
+        qh.KEEPnearinside = self.keep_near_inside;
+        qh.CENTERtype = self.center_type;
+        qh.TRIangulate = self.triangulate;
+        qh.GOODpoint = self.good_point;
+        qh.GOODpointp = std::ptr::null_mut();
+        qh.ONLYgood = self.only_good;
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{sys, Qh, QhError};
+
+/// Maximum error on the inner products of the rows of a rotation matrix
+const TOLERANCE: f64 = 1e-9;
+
+/// Size of the rotation matrix for a hull of dimension `hull_dim`
+///
+/// The lifted coordinate of a Delaunay triangulation is not rotated, as with `QRn`.
+pub(crate) fn rotation_dim(qh: &sys::qhT, hull_dim: usize) -> usize {
+    hull_dim - (qh.DELAUNAY != 0) as usize
+}
+
+/// Check that `matrix` is an orthonormal matrix of size `size`
+pub(crate) fn check_rotation(matrix: &[Vec<f64>], size: usize) -> Result<(), QhError<'static>> {
+    if matrix.len() != size {
+        return Err(QhError::invalid_input(format!(
+            "the rotation matrix has {} rows, but the input is rotated in dimension {}",
+            matrix.len(),
+            size,
+        )));
+    }
+    if let Some((i, row)) = matrix.iter().enumerate().find(|(_, row)| row.len() != size) {
+        return Err(QhError::invalid_input(format!(
+            "row {} of the rotation matrix has {} coordinates, {} are needed",
+            i,
+            row.len(),
+            size,
+        )));
+    }
+    for (i, a) in matrix.iter().enumerate() {
+        for (j, b) in matrix.iter().enumerate().skip(i) {
+            let dot: f64 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+            let expected = if i == j { 1.0 } else { 0.0 };
+            if !dot.is_finite() || (dot - expected).abs() > TOLERANCE {
+                return Err(QhError::invalid_input(format!(
+                    "the rotation matrix is not orthonormal: the inner product of rows {} and {} is {}",
+                    i, j, dot,
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rotate the input after `qh_init_B`, as qhull does for `QRn`
+///
+/// Applies the matrix given with [`QhBuilder::rotate_input`](crate::QhBuilder::rotate_input)
+/// and records the rotation of the input, composed with the random one of `QRn`, for [`Qh::input_rotation`].
+/// The matrix must have been [checked](check_rotation).
+pub(crate) unsafe fn rotate_input(qh: &mut Qh) -> Result<(), QhError<'static>> {
+    let raw = qh.qh.get_mut();
+    let hull_dim = raw.hull_dim as usize;
+
+    // `qh_init_B` leaves the random rotation in `gm_row`, qhull reuses it later
+    let mut applied = (raw.ROTATErandom >= 0).then(|| {
+        (0..hull_dim)
+            .map(|i| std::slice::from_raw_parts(*raw.gm_row.add(i), hull_dim).to_vec())
+            .collect::<Vec<_>>()
+    });
+
+    if let Some(matrix) = qh.owned_values.input_rotation.clone() {
+        // identity on the lifted coordinate
+        let mut matrix = (*matrix).clone();
+        for row in &mut matrix {
+            row.resize(hull_dim, 0.0);
+        }
+        for i in matrix.len()..hull_dim {
+            let mut row = vec![0.0; hull_dim];
+            row[i] = 1.0;
+            matrix.push(row);
+        }
+
+        // `qh_rotateinput` uses one more row as a scratch buffer
+        let mut rows = matrix.clone();
+        rows.push(vec![0.0; hull_dim]);
+        let mut row_ptrs: Vec<*mut f64> = rows.iter_mut().map(|row| row.as_mut_ptr()).collect();
+        Qh::try_on_qh_mut(qh, |qh| sys::qh_rotateinput(qh, row_ptrs.as_mut_ptr())).map_err(|e| e.into_static())?;
+
+        applied = Some(match applied {
+            Some(random) => multiply(&matrix, &random),
+            None => matrix,
+        });
+    }
+
+    qh.input_rotation = applied;
+    Ok(())
+}
+
+/// Product of two square matrices
+fn multiply(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    a.iter()
+        .map(|row| {
+            (0..b.len())
+                .map(|j| row.iter().zip(b).map(|(a, b)| a * b[j]).sum())
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,114 @@
+use crate::{Qh, QhError};
+
+/// Affine map applied by qhull to one coordinate of the input
+///
+/// A coordinate `x` of the input is replaced by `x * scale + offset`.
+/// See [`Qh::scaling`](crate::Qh::scaling).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisScaling {
+    /// Factor multiplying the coordinate
+    pub scale: f64,
+    /// Value added to the coordinate after the multiplication
+    pub offset: f64,
+}
+
+impl AxisScaling {
+    /// The coordinate is not changed
+    pub const IDENTITY: Self = Self { scale: 1.0, offset: 0.0 };
+
+    /// Map a coordinate of the input to the coordinate used by qhull
+    pub fn apply(&self, x: f64) -> f64 {
+        x * self.scale + self.offset
+    }
+
+    /// Map a coordinate used by qhull back to the coordinate of the input
+    pub fn invert(&self, x: f64) -> f64 {
+        (x - self.offset) / self.scale
+    }
+
+    /// Apply `self`, then `then`
+    pub(crate) fn then(&self, then: &Self) -> Self {
+        Self {
+            scale: self.scale * then.scale,
+            offset: self.offset * then.scale + then.offset,
+        }
+    }
+}
+
+/// New range of a coordinate of the input (`Qbk:low QBk:high` options)
+///
+/// As for qhull, the range `[0, 0]` drops the coordinate (`Qbk:0Bk:0`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ScaleBound {
+    pub(crate) dim: usize,
+    pub(crate) low: f64,
+    pub(crate) high: f64,
+}
+
+impl ScaleBound {
+    pub(crate) fn drop(dim: usize) -> Self {
+        Self { dim, low: 0.0, high: 0.0 }
+    }
+
+    pub(crate) fn is_drop(&self) -> bool {
+        self.low == 0.0 && self.high == 0.0
+    }
+}
+
+/// Set the ranges of some coordinates of the input, replacing their previous ranges
+///
+/// Updates the flags that make `qh_init_B` project (`PROJECTinput`) and scale (`SCALEinput`) the input.
+pub(crate) fn set_bounds<'a>(
+    qh: &'a mut Qh,
+    new: impl IntoIterator<Item = ScaleBound>,
+) -> Result<(), QhError<'a>> {
+    let bounds = &mut qh.owned_values.scale_bounds;
+    for bound in new {
+        bounds.retain(|b| b.dim != bound.dim);
+        bounds.push(bound);
+    }
+    let dropped = bounds.iter().filter(|b| b.is_drop()).count();
+    let scaled = bounds.len() > dropped;
+    unsafe {
+        Qh::try_on_qh_mut(qh, |qh| {
+            (*qh).PROJECTinput = dropped as _;
+            if scaled {
+                (*qh).SCALEinput = true as _;
+            }
+        })
+    }
+}
+
+/// The `Qbk:low QBk:high` options for the bounds, e.g. ` Qb0:-1e0 QB0:1e0`
+///
+/// `qh_init_B` reads the bounds from the command line (`qh_initthresholds`),
+/// the exponential notation is exact and short.
+pub(crate) fn bound_options(bounds: &[ScaleBound]) -> String {
+    bounds
+        .iter()
+        .map(|b| format!(" Qb{}:{:e} QB{}:{:e}", b.dim, b.low, b.dim, b.high))
+        .collect()
+}
+
+/// The maps that `qh_scaleinput` applies to the coordinates of `points` that are not dropped
+///
+/// Same as `qh_scalepoints`: the range of each coordinate with a bound is mapped to `[low, high]`.
+pub(crate) fn input_scaling(bounds: &[ScaleBound], dim: usize, points: &[f64]) -> Vec<AxisScaling> {
+    (0..dim)
+        .filter_map(|k| match bounds.iter().find(|bound| bound.dim == k) {
+            Some(bound) if bound.is_drop() => None,
+            Some(bound) => {
+                let (min, max) = points
+                    .iter()
+                    .skip(k)
+                    .step_by(dim)
+                    .fold((f64::MAX, f64::MIN), |(min, max), &x| (min.min(x), max.max(x)));
+                Some(AxisScaling {
+                    scale: (bound.high - bound.low) / (max - min),
+                    offset: (bound.low * max - min * bound.high) / (max - min),
+                })
+            }
+            None => Some(AxisScaling::IDENTITY),
+        })
+        .collect()
+}
@@ -0,0 +1,155 @@
+/// Affine transform from the coordinates of a point to its barycentric coordinates in a simplex
+///
+/// This is the equivalent of a row of the `transform` attribute of scipy's `Delaunay`:
+/// given the vertices `v_0, ..., v_d` of a simplex, the transform stores the inverse of the matrix
+/// `T = [v_0 - v_d, ..., v_{d-1} - v_d]` and the origin `v_d`.
+///
+/// Transforms are usually obtained from [`Qh::barycentric_transforms`](crate::Qh::barycentric_transforms).
+///
+/// # Degenerate simplices
+/// If the simplex has (nearly) zero volume, the matrix cannot be inverted and the inverse is filled with `NaN`,
+/// see [`SimplexTransform::is_degenerate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimplexTransform {
+    dim: usize,
+    inverse: Vec<f64>,
+    origin: Vec<f64>,
+}
+
+impl SimplexTransform {
+    /// Compute the transform of a simplex
+    ///
+    /// `vertices` must contain `dim + 1` points of dimension `dim`.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let t = SimplexTransform::new(&[
+    ///     &[0.0, 0.0][..],
+    ///     &[2.0, 0.0][..],
+    ///     &[0.0, 2.0][..],
+    /// ]);
+    /// assert!(!t.is_degenerate());
+    /// assert_eq!(t.barycentric(&[0.5, 0.5]), vec![0.5, 0.25, 0.25]);
+    ///
+    /// // aligned points
+    /// let t = SimplexTransform::new(&[
+    ///     &[0.0, 0.0][..],
+    ///     &[1.0, 1.0][..],
+    ///     &[2.0, 2.0][..],
+    /// ]);
+    /// assert!(t.is_degenerate());
+    /// assert!(t.barycentric(&[0.5, 0.5]).iter().all(|c| c.is_nan()));
+    /// ```
+    pub fn new<P: AsRef<[f64]>>(vertices: &[P]) -> Self {
+        assert!(!vertices.is_empty(), "a simplex must have at least one vertex");
+        let dim = vertices.len() - 1;
+        let origin = vertices[dim].as_ref().to_vec();
+        assert_eq!(origin.len(), dim, "vertices have the wrong dimension");
+
+        // T[i][j] = v_j[i] - v_d[i], row-major
+        let mut matrix = vec![0.0; dim * dim];
+        let mut scale: f64 = 0.0;
+        for (j, vertex) in vertices[..dim].iter().enumerate() {
+            let vertex = vertex.as_ref();
+            assert_eq!(vertex.len(), dim, "vertices have the wrong dimension");
+            for i in 0..dim {
+                matrix[i * dim + j] = vertex[i] - origin[i];
+                scale = scale.max(matrix[i * dim + j].abs());
+            }
+        }
+
+        let inverse = invert(dim, matrix, scale).unwrap_or_else(|| vec![f64::NAN; dim * dim]);
+
+        Self {
+            dim,
+            inverse,
+            origin,
+        }
+    }
+
+    /// The dimension of the simplex
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// The inverse matrix `T^-1`, row-major `dim x dim`
+    pub fn inverse(&self) -> &[f64] {
+        &self.inverse
+    }
+
+    /// The origin of the transform, i.e. the last vertex of the simplex
+    pub fn origin(&self) -> &[f64] {
+        &self.origin
+    }
+
+    /// Check if the simplex is degenerate
+    ///
+    /// Degenerate transforms produce `NaN` barycentric coordinates.
+    pub fn is_degenerate(&self) -> bool {
+        self.inverse.iter().any(|x| x.is_nan())
+    }
+
+    /// Barycentric coordinates of a point
+    ///
+    /// The result has `dim + 1` components, the `i`-th being the weight of the `i`-th vertex.
+    /// The point is inside the simplex if all the coordinates are non-negative.
+    pub fn barycentric(&self, point: &[f64]) -> Vec<f64> {
+        assert_eq!(point.len(), self.dim, "point has the wrong dimension");
+        let d = point
+            .iter()
+            .zip(&self.origin)
+            .map(|(x, o)| x - o)
+            .collect::<Vec<_>>();
+        let mut result = self
+            .inverse
+            .chunks_exact(self.dim.max(1))
+            .take(self.dim)
+            .map(|row| row.iter().zip(&d).map(|(a, b)| a * b).sum::<f64>())
+            .collect::<Vec<_>>();
+        let last = 1.0 - result.iter().sum::<f64>();
+        result.push(last);
+        result
+    }
+}
+
+/// Invert a row-major square matrix using Gauss-Jordan elimination with partial pivoting
+///
+/// Returns `None` if the matrix is (nearly) singular with respect to `scale`.
+fn invert(n: usize, mut a: Vec<f64>, scale: f64) -> Option<Vec<f64>> {
+    let eps = f64::EPSILON * 1e3 * scale.max(f64::MIN_POSITIVE);
+    let mut inv = vec![0.0; n * n];
+    for i in 0..n {
+        inv[i * n + i] = 1.0;
+    }
+
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&r1, &r2| a[r1 * n + col].abs().total_cmp(&a[r2 * n + col].abs()))?;
+        let magnitude = a[pivot * n + col].abs();
+        if magnitude.is_nan() || magnitude <= eps {
+            return None;
+        }
+        for k in 0..n {
+            a.swap(col * n + k, pivot * n + k);
+            inv.swap(col * n + k, pivot * n + k);
+        }
+
+        let p = a[col * n + col];
+        for k in 0..n {
+            a[col * n + k] /= p;
+            inv[col * n + k] /= p;
+        }
+
+        for row in (0..n).filter(|&row| row != col) {
+            let factor = a[row * n + col];
+            if factor != 0.0 {
+                for k in 0..n {
+                    a[row * n + k] -= factor * a[col * n + k];
+                    inv[row * n + k] -= factor * inv[col * n + k];
+                }
+            }
+        }
+    }
+
+    Some(inv)
+}
@@ -0,0 +1,54 @@
+use crate::sys;
+
+/// Statistics of a qhull run
+///
+/// These are some of the counters that qhull prints with the `Ts` option,
+/// see [`Qh::statistics`](crate::Qh::statistics).
+#[derive(Debug, Clone, PartialEq)]
+pub struct QhStatistics {
+    /// Number of points processed (added to the hull)
+    pub points_processed: usize,
+    /// Number of facets created, including the ones deleted or merged afterwards
+    pub facets_created: usize,
+    /// Number of facets (or cycles of facets) merged
+    pub facets_merged: usize,
+    /// Maximum number of merges into a single facet of the hull
+    ///
+    /// This is an aggregate statistic, computed by `qh_collectstatistics`.
+    pub max_facet_merges: usize,
+    /// Total number of distance tests
+    pub distance_tests: usize,
+    /// Number of distance tests for partitioning the points
+    pub partition_distance_tests: usize,
+    /// Maximum distance of a point above a facet
+    pub max_outside: f64,
+    /// Number of times the hull was built with a joggled input (`QJ`) or for a rerun (`QRn`)
+    ///
+    /// Hulls built without these options are built once and report `0`,
+    /// joggled hulls report more than `1` if qhull restarted the construction.
+    pub build_count: usize,
+    /// Number of retries due to precision problems
+    pub retries: usize,
+}
+
+impl QhStatistics {
+    /// Read the counters of a qhull instance
+    ///
+    /// # Safety
+    /// `qh` must point to a valid instance
+    pub(crate) unsafe fn from_raw(qh: *const sys::qhT) -> Self {
+        let qh = &*qh;
+        let counter = |id: sys::qh_statistics| qh.qhstat.stats[id as usize].i.max(0) as usize;
+        Self {
+            points_processed: counter(sys::qh_statistics_Zprocessed),
+            facets_created: counter(sys::qh_statistics_Zsetplane),
+            facets_merged: counter(sys::qh_statistics_Ztotmerge),
+            max_facet_merges: counter(sys::qh_statistics_Znummergemax),
+            distance_tests: counter(sys::qh_statistics_Zdistplane),
+            partition_distance_tests: counter(sys::qh_statistics_Zpartition),
+            max_outside: qh.max_outside,
+            build_count: qh.build_cnt.max(0) as usize,
+            retries: counter(sys::qh_statistics_Zretry),
+        }
+    }
+}
@@ -1,12 +1,67 @@
-use std::io;
+//! Temporary `FILE` streams used to exchange data with qhull
+//!
+//! By default, the streams are temporary files created with `tmpfile`,
+//! or in the temporary directory of the system if that fails ([`TmpFile::in_temp_dir`]).
+//! With the `memstream` feature, on Unix the streams are kept in memory instead:
+//! output is captured with `open_memstream` and input is read with `fmemopen`,
+//! so no file is created. This also works where `tmpfile` is not allowed.
+//! On WASI the streams are always kept in memory, as there is no temporary directory.
+//!
+//! # Example
+//! The error stream is replaced after each failure, without leaking the previous one:
+//! ```
+//! # use qhull::*;
+//! fn open_files() -> Option<usize> {
+//!     std::fs::read_dir("/proc/self/fd").ok().map(|fds| fds.count())
+//! }
+//!
+//! let before = open_files();
+//! for _ in 0..1000 {
+//!     let Err(error) = Qh::builder().build_from_iter([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]) else {
+//!         panic!("flat input should fail");
+//!     };
+//!     assert!(error.error_message.unwrap().contains("QH6154"));
+//! }
+//! assert_eq!(open_files(), before);
+//! ```
+
+use std::{cell::Cell, fs, io, path::{Path, PathBuf}, sync::atomic::{AtomicUsize, Ordering}};
 
 use crate::sys;
 
 pub struct TmpFile {
     file: *mut sys::FILE,
+    /// Size of the data already returned by [`TmpFile::read_so_far`]
+    read: Cell<u64>,
+    /// `None` for the streams backed by a file, see [`TmpFile::from_file`]
+    #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+    memory: Option<Box<MemoryBuffer>>,
+}
+
+/// Storage of an in-memory stream
+///
+/// Boxed because `open_memstream` keeps pointers to `buffer` and `size`.
+#[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+struct MemoryBuffer {
+    /// Allocated by `open_memstream`, valid after each `fflush`
+    buffer: *mut std::os::raw::c_char,
+    size: usize,
+    /// Data read through `fmemopen`, set by [`TmpFile::write_and_rewind`]
+    input: Option<Vec<u8>>,
+}
+
+#[cfg(windows)]
+extern "C" {
+    fn _open_osfhandle(osfhandle: isize, flags: std::os::raw::c_int) -> std::os::raw::c_int;
+    fn _fdopen(fd: std::os::raw::c_int, mode: *const std::os::raw::c_char) -> *mut sys::FILE;
+    fn _close(fd: std::os::raw::c_int) -> std::os::raw::c_int;
 }
 
 impl TmpFile {
+    /// Create a new temporary stream
+    ///
+    /// If the C library cannot create the temporary file (`tmpfile`, or `tmpfile_s` on Windows,
+    /// that uses the root of the drive and is often not writable), [`TmpFile::in_temp_dir`] is used.
     pub fn new() -> io::Result<TmpFile> {
         unsafe {
             // on windows
@@ -17,73 +72,436 @@ impl TmpFile {
                     if !file.is_null() {
                         sys::fclose(file);
                     }
+                    Self::in_temp_dir()
+                } else {
+                    Ok(TmpFile { file, read: Cell::new(0) })
+                }
+            }
+            #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+            {
+                let mut memory = Box::new(MemoryBuffer {
+                    buffer: std::ptr::null_mut(),
+                    size: 0,
+                    input: None,
+                });
+                let file = sys::open_memstream(&mut memory.buffer, &mut memory.size);
+                if file.is_null() {
                     Err(io::Error::last_os_error())
                 } else {
-                    Ok(TmpFile { file })
+                    Ok(TmpFile { file, read: Cell::new(0), memory: Some(memory) })
                 }
             }
-            #[cfg(not(windows))]
+            #[cfg(not(any(windows, any(all(unix, feature = "memstream"), target_os = "wasi"))))]
             {
                 let file = sys::tmpfile();
                 if file.is_null() {
-                    Err(io::Error::last_os_error())
+                    Self::in_temp_dir()
                 } else {
-                    Ok(TmpFile { file })
+                    Ok(TmpFile { file, read: Cell::new(0) })
                 }
             }
         }
     }
 
+    /// Create a temporary file in [`std::env::temp_dir`] (`%TEMP%` on Windows)
+    ///
+    /// The file is deleted when it is closed (Windows) or immediately after it is opened (elsewhere).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::tmp_file::TmpFile;
+    /// let mut file = TmpFile::in_temp_dir().unwrap();
+    /// file.write_and_rewind(b"hello").unwrap();
+    /// assert_eq!(file.read_as_string_and_close().unwrap(), "hello");
+    /// ```
+    pub fn in_temp_dir() -> io::Result<TmpFile> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        loop {
+            let path: PathBuf = std::env::temp_dir().join(format!(
+                "qhull-rs-{}-{}.tmp",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed),
+            ));
+            let mut options = fs::OpenOptions::new();
+            options.read(true).write(true).create_new(true);
+            #[cfg(windows)]
+            {
+                use std::os::windows::fs::OpenOptionsExt;
+                const FILE_FLAG_DELETE_ON_CLOSE: u32 = 0x04000000;
+                options.custom_flags(FILE_FLAG_DELETE_ON_CLOSE);
+            }
+            match options.open(&path) {
+                Ok(file) => {
+                    #[cfg(not(windows))]
+                    fs::remove_file(&path)?;
+                    return Self::from_file(file);
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Use an open file as stream
+    ///
+    /// The file must be readable and writable, and it should be empty:
+    /// the whole file is read back by [`TmpFile::read_and_close`].
+    /// The file is closed when the stream is dropped, or immediately if it cannot be converted to a stream.
+    /// The file descriptor is converted with `fdopen`, on Windows the handle with `_open_osfhandle` and `_fdopen`.
+    ///
+    /// # Example
+    /// Capture the output of `rbox` in a file of the application:
+    /// ```
+    /// # use qhull::tmp_file::TmpFile;
+    /// let path = std::env::temp_dir().join(format!("qhull-rs-doctest-{}.txt", std::process::id()));
+    /// let file = std::fs::OpenOptions::new()
+    ///     .read(true)
+    ///     .write(true)
+    ///     .create(true)
+    ///     .truncate(true)
+    ///     .open(&path)
+    ///     .unwrap();
+    ///
+    /// let mut file = TmpFile::from_file(file).unwrap();
+    /// file.write_and_rewind(b"1 2 3").unwrap();
+    /// assert_eq!(file.read_as_string_and_close().unwrap(), "1 2 3");
+    /// assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2 3");
+    ///
+    /// // a read-only file cannot be used as stream, and it is closed
+    /// # #[cfg(unix)]
+    /// # {
+    /// fn open_files() -> Option<usize> {
+    ///     std::fs::read_dir("/proc/self/fd").ok().map(|fds| fds.count())
+    /// }
+    /// let before = open_files();
+    /// for _ in 0..100 {
+    ///     assert!(TmpFile::from_file(std::fs::File::open(&path).unwrap()).is_err());
+    /// }
+    /// assert_eq!(open_files(), before);
+    /// # }
+    /// std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn from_file(file: fs::File) -> io::Result<TmpFile> {
+        let file = unsafe {
+            #[cfg(windows)]
+            {
+                use std::os::windows::io::{FromRawHandle, IntoRawHandle};
+                let handle = file.into_raw_handle();
+                let fd = _open_osfhandle(handle as isize, 0);
+                if fd == -1 {
+                    drop(fs::File::from_raw_handle(handle));
+                    return Err(io::Error::last_os_error());
+                }
+                let stream = _fdopen(fd, c"r+b".as_ptr());
+                if stream.is_null() {
+                    let error = io::Error::last_os_error();
+                    _close(fd);
+                    return Err(error);
+                }
+                stream
+            }
+            #[cfg(not(windows))]
+            {
+                use std::os::fd::{FromRawFd, IntoRawFd};
+                let fd = file.into_raw_fd();
+                let stream = sys::fdopen(fd, c"r+b".as_ptr());
+                if stream.is_null() {
+                    let error = io::Error::last_os_error();
+                    drop(fs::File::from_raw_fd(fd));
+                    return Err(error);
+                }
+                stream
+            }
+        };
+        Ok(TmpFile {
+            file,
+            read: Cell::new(0),
+            #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+            memory: None,
+        })
+    }
+
+    /// Open a file as stream, creating it if it does not exist
+    ///
+    /// The file is opened for reading and appending: its content is kept and qhull writes after it,
+    /// e.g. to keep a log of the messages, see [`QhBuilder::stderr_file`](crate::QhBuilder::stderr_file).
+    /// The file is converted to a `FILE` stream as in [`TmpFile::from_file`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::tmp_file::TmpFile;
+    /// let path = std::env::temp_dir().join(format!("qhull-rs-doctest-path-{}.txt", std::process::id()));
+    /// std::fs::write(&path, "0 ").unwrap();
+    ///
+    /// let mut file = TmpFile::from_path(&path).unwrap();
+    /// assert_eq!(file.size().unwrap(), 2);
+    /// file.write_and_rewind(b"1 2 3").unwrap();
+    /// assert_eq!(file.read_from(2).unwrap(), b"1 2 3");
+    /// assert_eq!(file.read_as_string_and_close().unwrap(), "0 1 2 3");
+    /// assert_eq!(std::fs::read_to_string(&path).unwrap(), "0 1 2 3");
+    /// std::fs::remove_file(&path).unwrap();
+    ///
+    /// assert!(TmpFile::from_path(std::env::temp_dir().join("qhull-rs-missing-dir/file.txt")).is_err());
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<TmpFile> {
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+        Self::from_file(file)
+    }
+
     pub fn file_handle(&self) -> *mut sys::FILE {
         self.file
     }
 
-    pub fn read_and_close(self) -> Result<Vec<u8>, std::io::Error> {
-        // flush the file
-        let _ = unsafe { sys::fflush(self.file) };
+    /// Write some data to the file and rewind it
+    ///
+    /// This is used to prepare an input file for qhull.
+    pub fn write_and_rewind(&mut self, data: &[u8]) -> Result<(), std::io::Error> {
+        #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+        if let Some(memory) = self.memory.as_mut().filter(|_| !data.is_empty()) {
+            // a memory stream cannot be read, replace it with a read-only stream over a copy of the data
+            let mut input = data.to_vec();
+            let file = unsafe { sys::fmemopen(input.as_mut_ptr() as *mut _, input.len(), c"r".as_ptr()) };
+            if file.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            unsafe { sys::fclose(self.file) };
+            self.file = file;
+            memory.input = Some(input);
+            return Ok(());
+        }
+        unsafe {
+            let written = sys::fwrite(data.as_ptr() as *const _, 1, data.len() as _, self.file);
+            if (written as usize) != data.len() || sys::fflush(self.file) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            sys::rewind(self.file);
+        }
+        Ok(())
+    }
 
-        // TODO fix this: written by copilot but doesn't work, but should be more efficient
-        /*
-        // Seek to the beginning of the file
-        //let _ = unsafe { sys::fseek(self.file, 0, sys::SEEK_SET as _) };
-        let _ = unsafe { sys::rewind(self.file) };
+    /// Copy the content of the file to a writer
+    ///
+    /// The file is read from the beginning in chunks, without loading it in memory.
+    pub fn write_to(&mut self, writer: &mut impl io::Write) -> Result<(), std::io::Error> {
+        #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+        if let Some(content) = self.memory_content() {
+            return writer.write_all(content?);
+        }
+        let mut buffer = [0u8; 4096];
+        unsafe {
+            if sys::fflush(self.file) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            sys::rewind(self.file);
+            loop {
+                let read = sys::fread(buffer.as_mut_ptr() as *mut _, 1, buffer.len() as _, self.file) as usize;
+                writer.write_all(&buffer[..read])?;
+                if read < buffer.len() {
+                    if sys::ferror(self.file) != 0 {
+                        return Err(io::Error::last_os_error());
+                    }
+                    return Ok(());
+                }
+            }
+        }
+    }
 
-        // Get the current size of the file
-        let size = unsafe { sys::ftell(self.file) };
-        println!("size: {:?}", size);
+    /// Read the whole content of the file
+    ///
+    /// The size is obtained by seeking to the end, then the content is read with a single `fread`.
+    /// In-memory streams are copied from their buffer.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::tmp_file::TmpFile;
+    /// let data = (0..5_000_000).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+    /// let mut file = TmpFile::new().unwrap();
+    /// file.write_and_rewind(&data).unwrap();
+    /// assert_eq!(file.read_and_close().unwrap(), data);
+    ///
+    /// assert!(TmpFile::new().unwrap().read_and_close().unwrap().is_empty());
+    /// ```
+    pub fn read_and_close(self) -> Result<Vec<u8>, std::io::Error> {
+        #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+        if let Some(content) = self.memory_content() {
+            return content.map(|content| content.to_vec());
+        }
+        unsafe {
+            if sys::fflush(self.file) != 0 || sys::fseek(self.file, 0, sys::SEEK_END as _) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let size = usize::try_from(sys::ftell(self.file)).map_err(|_| io::Error::last_os_error())?;
+            sys::rewind(self.file);
 
-        // Create a buffer with the size of the file
-        let mut buffer = vec![0u8; size as usize];
+            let mut buffer = vec![0u8; size];
+            let read = sys::fread(buffer.as_mut_ptr() as *mut _, 1, size as _, self.file) as usize;
+            if read != size {
+                if sys::ferror(self.file) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                buffer.truncate(read);
+            }
+            Ok(buffer)
+        }
+    }
 
-        // Read the file content into the buffer
-        let _ = unsafe { sys::fread(buffer.as_mut_ptr() as *mut _, 1, size as _, self.file) };
-        */
+    /// Size of the content of the stream, in bytes
+    ///
+    /// The stream is left at its end.
+    pub fn size(&mut self) -> Result<u64, std::io::Error> {
+        #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+        if let Some(content) = self.memory_content() {
+            return content.map(|content| content.len() as u64);
+        }
+        unsafe {
+            if sys::fflush(self.file) != 0 || sys::fseek(self.file, 0, sys::SEEK_END as _) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            u64::try_from(sys::ftell(self.file)).map_err(|_| io::Error::last_os_error())
+        }
+    }
 
-        let mut buffer = Vec::new();
+    /// Read the content of the stream after the first `start` bytes, without closing it
+    ///
+    /// The stream is left at its end, so that the next writes follow the content.
+    pub fn read_from(&mut self, start: u64) -> Result<Vec<u8>, std::io::Error> {
+        #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+        if let Some(content) = self.memory_content() {
+            return content.map(|content| content.get(start as usize..).unwrap_or_default().to_vec());
+        }
+        let size = self.size()?;
+        let Some(len) = size.checked_sub(start) else {
+            return Ok(Vec::new());
+        };
         unsafe {
-            sys::rewind(self.file);
-            while sys::feof(self.file) == 0 {
-                let c = sys::fgetc(self.file);
-                if c == sys::EOF {
-                    break;
+            let offset = start.try_into().map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+            if sys::fseek(self.file, offset, sys::SEEK_SET as _) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let mut buffer = vec![0u8; len as usize];
+            let read = sys::fread(buffer.as_mut_ptr() as *mut _, 1, buffer.len() as _, self.file) as usize;
+            let error = sys::ferror(self.file) != 0;
+            sys::fseek(self.file, 0, sys::SEEK_END as _);
+            if read != buffer.len() {
+                if error {
+                    return Err(io::Error::last_os_error());
                 }
-                buffer.push(c as u8);
+                buffer.truncate(read);
             }
+            Ok(buffer)
         }
+    }
 
-        Ok(buffer)
+    /// Read the data written since the previous call, without closing the stream
+    ///
+    /// The position of the stream is saved with `ftell` and restored with `fseek` after reading,
+    /// so that the next writes of qhull go where they would have gone:
+    /// the output can be inspected while it is being written.
+    ///
+    /// # Example
+    /// Interleave writes of the C side and reads:
+    /// ```
+    /// # use qhull::{sys, tmp_file::TmpFile};
+    /// let file = TmpFile::new().unwrap();
+    /// let write = |data: &[u8]| unsafe {
+    ///     sys::fwrite(data.as_ptr() as *const _, 1, data.len() as _, file.file_handle());
+    /// };
+    ///
+    /// assert_eq!(file.read_so_far().unwrap(), b"");
+    /// for i in 0..100 {
+    ///     let line = format!("line {}\n", i);
+    ///     write(line.as_bytes());
+    ///     write(line.as_bytes());
+    ///     assert_eq!(file.read_so_far().unwrap(), line.repeat(2).as_bytes());
+    /// }
+    /// assert_eq!(file.read_so_far().unwrap(), b"");
+    ///
+    /// // the whole content is still there
+    /// let content = file.read_as_string_and_close().unwrap();
+    /// assert_eq!(content.lines().count(), 200);
+    /// assert!(content.ends_with("line 99\nline 99\n"));
+    /// ```
+    pub fn read_so_far(&self) -> Result<Vec<u8>, std::io::Error> {
+        let start = self.read.get();
+        #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+        if let Some(content) = self.memory_content() {
+            let content = content?.get(start as usize..).unwrap_or_default().to_vec();
+            self.read.set(start + content.len() as u64);
+            return Ok(content);
+        }
+        unsafe {
+            if sys::fflush(self.file) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let position = sys::ftell(self.file);
+            if position < 0 || sys::fseek(self.file, 0, sys::SEEK_END as _) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let end = sys::ftell(self.file);
+            let mut buffer = vec![0u8; u64::try_from(end).unwrap_or(0).saturating_sub(start) as usize];
+            let mut error = None;
+            if !buffer.is_empty() {
+                let offset = start.try_into().map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+                if sys::fseek(self.file, offset, sys::SEEK_SET as _) != 0 {
+                    error = Some(io::Error::last_os_error());
+                } else {
+                    let read = sys::fread(buffer.as_mut_ptr() as *mut _, 1, buffer.len() as _, self.file) as usize;
+                    if read != buffer.len() && sys::ferror(self.file) != 0 {
+                        error = Some(io::Error::last_os_error());
+                    }
+                    buffer.truncate(read);
+                }
+            }
+            // restore the position even if the read failed
+            if sys::fseek(self.file, position, sys::SEEK_SET as _) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if let Some(error) = error {
+                return Err(error);
+            }
+            self.read.set(start + buffer.len() as u64);
+            Ok(buffer)
+        }
     }
 
     pub fn read_as_string_and_close(self) -> Result<String, std::io::Error> {
         let buffer = self.read_and_close()?;
         String::from_utf8(buffer).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     }
+
+    /// Content of the in-memory stream, `None` if the stream is backed by a file
+    #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+    fn memory_content(&self) -> Option<Result<&[u8], std::io::Error>> {
+        let memory = self.memory.as_ref()?;
+        if let Some(input) = &memory.input {
+            return Some(Ok(input));
+        }
+        unsafe {
+            // updates `buffer` and `size`
+            if sys::fflush(self.file) != 0 {
+                return Some(Err(io::Error::last_os_error()));
+            }
+            if memory.buffer.is_null() {
+                return Some(Ok(&[]));
+            }
+            Some(Ok(std::slice::from_raw_parts(memory.buffer as *const u8, memory.size)))
+        }
+    }
 }
 
 impl Drop for TmpFile {
     fn drop(&mut self) {
         unsafe {
             sys::fclose(self.file);
+            #[cfg(any(all(unix, feature = "memstream"), target_os = "wasi"))]
+            if let Some(memory) = &self.memory {
+                sys::free(memory.buffer as *mut _);
+            }
         }
     }
 }
@@ -0,0 +1,13 @@
+/// Triangle mesh of the surface of a 3D hull
+///
+/// See [`Qh::triangle_mesh`](crate::Qh::triangle_mesh).
+/// The buffers can be uploaded to a GPU as they are.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TriangleMesh {
+    /// Coordinates of the vertices of the hull, each once
+    pub positions: Vec<[f64; 3]>,
+    /// Triangles, as indices in [`positions`](TriangleMesh::positions), counterclockwise seen from outside
+    pub triangles: Vec<[u32; 3]>,
+    /// Index of the input point of each position
+    pub input_indices: Vec<usize>,
+}
@@ -0,0 +1,327 @@
+//! Wrappers with the dimension in the type
+//!
+//! [`QhTyped<D>`] is a convex hull of `D`-dimensional points and
+//! [`DelaunayTyped<D>`] a Delaunay triangulation of `D`-dimensional points,
+//! the points are arrays instead of slices whose length is checked at runtime.
+//! Both wrap a [`Qh`], that is still available for everything else.
+//!
+//! A Delaunay triangulation is computed as the lower hull of the points lifted to `D + 1` dimensions,
+//! and a simplex of the triangulation has `D + 1` vertices.
+//! As `D + 1` cannot be written in a generic type on stable Rust,
+//! [`DelaunayTyped<D>`] is available for the dimensions implementing [`DelaunayDim`] (1 to 8),
+//! that provides the lifted types.
+
+use std::array::TryFromSliceError;
+
+use crate::{Facet, Qh, QhBuilder, QhError};
+
+/// Convex hull of `D`-dimensional points
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// let hull = QhTyped::new([
+///     [0.0, 0.0, 0.0],
+///     [1.0, 0.0, 0.0],
+///     [0.0, 1.0, 0.0],
+///     [0.0, 0.0, 1.0],
+///     [0.1, 0.1, 0.1],
+/// ])
+/// .unwrap();
+///
+/// let mut vertices = hull.vertices().collect::<Vec<[f64; 3]>>();
+/// vertices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+/// assert_eq!(vertices, [[0.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]);
+/// assert_eq!(hull.vertex_indices().len(), 4);
+/// assert_eq!(hull.point(4), Some([0.1, 0.1, 0.1]));
+///
+/// assert!(hull.contains(&[0.2, 0.2, 0.2]));
+/// assert!(!hull.contains(&[0.5, 0.5, 0.5]));
+///
+/// for (normal, offset) in hull.equations() {
+///     // the interior point is below every facet
+///     let dist: f64 = normal.iter().zip([0.1, 0.1, 0.1]).map(|(n, x)| n * x).sum::<f64>() + offset;
+///     assert!(dist < 0.0);
+/// }
+/// ```
+pub struct QhTyped<const D: usize> {
+    qh: Qh<'static>,
+}
+
+impl<const D: usize> QhTyped<D> {
+    /// Compute the convex hull of the points with the default settings
+    pub fn new(points: impl IntoIterator<Item = [f64; D]>) -> Result<Self, QhError<'static>> {
+        Self::with_builder(QhBuilder::default(), points)
+    }
+
+    /// Compute the convex hull of the points with the settings of a builder
+    ///
+    /// Returns an error if the builder does not produce a `D`-dimensional convex hull,
+    /// for example if it computes a Delaunay triangulation (use [`DelaunayTyped`])
+    /// or a halfspace intersection.
+    /// The hull is computed even if the builder does not [compute](QhBuilder::compute) it.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]];
+    /// let hull = QhTyped::with_builder(Qh::builder().keep_coplanar(true), points).unwrap();
+    /// assert_eq!(hull.qh().num_facets(), 3);
+    ///
+    /// assert!(QhTyped::with_builder(Qh::builder().delaunay(true), points).is_err());
+    ///
+    /// let hull = QhTyped::with_builder(Qh::builder().compute(false), points).unwrap();
+    /// assert!(hull.contains(&[0.25, 0.25]));
+    /// assert!(!hull.contains(&[1.0, 1.0]));
+    /// ```
+    pub fn with_builder(builder: QhBuilder, points: impl IntoIterator<Item = [f64; D]>) -> Result<Self, QhError<'static>> {
+        const { assert!(D > 0, "points must have at least one coordinate") };
+        let coords: Vec<f64> = points.into_iter().flatten().collect();
+        let mut qh = builder.build_managed(D, coords)?;
+        if !qh.is_computed() {
+            qh.compute().map_err(|e| e.into_static())?;
+        }
+        let (delaunay, halfspace) = unsafe {
+            let raw = &*Qh::raw_ptr(&qh);
+            (raw.DELAUNAY != 0, raw.HALFspace != 0)
+        };
+        if delaunay || halfspace || qh.hull_dim() != D {
+            return Err(QhError::invalid_input(format!(
+                "QhTyped<{}> needs a {}-d convex hull, the builder computes a {}-d {}",
+                D,
+                D,
+                qh.hull_dim(),
+                if delaunay { "Delaunay triangulation" } else if halfspace { "halfspace intersection" } else { "hull" },
+            )));
+        }
+        Ok(Self { qh })
+    }
+
+    /// The underlying instance
+    pub fn qh(&self) -> &Qh<'static> {
+        &self.qh
+    }
+
+    /// The underlying instance, mutable
+    pub fn qh_mut(&mut self) -> &mut Qh<'static> {
+        &mut self.qh
+    }
+
+    /// Unwrap the underlying instance
+    pub fn into_inner(self) -> Qh<'static> {
+        self.qh
+    }
+
+    /// Coordinates of an input point, see [`Qh::point`]
+    pub fn point(&self, index: usize) -> Option<[f64; D]> {
+        self.qh.point(index).map(to_array)
+    }
+
+    /// The input points
+    pub fn points(&self) -> impl Iterator<Item = [f64; D]> + '_ {
+        self.qh.points().map(to_array)
+    }
+
+    /// Coordinates of the vertices of the hull
+    pub fn vertices(&self) -> impl Iterator<Item = [f64; D]> + '_ {
+        self.qh
+            .vertices()
+            .map(|v| to_array(v.point().expect("hull vertices have coordinates")))
+    }
+
+    /// Indices of the input points that are vertices of the hull
+    pub fn vertex_indices(&self) -> Vec<usize> {
+        self.qh
+            .vertices()
+            .map(|v| v.index(&self.qh).expect("hull vertex is not an input point"))
+            .collect()
+    }
+
+    /// The facets of the hull, see [`Facet::normal_array`] for their normals
+    pub fn facets(&self) -> impl Iterator<Item = Facet<'_>> {
+        self.qh.facets()
+    }
+
+    /// Hyperplane of each facet, as normal and offset
+    ///
+    /// A point `x` is below the facet if `normal · x + offset < 0`.
+    pub fn equations(&self) -> Vec<([f64; D], f64)> {
+        self.qh
+            .facets()
+            .map(|f| (f.normal_array().expect("hull facets have a normal"), f.offset()))
+            .collect()
+    }
+
+    /// Check whether a point is inside the hull, see [`Qh::contains`]
+    pub fn contains(&self, point: &[f64; D]) -> bool {
+        self.qh
+            .contains(point)
+            .expect("the dimension of a QhTyped is checked at construction")
+    }
+}
+
+/// A dimension of the input of [`DelaunayTyped`]
+///
+/// Provides the types of the lifted space, of dimension `D + 1`.
+/// Implemented for the dimensions from 1 to 8.
+pub trait DelaunayDim {
+    /// Dimension of the lifted points, `D + 1`
+    const LIFTED_DIM: usize;
+    /// Indices of the `D + 1` vertices of a simplex, `[usize; D + 1]`
+    type Simplex: Copy
+        + std::fmt::Debug
+        + PartialEq
+        + AsRef<[usize]>
+        + for<'s> TryFrom<&'s [usize], Error = TryFromSliceError>;
+    /// A point of the lifted space, `[f64; D + 1]`
+    type LiftedPoint: Copy
+        + std::fmt::Debug
+        + PartialEq
+        + AsRef<[f64]>
+        + for<'s> TryFrom<&'s [f64], Error = TryFromSliceError>;
+}
+
+/// Marker type for the dimension `D`, see [`DelaunayDim`]
+pub struct Dim<const D: usize>;
+
+macro_rules! delaunay_dims {
+    ($($dim:literal => $lifted:literal),* $(,)?) => {
+        $(
+            impl DelaunayDim for Dim<$dim> {
+                const LIFTED_DIM: usize = $lifted;
+                type Simplex = [usize; $lifted];
+                type LiftedPoint = [f64; $lifted];
+            }
+        )*
+    };
+}
+
+delaunay_dims!(1 => 2, 2 => 3, 3 => 4, 4 => 5, 5 => 6, 6 => 7, 7 => 8, 8 => 9);
+
+/// Delaunay triangulation of `D`-dimensional points
+///
+/// The instance is built as with [`Qh::new_delaunay`], its [`hull_dim`](Qh::hull_dim) is `D + 1`.
+///
+/// # Example
+/// ```
+/// # use qhull::*;
+/// let delaunay = DelaunayTyped::new([
+///     [0.0, 0.0],
+///     [1.0, 0.0],
+///     [0.0, 1.0],
+///     [0.25, 0.25],
+/// ])
+/// .unwrap();
+/// assert_eq!(delaunay.qh().hull_dim(), 3);
+///
+/// let mut simplices = delaunay.simplices();
+/// simplices.iter_mut().for_each(|s| s.sort());
+/// simplices.sort();
+/// assert_eq!(simplices, [[0, 1, 3], [0, 2, 3], [1, 2, 3]]);
+///
+/// // the circumcenters are in the input space
+/// for (simplex, center) in delaunay.simplices().iter().zip(delaunay.circumcenters()) {
+///     let distances = simplex.map(|i| {
+///         let p = delaunay.point(i).unwrap();
+///         ((p[0] - center[0]).powi(2) + (p[1] - center[1]).powi(2)).sqrt()
+///     });
+///     assert!((distances[0] - distances[1]).abs() < 1e-12);
+///     assert!((distances[0] - distances[2]).abs() < 1e-12);
+/// }
+///
+/// // the lifted points are on the paraboloid (scaled by 'Qbb')
+/// let lifted: [f64; 3] = delaunay.lifted_point(1).unwrap();
+/// assert_eq!(&lifted[..2], &[1.0, 0.0]);
+/// ```
+///
+/// A 3D triangulation has tetrahedra:
+/// ```
+/// # use qhull::*;
+/// let delaunay = DelaunayTyped::new([
+///     [0.0, 0.0, 0.0],
+///     [1.0, 0.0, 0.0],
+///     [0.0, 1.0, 0.0],
+///     [0.0, 0.0, 1.0],
+///     [0.2, 0.3, 0.25],
+/// ])
+/// .unwrap();
+/// let simplices: Vec<[usize; 4]> = delaunay.simplices();
+/// assert_eq!(simplices.len(), 4);
+/// assert!(simplices.iter().all(|s| s.contains(&4)));
+/// assert!(delaunay.circumcenters().iter().all(|c: &[f64; 3]| c.iter().all(|x| x.is_finite())));
+/// ```
+pub struct DelaunayTyped<const D: usize>
+where
+    Dim<D>: DelaunayDim,
+{
+    qh: Qh<'static>,
+}
+
+impl<const D: usize> DelaunayTyped<D>
+where
+    Dim<D>: DelaunayDim,
+{
+    /// Compute the Delaunay triangulation of the points
+    pub fn new(points: impl IntoIterator<Item = [f64; D]>) -> Result<Self, QhError<'static>> {
+        let qh = Qh::new_delaunay(points)?;
+        debug_assert_eq!(qh.hull_dim(), <Dim<D> as DelaunayDim>::LIFTED_DIM);
+        Ok(Self { qh })
+    }
+
+    /// The underlying instance
+    pub fn qh(&self) -> &Qh<'static> {
+        &self.qh
+    }
+
+    /// The underlying instance, mutable
+    pub fn qh_mut(&mut self) -> &mut Qh<'static> {
+        &mut self.qh
+    }
+
+    /// Unwrap the underlying instance
+    pub fn into_inner(self) -> Qh<'static> {
+        self.qh
+    }
+
+    /// Coordinates of an input point, in the input space
+    pub fn point(&self, index: usize) -> Option<[f64; D]> {
+        self.qh.point(index).map(to_array)
+    }
+
+    /// Coordinates of an input point lifted to the paraboloid, as stored by qhull
+    pub fn lifted_point(&self, index: usize) -> Option<<Dim<D> as DelaunayDim>::LiftedPoint> {
+        if index >= self.qh.num_points() {
+            return None;
+        }
+        let lifted = unsafe {
+            let raw = &*Qh::raw_ptr(&self.qh);
+            let first_point = raw.first_point as *const f64;
+            let dim = raw.hull_dim as usize;
+            std::slice::from_raw_parts(first_point.add(index * dim), dim)
+        };
+        Some(lifted.try_into().expect("lifted points have D + 1 coordinates"))
+    }
+
+    /// Indices of the input points of each simplex, see [`Qh::simplex_indices`]
+    pub fn simplices(&self) -> Vec<<Dim<D> as DelaunayDim>::Simplex> {
+        self.qh
+            .simplex_indices()
+            .iter()
+            .map(|s| s[..].try_into().expect("Delaunay simplices have D + 1 vertices"))
+            .collect()
+    }
+
+    /// Circumcenter of each simplex, in the same order as [`DelaunayTyped::simplices`]
+    pub fn circumcenters(&self) -> Vec<[f64; D]> {
+        self.qh
+            .circumcenters()
+            .expect("DelaunayTyped is a Delaunay triangulation")
+            .iter()
+            .map(|c| to_array(c))
+            .collect()
+    }
+}
+
+fn to_array<const D: usize>(coords: &[f64]) -> [f64; D] {
+    coords.try_into().expect("point has the dimension of the hull")
+}
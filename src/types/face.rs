@@ -1,6 +1,6 @@
-use std::{fmt::Debug, marker::PhantomData, ops::Not};
+use std::{fmt::Debug, hash::{Hash, Hasher}, marker::PhantomData, ops::Not};
 
-use crate::{dbg_face_set, helpers::QhTypeRef, sys, Ridge, Set, Vertex};
+use crate::{dbg_face_set, helpers::QhTypeRef, sys, Point, Qh, QhError, Ridge, Set, Vertex};
 
 /// A face of the convex hull
 ///
@@ -22,7 +22,8 @@ impl<'a> Debug for Facet<'a> {
             .field("offset", &self.offset())
             .field("normal", &self.normal())
             .field("f", &"...")// TODO ???
-            .field("center", &self.center())
+            // the length of the center depends on qh.CENTERtype, see Facet::centrum
+            .field("has_center", &unsafe { !self.raw_ref().center.is_null() })
             .field("previous", &self.previous().map(|f| f.id()))
             .field("next", &self.next().map(|f| f.id()))
             .field("vertices", &self.vertices())
@@ -57,7 +58,51 @@ impl<'a> Debug for Facet<'a> {
     }
 }
 
+/// Two facets are equal if they are the same facet of the same [`Qh`](crate::Qh), see [`Facet::ptr_eq`]
+impl<'a> PartialEq for Facet<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        let eq = self.ptr_eq(other);
+        debug_assert!(!eq || self.id() == other.id());
+        eq
+    }
+}
+
+impl<'a> Eq for Facet<'a> {}
+
+impl<'a> Hash for Facet<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state);
+    }
+}
+
 impl<'a> Facet<'a> {
+    /// Whether `self` and `other` refer to the same qhull facet
+    ///
+    /// This is an identity comparison (the same as `==`), not a geometric one:
+    /// facets of different instances are never equal, even if they have the same coordinates.
+    /// Use it to track the facets in sets and maps (e.g. visited facets),
+    /// they are only meaningful while the [`Qh`](crate::Qh) that owns them is not modified.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::collections::HashSet;
+    /// let (dim, coords) = rbox::generate("100 D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// let facets: HashSet<Facet> = qh.facets().collect();
+    /// assert_eq!(facets.len(), qh.num_facets());
+    ///
+    /// let first = qh.facets().next().unwrap();
+    /// let neighbor = first.neighbors().unwrap().iter().next().unwrap();
+    /// assert!(first.ptr_eq(&qh.facets().next().unwrap()));
+    /// assert!(!first.ptr_eq(&neighbor));
+    /// assert!(neighbor.neighbors().unwrap().iter().any(|f| f == first));
+    /// ```
+    pub fn ptr_eq(&self, other: &Facet) -> bool {
+        self.ptr == other.ptr
+    }
+
     /// Check if the vertex is a sentinel (id = 0)
     ///
     /// A sentinel is a special vertex that is used to mark the end of a list
@@ -98,19 +143,81 @@ impl<'a> Facet<'a> {
             face.normal
                 .is_null()
                 .not()
-                .then(|| std::slice::from_raw_parts(dbg!(face.normal), self.dim()))
+                .then(|| std::slice::from_raw_parts(face.normal, self.dim()))
         }
     }
 
+    /// The normal as an array, see [`Facet::normal`]
+    ///
+    /// Returns `None` if the facet has no normal or if `D` is not the dimension of the normal.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    /// for facet in qh.facets() {
+    ///     let normal: [f64; 2] = facet.normal_array().unwrap();
+    ///     assert_eq!(normal, facet.normal().unwrap());
+    ///     assert!(facet.normal_array::<3>().is_none());
+    /// }
+    /// ```
+    pub fn normal_array<const D: usize>(&self) -> Option<[f64; D]> {
+        self.normal()?.try_into().ok()
+    }
+
     // TODO that union??
 
-    pub fn center(&self) -> Option<&'a [f64]> {
+    /// Voronoi center of the facet of a Delaunay triangulation, with `hull_dim - 1` coordinates
+    ///
+    /// Qhull stores either centrums or Voronoi centers in the facets, depending on `qh.CENTERtype`:
+    /// this is `None` if the center has not been computed or is a [centrum](Facet::centrum).
+    /// Qhull computes the Voronoi centers for the Voronoi outputs, see also [`Facet::circumcenter`].
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .voronoi(true)
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [2.0, 2.0]])
+    ///     .unwrap();
+    /// // the Voronoi vertices are computed by the output
+    /// qh.produce_output(PrintFormat::Off).unwrap();
+    ///
+    /// let centers = qh
+    ///     .facets()
+    ///     .filter_map(|f| f.voronoi_center(&qh))
+    ///     .collect::<Vec<_>>();
+    /// assert!(!centers.is_empty());
+    /// assert!(centers.iter().all(|c| c.len() == 2));
+    /// assert!(centers.iter().any(|c| c == &[0.5, 0.5]));
+    /// ```
+    pub fn voronoi_center(&self, qh: &Qh) -> Option<&'a [f64]> {
+        self.center_of_type(qh, sys::qh_CENTER_qh_ASvoronoi, qh.hull_dim() - 1)
+    }
+
+    /// Center of the facet, either a [centrum](Facet::centrum) or a [Voronoi center](Facet::voronoi_center)
+    ///
+    /// The number of coordinates depends on the type of the center, which is only known by the instance.
+    #[deprecated(note = "the length of the center depends on its type, use `Facet::centrum` or `Facet::voronoi_center` instead")]
+    pub fn center(&self, qh: &Qh) -> Option<&'a [f64]> {
+        let centrum = self.center_of_type(qh, sys::qh_CENTER_qh_AScentrum, qh.hull_dim());
+        centrum.or_else(|| self.voronoi_center(qh))
+    }
+
+    fn center_of_type(&self, qh: &Qh, center_type: sys::qh_CENTER, len: usize) -> Option<&'a [f64]> {
         unsafe {
+            if (*Qh::raw_ptr(qh)).CENTERtype != center_type {
+                return None;
+            }
             let face = self.raw_ref();
             face.center
                 .is_null()
                 .not()
-                .then(|| std::slice::from_raw_parts(dbg!(face.center), self.dim()))
+                .then(|| std::slice::from_raw_parts(face.center, len))
         }
     }
 
@@ -129,6 +236,40 @@ impl<'a> Facet<'a> {
         Set::maybe_new(face.vertices, self.dim())
     }
 
+    /// Visit the vertices of the facet
+    ///
+    /// Same vertices as [`Facet::vertices`], in the same order, without building a [`Set`] iterator.
+    /// Nothing is visited if the facet has no vertices.
+    ///
+    /// The closure receives a reference that is only valid for the call, it must not retain it
+    /// (copy the [`Vertex`] instead).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+    ///     .unwrap();
+    ///
+    /// for facet in qh.facets() {
+    ///     let mut ids = Vec::new();
+    ///     facet.for_each_vertex(|v| ids.push(v.id()));
+    ///     assert_eq!(ids, facet.vertices().unwrap().iter().map(|v| v.id()).collect::<Vec<_>>());
+    /// }
+    /// ```
+    pub fn for_each_vertex(&self, mut f: impl FnMut(&Vertex<'a>)) {
+        let set = unsafe { self.raw_ref() }.vertices;
+        if set.is_null() {
+            return;
+        }
+        // the elements of a set end with a null pointer
+        let mut element = unsafe { std::ptr::addr_of!((*set).e) as *const sys::setelemT };
+        while let Some(vertex) = Vertex::from_ptr(unsafe { (*element).p } as *mut sys::vertexT, self.dim()) {
+            f(&vertex);
+            element = unsafe { element.add(1) };
+        }
+    }
+
     pub fn ridges(&self) -> Option<Set<'a, Ridge<'a>>> {
         if self.dim() == 0 {
             None
@@ -143,16 +284,376 @@ impl<'a> Facet<'a> {
         Set::maybe_new(face.neighbors, self.dim())
     }
 
-    pub fn outside_set(&self) -> Option<Set<'a, Vertex<'a>>> {
+    /// The ridge shared with another facet
+    ///
+    /// Scans the [ridges](Facet::ridges) of this facet for one whose other side is `other`.
+    ///
+    /// # Remarks
+    /// Qhull only keeps explicit ridges for non-simplicial facets (e.g. after merging),
+    /// for simplicial facets (such as the ones produced by [`Qh::triangulate`]) this returns `None`.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// // every pair of neighboring faces of the cube shares exactly one ridge
+    /// for facet in qh.facets() {
+    ///     for neighbor in facet.neighbors().unwrap() {
+    ///         let ridge = facet.ridge_between(&neighbor).unwrap();
+    ///         assert_eq!(ridge.opposite(&facet).unwrap().id(), neighbor.id());
+    ///         assert_eq!(neighbor.ridge_between(&facet).unwrap().id(), ridge.id());
+    ///         assert_eq!(ridge.vertices().unwrap().iter().count(), 2);
+    ///         let shared = facet
+    ///             .ridges()
+    ///             .unwrap()
+    ///             .iter()
+    ///             .filter(|r| r.opposite(&facet).unwrap().id() == neighbor.id())
+    ///             .count();
+    ///         assert_eq!(shared, 1);
+    ///     }
+    ///
+    ///     // the opposite face is not a neighbor
+    ///     assert!(qh.facets().any(|f| f.id() != facet.id() && facet.ridge_between(&f).is_none()));
+    /// }
+    ///
+    /// // the facets of a triangulated hull are simplicial, they have no explicit ridges
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let mut qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// qh.triangulate().unwrap();
+    /// let facet = qh.facets().next().unwrap();
+    /// let neighbor = facet.neighbors().unwrap().iter().next().unwrap();
+    /// assert!(facet.ridge_between(&neighbor).is_none());
+    /// ```
+    pub fn ridge_between(&self, other: &Facet) -> Option<Ridge<'a>> {
+        let other = other.ptr;
+        self.ridges()?.iter().find(|ridge| {
+            let ridge = unsafe { ridge.raw_ref() };
+            (ridge.top == self.ptr && ridge.bottom == other) || (ridge.bottom == self.ptr && ridge.top == other)
+        })
+    }
+
+    /// The vertices of the facet, ordered by orientation
+    ///
+    /// * 2-d hulls: the two endpoints of the edge, oriented so that walking the facets
+    ///   traverses the hull counterclockwise
+    /// * 3-d hulls: the vertices of the facet in counterclockwise order when viewed from outside,
+    ///   i.e. the reverse of the order used by `qh_facet3vertex` for the `o` and `G` outputs
+    ///   (Geomview expects inward pointing normals)
+    ///
+    /// Other dimensions have no natural ordering and produce an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("100 D2").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// // the signed area of a counterclockwise polygon is positive
+    /// let mut area = 0.0;
+    /// for facet in qh.facets() {
+    ///     let vertices = facet.ordered_vertices(&qh).unwrap();
+    ///     let (a, b) = (vertices[0].point().unwrap(), vertices[1].point().unwrap());
+    ///     area += (a[0] * b[1] - b[0] * a[1]) / 2.0;
+    /// }
+    /// assert!(area > 0.0);
+    ///
+    /// // in 3-d the ordered vertices follow the outward normal (right-hand rule)
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// for facet in qh.facets() {
+    ///     let vertices = facet.ordered_vertices(&qh).unwrap();
+    ///     assert_eq!(vertices.len(), 4);
+    ///     let p = vertices.iter().map(|v| v.point().unwrap()).collect::<Vec<_>>();
+    ///     let (u, v) = ([0, 1, 2].map(|i| p[1][i] - p[0][i]), [0, 1, 2].map(|i| p[2][i] - p[0][i]));
+    ///     let cross = [
+    ///         u[1] * v[2] - u[2] * v[1],
+    ///         u[2] * v[0] - u[0] * v[2],
+    ///         u[0] * v[1] - u[1] * v[0],
+    ///     ];
+    ///     let normal = facet.normal().unwrap();
+    ///     assert!((0..3).map(|i| cross[i] * normal[i]).sum::<f64>() > 0.0);
+    /// }
+    /// ```
+    pub fn ordered_vertices<'b>(&self, qh: &'b Qh) -> Result<Vec<Vertex<'a>>, QhError<'b>> {
+        match qh.hull_dim() {
+            2 => {
+                let vertices = self.vertices().map(|s| s.iter().collect::<Vec<_>>()).unwrap_or_default();
+                if vertices.len() != 2 {
+                    return Err(QhError::invalid_input(format!(
+                        "facet f{} has {} vertices, expected 2",
+                        self.id(),
+                        vertices.len()
+                    )));
+                }
+                // same orientation as qh_printextremes_2d
+                if self.top_orient() ^ (sys::qh_ORIENTclock != 0) {
+                    Ok(vertices)
+                } else {
+                    Ok(vec![vertices[1], vertices[0]])
+                }
+            }
+            3 => {
+                let (facet, dim) = (self.ptr, self.dim());
+                let mut vertices = unsafe {
+                    Qh::try_on_qh(qh, |qh| {
+                        let qh = qh as *mut sys::qhT;
+                        let mut set = sys::qh_facet3vertex(qh, facet);
+                        let vertices = Set::<Vertex>::maybe_new(set, dim)
+                            .map(|s| s.iter().collect::<Vec<_>>())
+                            .unwrap_or_default();
+                        sys::qh_settempfree(qh, &mut set);
+                        vertices
+                    })?
+                };
+                // qh_facet3vertex is clockwise from outside unless qh_ORIENTclock is set
+                if sys::qh_ORIENTclock == 0 {
+                    vertices.reverse();
+                }
+                Ok(vertices)
+            }
+            dim => Err(QhError::invalid_input(format!(
+                "ordered vertices are only available for 2-d and 3-d hulls, not {}-d",
+                dim
+            ))),
+        }
+    }
+
+    /// Circumcenter of the facet of a Delaunay triangulation
+    ///
+    /// This is the center of the sphere through the vertices of the facet,
+    /// i.e. the Voronoi vertex of the facet, computed by `qh_facetcenter`.
+    /// The result has the dimension of the input points, not of the lifted points.
+    /// For non-simplicial facets, qhull uses a maximal simplex of the vertices.
+    ///
+    /// Returns an error if the instance is not a Delaunay triangulation.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::new_delaunay([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [2.0, 2.0]]).unwrap();
+    /// let triangle = qh
+    ///     .lower_facets()
+    ///     .find(|f| {
+    ///         let mut indices = f.vertices().unwrap().iter().map(|v| v.index(&qh).unwrap()).collect::<Vec<_>>();
+    ///         indices.sort();
+    ///         indices == [0, 1, 2]
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let center = triangle.circumcenter(&qh).unwrap();
+    /// assert_eq!(center.len(), 2);
+    /// assert!((center[0] - 0.5).abs() < 1e-12 && (center[1] - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn circumcenter<'b>(&self, qh: &'b Qh) -> Result<Vec<f64>, QhError<'b>> {
+        let (delaunay, center_size) = unsafe {
+            let qh = &*Qh::raw_ptr(qh);
+            (qh.DELAUNAY != 0, qh.center_size)
+        };
+        if !delaunay {
+            return Err(QhError::invalid_input(format!(
+                "the circumcenter of facet f{} requires a Delaunay triangulation",
+                self.id()
+            )));
+        }
+        let vertices = unsafe { self.raw_ref() }.vertices;
+        let dim = qh.hull_dim() - 1;
+        unsafe {
+            Qh::try_on_qh(qh, |qh| {
+                let qh = qh as *mut sys::qhT;
+                // allocated by qh_voronoi_center from the memory of qhull
+                let center = sys::qh_facetcenter(qh, vertices);
+                let coords = std::slice::from_raw_parts(center, dim).to_vec();
+                sys::qh_memfree(qh, center as *mut _, center_size);
+                coords
+            })
+        }
+    }
+
+    /// Centrum of the facet, with [`hull_dim`](Qh::hull_dim) coordinates
+    ///
+    /// The centrum is the centroid of the vertices projected to the hyperplane of the facet (`qh_getcentrum`):
+    /// it is inside the facet, and qhull uses it to test the convexity of the ridges.
+    /// It is neither the centroid of the vertices (which is off the hyperplane of a non-simplicial facet)
+    /// nor the [circumcenter](Facet::circumcenter), which may be outside of the facet.
+    ///
+    /// If qhull stored the centrum in the facet (e.g. when merging facets), it is copied,
+    /// otherwise it is computed: the point is allocated by qhull and freed after it is copied,
+    /// the facet is not modified.
+    /// The coordinates are the ones of the instance, as for [`Vertex::point`](crate::Vertex::point)
+    /// (e.g. lifted for a Delaunay triangulation).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// for facet in qh.facets() {
+    ///     let centrum = facet.centrum(&qh).unwrap();
+    ///     assert_eq!(centrum.len(), 3);
+    ///     assert!(qh.contains(&centrum).unwrap());
+    ///     // on the hyperplane of the facet
+    ///     let normal = facet.normal().unwrap();
+    ///     let dist = facet.offset() + normal.iter().zip(&centrum).map(|(n, x)| n * x).sum::<f64>();
+    ///     assert!(dist.abs() <= qh.dist_round());
+    ///     // the center of a face of the cube
+    ///     assert_eq!(centrum.iter().filter(|x| x.abs() < 1e-12).count(), 2);
+    ///     // the center is not a Voronoi center
+    ///     assert!(facet.voronoi_center(&qh).is_none());
+    /// }
+    /// ```
+    pub fn centrum<'b>(&self, qh: &'b Qh) -> Result<Vec<f64>, QhError<'b>> {
+        if let Some(centrum) = self.center_of_type(qh, sys::qh_CENTER_qh_AScentrum, qh.hull_dim()) {
+            return Ok(centrum.to_vec());
+        }
+        let normal_size = unsafe { (*Qh::raw_ptr(qh)).normal_size };
+        let facet = self.ptr;
+        let dim = qh.hull_dim();
+        unsafe {
+            Qh::try_on_qh(qh, |qh| {
+                let qh = qh as *mut sys::qhT;
+                // allocated by qh_projectpoint from the memory of qhull
+                let centrum = sys::qh_getcentrum(qh, facet);
+                let coords = std::slice::from_raw_parts(centrum, dim).to_vec();
+                sys::qh_memfree(qh, centrum as *mut _, normal_size);
+                coords
+            })
+        }
+    }
+
+    /// Points outside of the facet
+    ///
+    /// During the construction of the hull, these are the points that are still to be processed.
+    pub fn outside_set(&self) -> Option<Set<'a, Point<'a>>> {
         let face = unsafe { self.raw_ref() };
         Set::maybe_new(face.outsideset, self.dim())
     }
 
-    pub fn coplanar_set(&self) -> Option<Set<'a, Vertex<'a>>> {
+    /// The furthest point outside of the facet
+    ///
+    /// Returns the index in the input of the point of the [outside set](Facet::outside_set)
+    /// that is furthest from the facet's hyperplane, and its distance.
+    /// This is the point that qhull would add next for this facet.
+    ///
+    /// Qhull keeps the furthest point at the end of the outside set, at distance [`Facet::furthest_dist`],
+    /// but when the construction is stopped it has already removed the next point from its facet
+    /// without updating the set nor `furthestdist`: the distances are computed again (`qh_distplane`).
+    ///
+    /// Once the hull is complete, every outside point has been added to the hull or assigned to
+    /// a [coplanar set](Facet::coplanar_set), so this returns `None` for every facet.
+    /// The outside sets are left in place only when the construction is stopped early,
+    /// with [`stop_add`](crate::QhBuilder::stop_add) (`TAn`), [`stop_point`](crate::QhBuilder::stop_point) (`TVn`)
+    /// or [`stop_clone`](crate::QhBuilder::stop_clone) (`TCn`).
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("100 D3").unwrap();
+    /// // stop after adding 2 points to the initial simplex
+    /// let qh = Qh::builder().stop_add(3).build_managed(dim, coords.clone()).unwrap();
+    ///
+    /// let mut found = 0;
+    /// for facet in qh.facets() {
+    ///     let Some((index, dist)) = facet.furthest_outside_point(&qh) else {
+    ///         continue;
+    ///     };
+    ///     found += 1;
+    ///     let normal = facet.normal().unwrap();
+    ///     let distance = |index: usize| {
+    ///         let point = qh.point(index).unwrap();
+    ///         facet.offset() + normal.iter().zip(point).map(|(n, x)| n * x).sum::<f64>()
+    ///     };
+    ///     assert!((distance(index) - dist).abs() < 1e-12);
+    ///     assert!(dist <= facet.furthest_dist());
+    ///     for point in facet.outside_set().unwrap().iter() {
+    ///         assert!(distance(point.index(&qh).unwrap()) <= dist + 1e-12);
+    ///     }
+    /// }
+    /// assert!(found > 0);
+    ///
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    /// assert!(qh.facets().all(|f| f.furthest_outside_point(&qh).is_none()));
+    /// ```
+    pub fn furthest_outside_point(&self, qh: &Qh) -> Option<(usize, f64)> {
+        // the outside set is a set of points, not of vertices
+        self.outside_set()?
+            .iter()
+            .filter_map(|point| {
+                let index = point.index(qh)?;
+                // qh_distplane does not raise qhull errors
+                let mut dist = 0.0;
+                unsafe {
+                    sys::qh_distplane(Qh::raw_ptr(qh) as *mut _, point.raw_ptr(), self.ptr, &mut dist);
+                }
+                Some((index, dist))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Points that are coplanar with the facet
+    ///
+    /// See [`Facet::coplanar_points`] for more information.
+    pub fn coplanar_set(&self) -> Option<Set<'a, Point<'a>>> {
         let face = unsafe { self.raw_ref() };
         Set::maybe_new(face.coplanarset, self.dim())
     }
 
+    /// Input points that are coplanar with the facet
+    ///
+    /// Yields the index of each point in the input and its distance from the facet's hyperplane.  
+    /// Qhull only keeps the coplanar points when [`keep_coplanar`](crate::QhBuilder::keep_coplanar) (`Qc`)
+    /// or [`keep_inside`](crate::QhBuilder::keep_inside) (`Qi`) are enabled, this is the data behind the `Fc` and `Fp` outputs.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .keep_coplanar(true)
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///         [0.5, 0.0], // on the bottom edge
+    ///         [0.0, 0.5], // on the left edge
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// let mut coplanar = qh
+    ///     .facets()
+    ///     .flat_map(|f| f.coplanar_points(&qh).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// coplanar.sort_by_key(|(index, _)| *index);
+    /// assert_eq!(coplanar.iter().map(|(index, _)| *index).collect::<Vec<_>>(), vec![3, 4]);
+    /// assert!(coplanar.iter().all(|(_, dist)| dist.abs() < 1e-12));
+    ///
+    /// // the same points are available through the coplanar sets
+    /// for facet in qh.facets() {
+    ///     for point in facet.coplanar_set().iter().flat_map(|s| s.iter()) {
+    ///         let index = point.index(&qh).unwrap();
+    ///         assert_eq!(point.coords(), qh.point(index).unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub fn coplanar_points<'b>(&self, qh: &'b Qh) -> impl Iterator<Item = (usize, f64)> + 'b
+    where
+        'a: 'b,
+    {
+        let facet = self.ptr;
+        self.coplanar_set()
+            .into_iter()
+            .flat_map(|set| set.iter())
+            .filter_map(move |point| {
+                let index = point.index(qh)?;
+                // qh_distplane does not raise qhull errors
+                let mut dist = 0.0;
+                unsafe {
+                    sys::qh_distplane(Qh::raw_ptr(qh) as *mut _, point.raw_ptr(), facet, &mut dist);
+                }
+                Some((index, dist))
+            })
+    }
+
     pub fn visit_id(&self) -> u32 {
         let face = unsafe { self.raw_ref() };
         face.visitid
@@ -168,6 +669,13 @@ impl<'a> Facet<'a> {
         face.nummerge()
     }
 
+    /// Number of merges into this facet
+    ///
+    /// Alias of [`Facet::num_merge`], saturates at [`Facet::max_num_merge`].
+    pub fn merge_count(&self) -> u32 {
+        self.num_merge()
+    }
+
     pub fn max_num_merge() -> u32 {
         sys::qh_MAXnummerge
     }
@@ -217,6 +725,30 @@ impl<'a> Facet<'a> {
         face.upperdelaunay() != 0
     }
 
+    /// Whether the facet is part of the lower hull of the lifted points
+    ///
+    /// For a Delaunay triangulation (the `d` option, as in [`Qh::new_delaunay`](crate::Qh::new_delaunay)),
+    /// this is the opposite of [`Facet::upper_delaunay`]: the lower facets are the simplices of the triangulation.
+    /// For a hull of points lifted to a paraboloid by hand, qhull does not mark the upper facets,
+    /// and the facet is lower if the last coordinate of its normal is negative,
+    /// with the same tolerance used by qhull (`qh_ZEROdelaunay * qh.ANGLEround`).
+    ///
+    /// See [`Qh::lower_facets`](crate::Qh::lower_facets).
+    pub fn is_lower_delaunay(&self, qh: &Qh) -> bool {
+        let (delaunay, angle_round) = unsafe {
+            let qh = &*Qh::raw_ptr(qh);
+            (qh.DELAUNAY != 0, qh.ANGLEround)
+        };
+        if delaunay {
+            return !self.upper_delaunay();
+        }
+        // as qh_setfacetplane without the 'Qu' option, vertical facets are upper facets
+        match self.normal() {
+            Some(normal) => normal[normal.len() - 1] <= -angle_round * sys::qh_ZEROdelaunay as f64,
+            None => false,
+        }
+    }
+
     pub fn not_furthest(&self) -> bool {
         let face = unsafe { self.raw_ref() };
         face.notfurthest() != 0
@@ -0,0 +1,76 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{helpers::QhTypeRef, sys, Qh};
+
+/// A point known to qhull
+///
+/// This is a reference to the coordinates of a point (`pointT*` in qhull),
+/// as found in the point sets of a facet (see [`Facet::outside_set`](crate::Facet::outside_set)
+/// and [`Facet::coplanar_set`](crate::Facet::coplanar_set)).
+///
+/// Unlike a [`Vertex`](crate::Vertex), a point carries no other information than its coordinates.
+#[derive(Clone, Copy)]
+pub struct Point<'a> {
+    ptr: *mut f64,
+    dim: usize,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Debug for Point<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Point").field(&self.coords()).finish()
+    }
+}
+
+impl<'a> Point<'a> {
+    /// The coordinates of the point
+    pub fn coords(&self) -> &'a [f64] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.dim) }
+    }
+
+    /// Qhull id of the point
+    ///
+    /// This wraps [`qhull_sys::qh_pointid`], negative values are special ids
+    /// (e.g. `qh_IDunknown` if the point does not belong to qhull's point array).
+    pub fn id(&self, qh: &Qh) -> i32 {
+        unsafe { sys::qh_pointid(Qh::raw_ptr(qh) as *mut _, self.ptr) }
+    }
+
+    /// Get the index of the point in the input points
+    ///
+    /// Returns `None` if the point is not one of the input points.
+    pub fn index(&self, qh: &Qh) -> Option<usize> {
+        usize::try_from(self.id(qh))
+            .ok()
+            .filter(|&id| id < qh.num_points())
+    }
+
+    /// The dimension of the point
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+}
+
+impl<'a> QhTypeRef for Point<'a> {
+    type FFIType = f64;
+
+    fn from_ptr(ptr: *mut Self::FFIType, dim: usize) -> Option<Self> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Self {
+                ptr,
+                dim,
+                _marker: PhantomData,
+            })
+        }
+    }
+
+    unsafe fn raw_ptr(&self) -> *mut Self::FFIType {
+        self.ptr
+    }
+
+    fn dim(&self) -> usize {
+        self.dim()
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, hash::{Hash, Hasher}, marker::PhantomData};
 
 use crate::{helpers::QhTypeRef, sys, Facet, Set, Vertex};
 
@@ -27,7 +27,47 @@ impl<'a> Debug for Ridge<'a> {
     }
 }
 
+/// Two ridges are equal if they are the same ridge of the same [`Qh`](crate::Qh), see [`Ridge::ptr_eq`]
+impl<'a> PartialEq for Ridge<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        let eq = self.ptr_eq(other);
+        debug_assert!(!eq || self.id() == other.id());
+        eq
+    }
+}
+
+impl<'a> Eq for Ridge<'a> {}
+
+impl<'a> Hash for Ridge<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ridge.hash(state);
+    }
+}
+
 impl<'a> Ridge<'a> {
+    /// Whether `self` and `other` refer to the same qhull ridge
+    ///
+    /// This is an identity comparison (the same as `==`), not a geometric one:
+    /// ridges of different instances are never equal, even if they have the same coordinates.
+    /// Use it to track the ridges in sets and maps (e.g. visited ridges),
+    /// they are only meaningful while the [`Qh`](crate::Qh) that owns them is not modified.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::collections::HashSet;
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// // each ridge is in the ridges of two facets
+    /// let ridges: HashSet<Ridge> = qh.facets().flat_map(|f| f.ridges().unwrap()).collect();
+    /// assert_eq!(ridges.len(), qh.ridges().count());
+    /// assert_eq!(ridges.len(), 12);
+    /// ```
+    pub fn ptr_eq(&self, other: &Ridge) -> bool {
+        self.ridge == other.ridge
+    }
+
     pub fn vertices(&self) -> Option<Set<'a, Vertex<'a>>> {
         let ridge = unsafe { self.raw_ref() };
         Set::maybe_new(ridge.vertices, self.dim)
@@ -43,6 +83,40 @@ impl<'a> Ridge<'a> {
         Facet::from_ptr(ridge.bottom, self.dim).unwrap()
     }
 
+    /// The facet on the other side of the ridge
+    ///
+    /// Returns `None` if `facet` is neither the [top](Ridge::top) nor the [bottom](Ridge::bottom) of the ridge.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let (dim, coords) = rbox::generate("c D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// let facet = qh.facets().next().unwrap();
+    /// for ridge in facet.ridges().unwrap() {
+    ///     let neighbor = ridge.opposite(&facet).unwrap();
+    ///     assert_ne!(neighbor.id(), facet.id());
+    ///     assert!([ridge.top().id(), ridge.bottom().id()].contains(&neighbor.id()));
+    ///     assert_eq!(ridge.opposite(&neighbor).unwrap().id(), facet.id());
+    ///
+    ///     // a face of the cube that is not incident to the ridge
+    ///     let other = qh.facets().find(|f| f.id() != facet.id() && f.id() != neighbor.id()).unwrap();
+    ///     assert!(ridge.opposite(&other).is_none());
+    /// }
+    /// ```
+    pub fn opposite(&self, facet: &Facet) -> Option<Facet<'a>> {
+        let ridge = unsafe { self.raw_ref() };
+        let (dim, facet) = (facet.dim(), unsafe { facet.raw_ptr() });
+        if ridge.top == facet {
+            Facet::from_ptr(ridge.bottom, dim)
+        } else if ridge.bottom == facet {
+            Facet::from_ptr(ridge.top, dim)
+        } else {
+            None
+        }
+    }
+
     pub fn id(&self) -> u32 {
         let ridge = unsafe { self.raw_ref() };
         ridge.id
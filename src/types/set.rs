@@ -28,7 +28,7 @@ where
 
 impl<'a, T: QhTypeRef> Set<'a, T> {
     pub(crate) fn maybe_new(set: *mut sys::setT, dim: usize) -> Option<Self> {
-        set.is_null().not().then(|| Self {
+        set.is_null().not().then_some(Self {
             set,
             dim,
             _phantom: PhantomData,
@@ -36,7 +36,29 @@ impl<'a, T: QhTypeRef> Set<'a, T> {
     }
 
     /// Iterate over the elements of the set
-    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+    ///
+    /// Sets can also be used directly in `for` loops, see the [`IntoIterator`] implementations.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0],
+    ///         [1.0, 0.0],
+    ///         [0.0, 1.0],
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// for facet in qh.facets() {
+    ///     let vertices = facet.vertices().unwrap();
+    ///     assert_eq!(vertices.iter().count(), 2);
+    ///     for v in vertices {
+    ///         assert!(v.index(&qh).is_some());
+    ///     }
+    /// }
+    /// ```
+    pub fn iter(&self) -> SetIterator<'a, T> {
         SetIterator::new(self)
     }
 
@@ -56,15 +78,36 @@ pub(crate) fn dbg_face_set(set: Option<Set<Facet>>) -> Option<Vec<u32>> {
     set.map(|s| s.iter().map(|f| f.id()).collect())
 }
 
+impl<'a, T: QhTypeRef> IntoIterator for Set<'a, T> {
+    type Item = T;
+    type IntoIter = SetIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SetIterator::new(&self)
+    }
+}
+
+impl<'a, T: QhTypeRef> IntoIterator for &Set<'a, T> {
+    type Item = T;
+    type IntoIter = SetIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SetIterator::new(self)
+    }
+}
+
+/// Iterator over the elements of a [`Set`]
+///
+/// See [`Set::iter`].
 #[derive(Clone, Copy)]
-struct SetIterator<'a, T: QhTypeRef> {
+pub struct SetIterator<'a, T: QhTypeRef> {
     ptr: *mut *mut T::FFIType,
     dim: usize,
     _phantom: PhantomData<&'a T>,
 }
 
 impl<'a, T: QhTypeRef> SetIterator<'a, T> {
-    pub fn new(set: &Set<'a, T>) -> Self {
+    fn new(set: &Set<'a, T>) -> Self {
         let dim = set.dim;
         assert!(!set.set.is_null());
         let set = unsafe { &*set.set };
@@ -1,6 +1,6 @@
-use std::{fmt::Debug, marker::PhantomData, ops::Not};
+use std::{fmt::Debug, hash::{Hash, Hasher}, marker::PhantomData, ops::Not};
 
-use crate::{helpers::QhTypeRef, sys, Qh, QhError, Set};
+use crate::{dbg_face_set, helpers::QhTypeRef, sys, Facet, Qh, QhError, Set};
 
 /// A vertex of the convex hull
 ///
@@ -18,19 +18,51 @@ impl<'a> Debug for Vertex<'a> {
             .field("id", &self.id())
             .field("visit_id", &self.visit_id())
             .field("point", &self.point())
-            .field(
-                "neighbors",
-                &self
-                    .neighbors()
-                    .iter()
-                    .map(|n| n.iter().map(|v| v.id()).collect::<Vec<_>>())
-                    .collect::<Vec<_>>(),
-            )
+            .field("neighbor_facets", &dbg_face_set(self.neighbor_facets()))
             .finish()
     }
 }
 
+/// Two vertices are equal if they are the same vertex of the same [`Qh`](crate::Qh), see [`Vertex::ptr_eq`]
+impl<'a> PartialEq for Vertex<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        let eq = self.ptr_eq(other);
+        debug_assert!(!eq || self.id() == other.id());
+        eq
+    }
+}
+
+impl<'a> Eq for Vertex<'a> {}
+
+impl<'a> Hash for Vertex<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state);
+    }
+}
+
 impl<'a> Vertex<'a> {
+    /// Whether `self` and `other` refer to the same qhull vertex
+    ///
+    /// This is an identity comparison (the same as `==`), not a geometric one:
+    /// vertices of different instances are never equal, even if they have the same coordinates.
+    /// Use it to track the vertices in sets and maps (e.g. visited vertices),
+    /// they are only meaningful while the [`Qh`](crate::Qh) that owns them is not modified.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// # use std::collections::HashSet;
+    /// let (dim, coords) = rbox::generate("100 D3").unwrap();
+    /// let qh = Qh::builder().build_managed(dim, coords).unwrap();
+    ///
+    /// // each vertex is in several facets
+    /// let vertices: HashSet<Vertex> = qh.facets().flat_map(|f| f.vertices().unwrap()).collect();
+    /// assert_eq!(vertices.len(), qh.num_vertices());
+    /// ```
+    pub fn ptr_eq(&self, other: &Vertex) -> bool {
+        self.ptr == other.ptr
+    }
+
     /// Check if the vertex is a sentinel (id = 0)
     ///
     /// A sentinel is a special vertex that is used to mark the end of a list
@@ -51,36 +83,62 @@ impl<'a> Vertex<'a> {
     /// - is a sentinel
     /// - has no coordinates
     /// - coordinates do not belong to the original set of points
+    /// - is the [point at infinity](Vertex::is_at_infinity) of a Delaunay triangulation
+    ///
+    /// This method uses [`qhull_sys::qh_pointid`], so it works even if qhull copied or projected the input points.
+    /// The index refers to the points as given to the builder (the same as [`Qh::point`]),
+    /// for a Delaunay triangulation the stride of qhull's (lifted) point array is taken into account.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0],
+    ///     [0.0, 1.0],
+    ///     [0.25, 0.25],
+    ///     [1.0, 1.0],
+    /// ];
+    /// let qh = Qh::builder()
+    ///     .delaunay(true)
+    ///     .project_delaunay(true)
+    ///     .at_infinity(true)
+    ///     .triangulate(true)
+    ///     .build_from_iter(points)
+    ///     .unwrap();
+    ///
+    /// let (at_infinity, vertices): (Vec<_>, Vec<_>) = qh.vertices().partition(|v| v.is_at_infinity(&qh));
+    /// assert_eq!(at_infinity.len(), 1);
+    /// assert_eq!(at_infinity[0].index(&qh), None);
     ///
-    /// Use [`Vertex::index_unchecked`] if you are sure that the vertex has coordinates
+    /// let mut indices = vertices.iter().map(|v| v.index(&qh).unwrap()).collect::<Vec<_>>();
+    /// indices.sort();
+    /// assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+    /// ```
     pub fn index(&self, qh: &Qh) -> Option<usize> {
-        debug_assert_eq!(qh.dim, unsafe { sys::qh_get_hull_dim(qh.qh.get()) as usize });
-
-        let first_ptr = unsafe { sys::qh_get_first_point(qh.qh.get()) as *const f64 };
-        let end_ptr = unsafe { first_ptr.add(sys::qh_get_num_points(qh.qh.get()) as usize * qh.dim) };
-
-        // perform some additional checks if we own the coordinates
-        if let Some(coords_holder) = qh.coords_holder.as_ref() {
-            debug_assert_eq!(first_ptr, coords_holder.as_slice().as_ptr());
-            debug_assert_eq!(end_ptr, unsafe { coords_holder.as_slice().as_ptr().add(coords_holder.len()) });
-        }
-
-        if self.is_sentinel() {
+        if self.is_sentinel() || self.point().is_none() {
             return None;
         }
 
-        let current_ptr = self.point()?.as_ptr();
+        let id = unsafe { sys::qh_pointid(qh.qh.get(), self.raw_ref().point) };
 
-        if current_ptr < first_ptr || current_ptr >= end_ptr {
-            return None;
-        } else {
-            let diff = current_ptr as usize - first_ptr as usize;
-            let point_size = std::mem::size_of::<f64>() * qh.dim;
-            debug_assert_eq!(diff % point_size, 0);
-            let index = diff / point_size;
-            debug_assert!(index < unsafe { sys::qh_get_num_points(qh.qh.get()) as usize });
-            Some(index)
+        // negative ids are qh_IDunknown, qh_IDinterior, ...
+        usize::try_from(id).ok().filter(|&id| id < qh.num_points())
+    }
+
+    /// Check if the vertex is the point at infinity of a Delaunay triangulation
+    ///
+    /// Qhull adds this point when using the [`at_infinity`](crate::QhBuilder::at_infinity) option (`Qz`).
+    pub fn is_at_infinity(&self, qh: &Qh) -> bool {
+        let at_infinity = unsafe {
+            let qh = &*qh.qh.get();
+            qh.DELAUNAY != 0 && qh.ATinfinity != 0
+        };
+        if !at_infinity || self.is_sentinel() || self.point().is_none() {
+            return false;
         }
+        let id = unsafe { sys::qh_pointid(qh.qh.get(), self.raw_ref().point) };
+        usize::try_from(id).is_ok_and(|id| id == qh.num_points())
     }
 
     /// Get the id of the point that the vertex represents
@@ -89,7 +147,7 @@ impl<'a> Vertex<'a> {
     pub fn point_id<'b>(&self, qh: &'b Qh) -> Result<i32, QhError<'b>> {
         unsafe {
             let ptr = self.raw_ref().point;
-            Qh::try_on_qh(&qh, |qh| {
+            Qh::try_on_qh(qh, |qh| {
                 qhull_sys::qh_pointid(qh as *mut _, ptr as *mut _)
             })
         }
@@ -105,8 +163,7 @@ impl<'a> Vertex<'a> {
         let diff = current_ptr as usize - first_ptr as usize;
         let point_size = std::mem::size_of::<f64>() * qh.dim;
         debug_assert_eq!(diff % point_size, 0);
-        let index = diff / point_size;
-        index
+        diff / point_size
     }
 
     /// The dimension of the vertex
@@ -142,6 +199,35 @@ impl<'a> Vertex<'a> {
         }
     }
 
+    /// Get the coordinates of the vertex in the input space
+    ///
+    /// Same as [`Vertex::point`], but only the first [`Qh::dim`] coordinates are returned:
+    /// for a Delaunay triangulation, the lifted coordinate is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.5], [0.3, 0.4]];
+    /// let triangulation = Qh::new_delaunay(points).unwrap();
+    /// let hull = Qh::builder().build_from_iter(points).unwrap();
+    ///
+    /// for v in triangulation.vertices() {
+    ///     assert_eq!(v.point().unwrap().len(), 3);
+    ///     let index = v.index(&triangulation).unwrap();
+    ///     assert_eq!(v.input_point(&triangulation).unwrap(), &points[index]);
+    /// }
+    ///
+    /// // the indices of the triangulation refer to the same points as the indices of a hull
+    /// for v in hull.vertices() {
+    ///     let index = v.index(&hull).unwrap();
+    ///     assert_eq!(v.input_point(&hull).unwrap(), &points[index]);
+    ///     assert!(triangulation.vertices().any(|w| w.index(&triangulation) == Some(index)));
+    /// }
+    /// ```
+    pub fn input_point(&self, qh: &Qh) -> Option<&'a [f64]> {
+        self.point().map(|point| &point[..qh.dim().min(point.len())])
+    }
+
     /// Qhull id of the vertex
     ///
     /// # Warning
@@ -158,10 +244,39 @@ impl<'a> Vertex<'a> {
     }
 
     /// Get the neighbors of the vertex
+    #[deprecated(note = "the neighbors of a vertex are facets, use `Vertex::neighbor_facets` instead")]
     pub fn neighbors(&self) -> Option<Set<'a, Vertex<'a>>> {
         let vertex = unsafe { self.raw_ref() };
         Set::maybe_new(vertex.neighbors, self.dim())
     }
+
+    /// Get the facets that contain this vertex
+    ///
+    /// Qhull only builds the vertex-to-facet adjacency on demand,
+    /// this method returns `None` if it has not been built yet.  
+    /// Call [`Qh::compute_vertex_neighbors`] first to make sure the neighbors are available.
+    ///
+    /// # Example
+    /// ```
+    /// # use qhull::*;
+    /// let mut qh = Qh::builder()
+    ///     .build_from_iter([
+    ///         [0.0, 0.0, 0.0],
+    ///         [1.0, 0.0, 0.0],
+    ///         [0.0, 1.0, 0.0],
+    ///         [0.0, 0.0, 1.0],
+    ///     ]).unwrap();
+    ///
+    /// qh.compute_vertex_neighbors().unwrap();
+    ///
+    /// for vertex in qh.vertices() {
+    ///     assert_eq!(vertex.neighbor_facets().unwrap().iter().count(), 3);
+    /// }
+    /// ```
+    pub fn neighbor_facets(&self) -> Option<Set<'a, Facet<'a>>> {
+        let vertex = unsafe { self.raw_ref() };
+        Set::maybe_new(vertex.neighbors, self.dim())
+    }
 }
 
 impl<'a> QhTypeRef for Vertex<'a> {
@@ -0,0 +1,57 @@
+use crate::{QhErrorKind, QhMessage, Severity};
+
+/// Integrity checks of a hull
+///
+/// See [`Qh::validate`](crate::Qh::validate).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationReport {
+    /// Consistency of the facets, flipped facets and convexity (`qh_check_output`)
+    pub output: CheckResult,
+    /// All the points below the outer planes of all the facets (`qh_check_points`)
+    pub points: CheckResult,
+    /// Convexity of every ridge (`qh_checkconvex`)
+    pub convexity: CheckResult,
+    /// Maximum distance of a point above a facet (`qh.max_outside`)
+    pub max_outside: f64,
+    /// Number of coplanar points that are further above a neighbor of their facet than above the facet
+    ///
+    /// Only the points kept with [`keep_coplanar`](crate::QhBuilder::keep_coplanar) (`Qc`)
+    /// or [`keep_inside`](crate::QhBuilder::keep_inside) (`Qi`) are counted,
+    /// a difference within the roundoff error (`qh.DISTround`) is not counted.
+    pub misassigned_coplanar_points: usize,
+}
+
+impl ValidationReport {
+    /// Whether all the checks passed and no coplanar point is misassigned
+    pub fn is_ok(&self) -> bool {
+        self.checks().iter().all(|check| check.passed()) && self.misassigned_coplanar_points == 0
+    }
+
+    /// The checks, in the order they are run
+    pub fn checks(&self) -> [&CheckResult; 3] {
+        [&self.output, &self.points, &self.convexity]
+    }
+}
+
+/// Outcome of one of the checks of a [`ValidationReport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckResult {
+    /// Kind of the error raised by the check, `None` if it returned normally
+    pub error: Option<QhErrorKind>,
+    /// Id of the facet reported with the error
+    pub facet: Option<u32>,
+    /// The messages printed by qhull during the check
+    ///
+    /// Empty if the error output is not [captured](crate::QhBuilder::capture_stderr) in a temporary file.
+    pub messages: Vec<QhMessage>,
+}
+
+impl CheckResult {
+    /// Whether the check neither raised an error nor printed one
+    ///
+    /// With [`force_output`](crate::QhBuilder::force_output) (`Po`), qhull reports some problems
+    /// without raising an error.
+    pub fn passed(&self) -> bool {
+        self.error.is_none() && self.messages.iter().all(|m| m.severity != Severity::Error)
+    }
+}
@@ -0,0 +1,86 @@
+/// Ridge of a Voronoi diagram, the boundary between the cells of two sites
+///
+/// See [`Qh::voronoi_ridges`](crate::Qh::voronoi_ridges).
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoronoiRidge {
+    /// Indices of the input points whose cells share the ridge
+    pub sites: (usize, usize),
+    /// The Voronoi vertices of the ridge, without the vertex at infinity
+    ///
+    /// For 3D Voronoi diagrams, the vertices are in order around the ridge.
+    pub vertices: Vec<Vec<f64>>,
+    /// Unit normal of the hyperplane containing the ridge, as in the `Fi` and `Fo` outputs of qvoronoi
+    pub normal: Vec<f64>,
+    /// Offset of the hyperplane containing the ridge, as in the `Fi` and `Fo` outputs of qvoronoi
+    pub offset: f64,
+    /// Whether the ridge is bounded
+    pub kind: RidgeKind,
+}
+
+impl VoronoiRidge {
+    /// Whether the ridge is bounded (`Fi` output of qvoronoi)
+    pub fn is_bounded(&self) -> bool {
+        self.kind == RidgeKind::Bounded
+    }
+}
+
+/// Bounded and unbounded [Voronoi ridges](VoronoiRidge)
+#[derive(Debug, Clone, PartialEq)]
+pub enum RidgeKind {
+    /// The ridge is a bounded polytope (`Fi` output of qvoronoi)
+    Bounded,
+    /// The ridge goes to infinity (`Fo` output of qvoronoi)
+    ///
+    /// The Voronoi vertex at infinity is replaced by a direction.
+    UnboundedRay {
+        /// Unit vector in the hyperplane of the ridge, along which the ridge goes to infinity
+        ///
+        /// A ridge is unbounded when its two sites are on the convex hull of the sites.
+        /// The direction is the outward normal of a facet of that hull containing both sites,
+        /// taken from the Delaunay facets: a Voronoi vertex of the ridge next to a facet at infinity.
+        /// For 2D diagrams, the ridge is the ray from its Voronoi vertex in this direction.
+        /// In higher dimensions, the ridge goes to infinity in a cone of directions,
+        /// and this is one of its edges.
+        ///
+        /// For furthest-site diagrams ([`upper_delaunay`](crate::QhBuilder::upper_delaunay)),
+        /// the direction points inside the hull.
+        /// It is the zero vector if it cannot be computed, e.g. for flat Delaunay facets.
+        direction: Vec<f64>,
+    },
+}
+
+/// Unit vector orthogonal to the affine span of `face`, pointing away from `inside`
+///
+/// Returns `None` if `face` is empty or if `inside` is (numerically) in the span.
+pub(crate) fn outward_direction(face: &[&[f64]], inside: &[f64]) -> Option<Vec<f64>> {
+    let origin = face.first()?;
+    let relative = |point: &[f64]| point.iter().zip(*origin).map(|(x, o)| x - o).collect::<Vec<_>>();
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+    // removes the components along an orthonormal basis, returns the norm of the vector before and after
+    let reject = |v: &mut Vec<f64>, basis: &[Vec<f64>]| {
+        let length = dot(v, v).sqrt();
+        for b in basis {
+            let along = dot(v, b);
+            v.iter_mut().zip(b).for_each(|(x, y)| *x -= along * y);
+        }
+        (length, dot(v, v).sqrt())
+    };
+
+    // Gram-Schmidt on the edges of the face
+    let mut basis: Vec<Vec<f64>> = Vec::new();
+    for point in &face[1..] {
+        let mut v = relative(point);
+        let (length, norm) = reject(&mut v, &basis);
+        if norm > length * 1e-10 {
+            v.iter_mut().for_each(|x| *x /= norm);
+            basis.push(v);
+        }
+    }
+
+    let mut direction = relative(inside);
+    let (length, norm) = reject(&mut direction, &basis);
+    if norm <= length * 1e-10 || !norm.is_finite() {
+        return None;
+    }
+    Some(direction.iter().map(|x| -x / norm).collect())
+}